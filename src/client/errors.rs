@@ -1,12 +1,17 @@
 use async_trait::async_trait;
 use dco3_crypto::DracoonCryptoError;
-use reqwest::{Error as ClientError, Response};
+use std::time::Duration;
+
+use reqwest::{Error as ClientError, Response, StatusCode};
 use reqwest_middleware::Error as ReqError;
 use thiserror::Error;
 
-use crate::{nodes::models::S3ErrorResponse, utils::FromResponse};
+use crate::{
+    nodes::models::{S3ErrorResponse, S3XmlError},
+    utils::{parse_json, FromResponse},
+};
 
-use super::models::{DracoonAuthErrorResponse, DracoonErrorResponse};
+use super::models::{parse_retry_after, DracoonAuthErrorResponse, DracoonErrorResponse};
 
 #[derive(Debug, Error, PartialEq)]
 pub enum DracoonClientError {
@@ -18,6 +23,8 @@ pub enum DracoonClientError {
     MissingBaseUrl,
     #[error("Invalid DRACOON url")]
     InvalidUrl(String),
+    #[error("Invalid proxy url: {0}")]
+    InvalidProxyUrl(String),
     #[error("Invalid DRACOON path")]
     InvalidPath(String),
     #[error("Connection to DRACOON failed: {0}")]
@@ -30,8 +37,16 @@ pub enum DracoonClientError {
     Http(DracoonErrorResponse),
     #[error("S3 error")]
     S3Error(Box<S3ErrorResponse>),
+    #[error("S3 upload of part {part_number} failed ({status})")]
+    S3Upload {
+        part_number: u32,
+        status: StatusCode,
+        error: S3XmlError,
+    },
     #[error("Authentication error")]
     Auth(DracoonAuthErrorResponse),
+    #[error("Refresh token revoked or expired - authenticate again")]
+    RefreshTokenRevoked(DracoonAuthErrorResponse),
     #[error("IO error")]
     IoError,
     #[error("Crypto error")]
@@ -40,6 +55,75 @@ pub enum DracoonClientError {
     MissingEncryptionSecret,
     #[error("Missing argument")]
     MissingArgument,
+    #[error("Invalid retry config: {0}")]
+    InvalidRetryConfig(String),
+    #[error("Cannot move node {node_id} to {target_parent_id} across encryption boundary (source encrypted: {source_encrypted}, target encrypted: {target_encrypted}) - download and upload the node instead")]
+    EncryptionBoundaryCrossed {
+        node_id: u64,
+        target_parent_id: u64,
+        source_encrypted: bool,
+        target_encrypted: bool,
+    },
+    #[error("Upload failed and cannot be retried (reader is not seekable)")]
+    UploadNotRetryable(DracoonErrorResponse),
+    #[error("Upload session expired - restart the upload")]
+    UploadSessionExpired(DracoonErrorResponse),
+    #[error("Upload cannot be resumed: {0}")]
+    UploadNotResumable(String),
+    #[error("File too large ({size} bytes, max. {max_size} bytes)")]
+    FileTooLarge { size: u64, max_size: u64 },
+    #[error("Response body exceeds max. size ({max_size} bytes)")]
+    ResponseTooLarge { max_size: usize },
+    #[error("Node {node_id} not available after {timeout:?}")]
+    NodeNotAvailable { node_id: u64, timeout: Duration },
+    #[error("Upload verification failed for node {node_id}: {reason}")]
+    UploadVerificationFailed { node_id: u64, reason: String },
+    #[error("Encryption state mismatch for node {node_id} (node encrypted: {node_encrypted}, file key available: {file_key_available})")]
+    EncryptionStateMismatch {
+        node_id: u64,
+        node_encrypted: bool,
+        file_key_available: bool,
+    },
+    #[error("Provisioning API not available (missing or invalid service token)")]
+    ProvisioningNotAvailable(DracoonErrorResponse),
+    #[error("Unsupported API version {actual} (required: {required})")]
+    UnsupportedApiVersion { required: String, actual: String },
+    #[error("Content hash mismatch (expected: {expected}, actual: {actual})")]
+    HashMismatch { expected: String, actual: String },
+    #[error("Node already exists at target (conflict): {0}")]
+    NodeConflict(DracoonErrorResponse),
+    #[error("Cannot encrypt room {room_id} without a rescue key or user keypair")]
+    MissingRoomEncryptionKey { room_id: u64 },
+    #[error("Encrypted upload share provides no public keys to encrypt the file key")]
+    MissingSharePublicKeys,
+    #[error("Download url of encrypted node {node_id} serves ciphertext - the file key is required to decrypt the content")]
+    EncryptedDownloadUrl { node_id: u64 },
+    #[error("Invalid timestamp (RFC 3339 expected): {0}")]
+    InvalidTimestamp(String),
+    #[error("Invalid date pattern: {0}")]
+    InvalidDatePattern(String),
+    #[error("Range download of encrypted node {node_id} is not supported")]
+    EncryptedRangeDownload { node_id: u64 },
+    #[error("Invalid download range {start}..{end} (file size: {size} bytes)")]
+    InvalidRange { start: u64, end: u64, size: u64 },
+    #[error("Invalid email recipients: {0}")]
+    InvalidEmailRecipients(String),
+    #[error("Missing keys cannot be distributed ({remaining} keys remaining without progress, {distributed} keys distributed)")]
+    MissingKeysNotDistributable { remaining: u64, distributed: u64 },
+    #[error("Too many requests (retry after: {retry_after:?})")]
+    RateLimited {
+        error: DracoonErrorResponse,
+        retry_after: Option<Duration>,
+    },
+    #[error("Invalid chunk size (must be greater than 0)")]
+    InvalidChunkSize,
+    #[error("Upload {upload_id} not completed after {timeout:?}")]
+    UploadStatusTimeout {
+        upload_id: String,
+        timeout: Duration,
+    },
+    #[error("Current session not flagged by the API - no sessions revoked")]
+    CurrentSessionUnknown,
 }
 
 impl From<ReqError> for DracoonClientError {
@@ -78,10 +162,17 @@ impl From<ClientError> for DracoonClientError {
 
 #[async_trait]
 impl FromResponse for DracoonClientError {
+    /// Parses the error body (within the max. response body size) - if the body cannot be
+    /// parsed, the parsing error (e.g. `DracoonClientError::ResponseTooLarge`) is returned instead
     async fn from_response(value: Response) -> Result<Self, DracoonClientError> {
         if !value.status().is_success() {
-            let error = value.json::<DracoonErrorResponse>().await?;
-            return Ok(DracoonClientError::Http(error));
+            let status = value.status();
+            let retry_after = parse_retry_after(value.headers());
+            let error = match parse_json::<DracoonErrorResponse>(value).await {
+                Ok(error) => error,
+                Err(err) => return Ok(err),
+            };
+            return Ok(DracoonClientError::Http(error).into_rate_limited(status, retry_after));
         }
         Err(DracoonClientError::Unknown)
     }
@@ -94,21 +185,68 @@ impl From<DracoonCryptoError> for DracoonClientError {
 }
 
 impl DracoonClientError {
+    /// Maps a 429 Too Many Requests error to `DracoonClientError::RateLimited`
+    /// with the (last seen) `Retry-After` of the response
+    pub(crate) fn into_rate_limited(
+        self,
+        status: StatusCode,
+        retry_after: Option<Duration>,
+    ) -> Self {
+        match self {
+            DracoonClientError::Http(error) if status == StatusCode::TOO_MANY_REQUESTS => {
+                DracoonClientError::RateLimited { error, retry_after }
+            }
+            err => err,
+        }
+    }
+
+    /// Maps an `invalid_grant` auth error of a refresh token request to
+    /// `DracoonClientError::RefreshTokenRevoked`
+    pub(crate) fn into_refresh_token_revoked(self) -> Self {
+        match self {
+            DracoonClientError::Auth(error) if error.is_invalid_grant() => {
+                DracoonClientError::RefreshTokenRevoked(error)
+            }
+            err => err,
+        }
+    }
+
     pub fn get_http_error(&self) -> Option<&DracoonErrorResponse> {
         match self {
             DracoonClientError::Http(error) => Some(error),
+            DracoonClientError::RateLimited { error, .. } => Some(error),
             _ => None,
         }
     }
 
-    /// Check if the error is an authentication error
+    /// Check if the error is an authentication error (includes a revoked refresh token)
     pub fn is_auth_error(&self) -> bool {
-        matches!(self, DracoonClientError::Auth(_))
+        matches!(
+            self,
+            DracoonClientError::Auth(_) | DracoonClientError::RefreshTokenRevoked(_)
+        )
+    }
+
+    /// Check if the error is caused by a revoked (or expired) refresh token
+    /// Retrying does not help - a full authentication flow is required.
+    pub fn is_refresh_token_revoked(&self) -> bool {
+        matches!(self, DracoonClientError::RefreshTokenRevoked(_))
     }
 
     /// Check if the error is an HTTP error
     pub fn is_http_error(&self) -> bool {
-        matches!(self, DracoonClientError::Http(_))
+        matches!(
+            self,
+            DracoonClientError::Http(_) | DracoonClientError::RateLimited { .. }
+        )
+    }
+
+    /// Check if the error is an S3 error (including failed upload parts)
+    pub fn is_s3_error(&self) -> bool {
+        matches!(
+            self,
+            DracoonClientError::S3Error(_) | DracoonClientError::S3Upload { .. }
+        )
     }
 
     /// Check if the error is an 401 Unauthorized error
@@ -147,10 +285,21 @@ impl DracoonClientError {
     pub fn is_conflict(&self) -> bool {
         match self {
             DracoonClientError::Http(error) => error.is_conflict(),
+            DracoonClientError::NodeConflict(_) => true,
             _ => false,
         }
     }
 
+    /// Check if the error is caused by content not matching the expected hash
+    pub fn is_hash_mismatch(&self) -> bool {
+        matches!(self, DracoonClientError::HashMismatch { .. })
+    }
+
+    /// Check if the error is caused by a node that already exists at the target (transfer)
+    pub fn is_node_conflict(&self) -> bool {
+        matches!(self, DracoonClientError::NodeConflict(_))
+    }
+
     /// Check if the error is an 412 Precondition Failed error
     pub fn is_precondition_failed(&self) -> bool {
         match self {
@@ -163,10 +312,134 @@ impl DracoonClientError {
     pub fn is_too_many_requests(&self) -> bool {
         match self {
             DracoonClientError::Http(error) => error.is_too_many_requests(),
+            DracoonClientError::RateLimited { .. } => true,
             _ => false,
         }
     }
 
+    /// Returns the requested delay (`Retry-After`) before a rate limited request can be sent again
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            DracoonClientError::RateLimited { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// Check if the error is caused by a failed upload that cannot be retried
+    pub fn is_upload_not_retryable(&self) -> bool {
+        matches!(self, DracoonClientError::UploadNotRetryable(_))
+    }
+
+    /// Check if the error is caused by an expired upload (upload id / token no longer valid)
+    pub fn is_upload_session_expired(&self) -> bool {
+        matches!(self, DracoonClientError::UploadSessionExpired(_))
+    }
+
+    /// Check if the error is caused by an upload session that cannot be resumed
+    pub fn is_upload_not_resumable(&self) -> bool {
+        matches!(self, DracoonClientError::UploadNotResumable(_))
+    }
+
+    /// Check if the error is caused by a file exceeding the supported size
+    pub fn is_file_too_large(&self) -> bool {
+        matches!(self, DracoonClientError::FileTooLarge { .. })
+    }
+
+    /// Check if the error is caused by a response body exceeding the configured max. size
+    pub fn is_response_too_large(&self) -> bool {
+        matches!(self, DracoonClientError::ResponseTooLarge { .. })
+    }
+
+    /// Check if the error is caused by a node not becoming available within the timeout
+    pub fn is_node_not_available(&self) -> bool {
+        matches!(self, DracoonClientError::NodeNotAvailable { .. })
+    }
+
+    /// Check if the error is caused by a mismatch of the uploaded node (size or hash)
+    pub fn is_upload_verification_failed(&self) -> bool {
+        matches!(self, DracoonClientError::UploadVerificationFailed { .. })
+    }
+
+    /// Check if the error is caused by moving a node between encrypted and unencrypted parents
+    pub fn is_encryption_boundary_crossed(&self) -> bool {
+        matches!(self, DracoonClientError::EncryptionBoundaryCrossed { .. })
+    }
+
+    /// Check if the error is caused by a node whose encryption state does not match its file key
+    pub fn is_encryption_state_mismatch(&self) -> bool {
+        matches!(self, DracoonClientError::EncryptionStateMismatch { .. })
+    }
+
+    /// Check if the error is caused by a missing or invalid provisioning service token
+    pub fn is_provisioning_not_available(&self) -> bool {
+        matches!(self, DracoonClientError::ProvisioningNotAvailable(_))
+    }
+
+    /// Check if the error is caused by an API version below the required minimum
+    pub fn is_unsupported_api_version(&self) -> bool {
+        matches!(self, DracoonClientError::UnsupportedApiVersion { .. })
+    }
+
+    /// Check if the error is caused by encrypting a room without a rescue key or user keypair
+    pub fn is_missing_room_encryption_key(&self) -> bool {
+        matches!(self, DracoonClientError::MissingRoomEncryptionKey { .. })
+    }
+
+    /// Check if the error is caused by an encrypted upload share without public keys
+    pub fn is_missing_share_public_keys(&self) -> bool {
+        matches!(self, DracoonClientError::MissingSharePublicKeys)
+    }
+
+    /// Check if the error is caused by requesting a plain download url for an encrypted node
+    pub fn is_encrypted_download_url(&self) -> bool {
+        matches!(self, DracoonClientError::EncryptedDownloadUrl { .. })
+    }
+
+    /// Check if the error is caused by a timestamp that is not a valid RFC 3339 timestamp
+    pub fn is_invalid_timestamp(&self) -> bool {
+        matches!(self, DracoonClientError::InvalidTimestamp(_))
+    }
+
+    /// Check if the error is caused by an invalid (strftime-like) date pattern
+    pub fn is_invalid_date_pattern(&self) -> bool {
+        matches!(self, DracoonClientError::InvalidDatePattern(_))
+    }
+
+    /// Check if the error is caused by requesting a range download of an encrypted node
+    pub fn is_encrypted_range_download(&self) -> bool {
+        matches!(self, DracoonClientError::EncryptedRangeDownload { .. })
+    }
+
+    /// Check if the error is caused by a download range exceeding the file size
+    pub fn is_invalid_range(&self) -> bool {
+        matches!(self, DracoonClientError::InvalidRange { .. })
+    }
+
+    /// Check if the error is caused by empty or malformed email recipients
+    pub fn is_invalid_email_recipients(&self) -> bool {
+        matches!(self, DracoonClientError::InvalidEmailRecipients(_))
+    }
+
+    /// Check if the error is caused by missing keys that cannot be distributed
+    pub fn is_missing_keys_not_distributable(&self) -> bool {
+        matches!(self, DracoonClientError::MissingKeysNotDistributable { .. })
+    }
+
+    /// Check if the error is caused by a chunk size of 0
+    pub fn is_invalid_chunk_size(&self) -> bool {
+        matches!(self, DracoonClientError::InvalidChunkSize)
+    }
+
+    /// Check if the error is caused by an upload that was not completed in time (status polling)
+    pub fn is_upload_status_timeout(&self) -> bool {
+        matches!(self, DracoonClientError::UploadStatusTimeout { .. })
+    }
+
+    /// Check if the error is caused by a session list without a flagged current session
+    pub fn is_current_session_unknown(&self) -> bool {
+        matches!(self, DracoonClientError::CurrentSessionUnknown)
+    }
+
     /// Check if the error is an 500 Internal Server Error error
     pub fn is_server_error(&self) -> bool {
         match self {
@@ -1,14 +1,30 @@
+use async_trait::async_trait;
+use http::Extensions;
+use reqwest_middleware::{Middleware, Next};
 use reqwest_retry::{default_on_request_failure, Retryable, RetryableStrategy};
+use retry_policies::Jitter;
 use secrecy::SecretString;
-use std::fmt::{Display, Formatter};
+use std::{
+    fmt::{Display, Formatter},
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
+};
+use tracing::debug;
 use url::ParseError;
 
-use chrono::Utc;
-use reqwest::{Response, StatusCode};
+use chrono::{DateTime, Utc};
+use reqwest::{
+    header::{self, HeaderMap},
+    Request, Response, StatusCode,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    constants::{GRANT_TYPE_AUTH_CODE, GRANT_TYPE_PASSWORD, GRANT_TYPE_REFRESH_TOKEN},
+    constants::{
+        DRACOON_TOKEN_REVOKE_URL, DRACOON_TOKEN_URL, GRANT_TYPE_AUTH_CODE,
+        GRANT_TYPE_CLIENT_CREDENTIALS, GRANT_TYPE_PASSWORD, GRANT_TYPE_REFRESH_TOKEN,
+        MAX_RETRY_DELAY, OAUTH_ERROR_INVALID_GRANT,
+    },
     utils::parse_body,
 };
 
@@ -37,6 +53,28 @@ impl OAuth2PasswordFlow {
     }
 }
 
+/// represents form data payload for `OAuth2` client credentials flow
+/// (client id and secret are passed via basic auth header)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OAuth2ClientCredentialsFlow {
+    pub grant_type: String,
+}
+
+impl OAuth2ClientCredentialsFlow {
+    /// creates a new client credentials flow payload
+    pub fn new() -> Self {
+        Self {
+            grant_type: GRANT_TYPE_CLIENT_CREDENTIALS.to_string(),
+        }
+    }
+}
+
+impl Default for OAuth2ClientCredentialsFlow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// represents form data payload for `OAuth2` authorization code flow
 #[derive(Debug, Serialize, Deserialize)]
 pub struct OAuth2AuthCodeFlow {
@@ -106,7 +144,8 @@ impl OAuth2TokenRevoke {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct OAuth2TokenResponse {
     access_token: String,
-    refresh_token: String,
+    // not returned for the client credentials flow
+    refresh_token: Option<String>,
     token_type: Option<String>,
     expires_in: u64,
     expires_in_inactive: Option<u64>,
@@ -161,6 +200,11 @@ impl DracoonErrorResponse {
         self.code == 409
     }
 
+    /// Checks if error is 410 Gone
+    pub fn is_gone(&self) -> bool {
+        self.code == 410
+    }
+
     /// Checks if error is 429 Too Many Requests
     pub fn is_too_many_requests(&self) -> bool {
         self.code == 429
@@ -196,8 +240,14 @@ impl DracoonErrorResponse {
         self.code == 412
     }
 
-    // Returns DRACOON API error code if available
-    pub fn error_code(&self) -> Option<i32> {
+    /// Returns the DRACOON API error code (e.g. `-40001` node not found) mapped to [DracoonApiErrorCode]
+    pub fn error_code(&self) -> DracoonApiErrorCode {
+        self.error_code
+            .map_or(DracoonApiErrorCode::Unspecified, Into::into)
+    }
+
+    /// Returns the raw DRACOON API error code if available
+    pub fn raw_error_code(&self) -> Option<i32> {
         self.error_code
     }
 
@@ -217,6 +267,58 @@ impl DracoonErrorResponse {
     }
 }
 
+/// DRACOON API error codes (`errorCode` of an error response)
+/// Codes without a variant are returned as `Other`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DracoonApiErrorCode {
+    /// -20501: upload not found
+    UploadNotFound,
+    /// -40001: node not found
+    NodeNotFound,
+    /// -40755: bad file name
+    BadFileName,
+    /// -40761: file key not found
+    FileKeyNotFound,
+    /// -70020: user locked
+    UserLocked,
+    /// -70501: user not found
+    UserNotFound,
+    /// any other error code
+    Other(i32),
+    /// no error code in the response
+    Unspecified,
+}
+
+impl DracoonApiErrorCode {
+    /// Returns the numeric error code (None if unspecified)
+    pub fn code(&self) -> Option<i32> {
+        match self {
+            Self::UploadNotFound => Some(-20501),
+            Self::NodeNotFound => Some(-40001),
+            Self::BadFileName => Some(-40755),
+            Self::FileKeyNotFound => Some(-40761),
+            Self::UserLocked => Some(-70020),
+            Self::UserNotFound => Some(-70501),
+            Self::Other(code) => Some(*code),
+            Self::Unspecified => None,
+        }
+    }
+}
+
+impl From<i32> for DracoonApiErrorCode {
+    fn from(code: i32) -> Self {
+        match code {
+            -20501 => Self::UploadNotFound,
+            -40001 => Self::NodeNotFound,
+            -40755 => Self::BadFileName,
+            -40761 => Self::FileKeyNotFound,
+            -70020 => Self::UserLocked,
+            -70501 => Self::UserNotFound,
+            code => Self::Other(code),
+        }
+    }
+}
+
 /// DRACOON `OAuth2` error response
 #[derive(Deserialize, Debug, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -232,6 +334,11 @@ impl DracoonAuthErrorResponse {
             error_description: None,
         }
     }
+
+    /// returns true if the grant (e.g. refresh token) is invalid, expired or revoked
+    pub(crate) fn is_invalid_grant(&self) -> bool {
+        self.error == OAUTH_ERROR_INVALID_GRANT
+    }
 }
 
 impl Display for DracoonAuthErrorResponse {
@@ -255,6 +362,28 @@ impl OAuth2TokenResponse {
     }
 }
 
+/// Defines how jitter is applied to the retry intervals
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JitterMode {
+    /// no jitter is applied
+    None,
+    /// jitter between 0 and the calculated backoff duration
+    Full,
+    /// jitter between the min. retry delay and the calculated backoff duration
+    #[default]
+    Bounded,
+}
+
+impl From<JitterMode> for Jitter {
+    fn from(value: JitterMode) -> Self {
+        match value {
+            JitterMode::None => Jitter::None,
+            JitterMode::Full => Jitter::Full,
+            JitterMode::Bounded => Jitter::Bounded,
+        }
+    }
+}
+
 /// represents the state of a status code
 ///  - Ok: 2xx
 /// - Error: 4xx or 5xx
@@ -276,14 +405,42 @@ impl From<StatusCode> for StatusCodeState {
     }
 }
 
+/// health of an access token (see `Dracoon::token_health`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenHealth {
+    pub is_valid: bool,
+    /// remaining lifetime of the access token (None if the expiry is not known)
+    pub remaining_lifetime: Option<chrono::Duration>,
+    /// offset between server time and local time in seconds (positive if the server is ahead)
+    pub server_time_offset: Option<i64>,
+    pub clock_skew_detected: bool,
+}
+
+/// result of a connectivity probe (see `Dracoon::health_check`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthStatus {
+    /// DRACOON responded to the (unauthenticated) software version request
+    pub is_reachable: bool,
+    /// the user account could be requested with the current access token
+    pub is_authenticated: bool,
+    /// REST API version (if reachable)
+    pub api_version: Option<String>,
+    pub reachability_latency: std::time::Duration,
+    /// latency of the authenticated request (None if DRACOON is not reachable)
+    pub auth_latency: Option<std::time::Duration>,
+}
+
 impl From<OAuth2TokenResponse> for Connection {
     /// transforms a `OAuth2` token response into a connection for the client
     fn from(value: OAuth2TokenResponse) -> Self {
         Self {
             connected_at: Utc::now(),
             access_token: SecretString::from(value.access_token),
-            refresh_token: SecretString::from(value.refresh_token),
+            refresh_token: value.refresh_token.map(SecretString::from),
             expires_in: value.expires_in,
+            scope: value.scope,
+            server_time_offset: None,
+            client_credentials: false,
         }
     }
 }
@@ -323,6 +480,133 @@ impl RetryableStrategy for DracoonCustomRetryStrategy {
     }
 }
 
+/// point in time of the next attempt as requested by the `Retry-After` header
+#[derive(Clone, Copy)]
+struct RetryAfter(SystemTime);
+
+/// Waits for the `Retry-After` of a 429 Too Many Requests response before the next attempt.
+/// Runs within the retry middleware - the extensions are shared by all attempts of a request,
+/// so the wait (clamped to the max. retry delay) is added to the regular backoff if it is longer.
+pub(crate) struct RetryAfterMiddleware;
+
+#[async_trait]
+impl Middleware for RetryAfterMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        if let Some(RetryAfter(retry_at)) = extensions.remove::<RetryAfter>() {
+            if let Ok(wait) = retry_at.duration_since(SystemTime::now()) {
+                debug!("Waiting {:?} before next attempt (Retry-After)", wait);
+                tokio::time::sleep(wait).await;
+            }
+        }
+
+        let response = next.run(req, extensions).await?;
+
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            if let Some(retry_after) = parse_retry_after(response.headers()) {
+                let retry_after = retry_after.min(Duration::from_millis(MAX_RETRY_DELAY));
+                extensions.insert(RetryAfter(SystemTime::now() + retry_after));
+            }
+        }
+
+        Ok(response)
+    }
+}
+
+/// Callback invoked after each request with method and path (e.g. "GET /api/v4/nodes/1"),
+/// elapsed time and response status
+pub type RequestObserver = Arc<dyn Fn(&str, Duration, StatusCode) + Send + Sync>;
+
+/// Invokes the request observer once per request (including all retried attempts).
+/// Requests without a response (e.g. connection errors) are not observed.
+/// OAuth token requests (token refresh and revoke) are skipped unless `observe_auth_requests` is set.
+pub(crate) struct RequestObserverMiddleware {
+    pub(crate) observer: RequestObserver,
+    pub(crate) observe_auth_requests: bool,
+}
+
+impl RequestObserverMiddleware {
+    fn is_auth_request(req: &Request) -> bool {
+        let path = req.url().path();
+
+        path.ends_with(DRACOON_TOKEN_URL) || path.ends_with(DRACOON_TOKEN_REVOKE_URL)
+    }
+}
+
+#[async_trait]
+impl Middleware for RequestObserverMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        if !self.observe_auth_requests && Self::is_auth_request(&req) {
+            return next.run(req, extensions).await;
+        }
+
+        // the query is never passed to the observer (may contain tokens)
+        let request = format!("{} {}", req.method(), req.url().path());
+        let start = Instant::now();
+
+        let response = next.run(req, extensions).await?;
+
+        (self.observer)(&request, start.elapsed(), response.status());
+
+        Ok(response)
+    }
+}
+
+/// max. size of a response body parsed by `parse_body` (set per response by the API client)
+#[derive(Clone, Copy)]
+pub(crate) struct MaxResponseBodySize(pub(crate) usize);
+
+/// Attaches the configured max. response body size to each API response.
+/// Not used for download clients - streamed content is never parsed.
+pub(crate) struct MaxResponseBodySizeMiddleware {
+    pub(crate) max_size: usize,
+}
+
+#[async_trait]
+impl Middleware for MaxResponseBodySizeMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let mut response = next.run(req, extensions).await?;
+
+        response
+            .extensions_mut()
+            .insert(MaxResponseBodySize(self.max_size));
+
+        Ok(response)
+    }
+}
+
+/// Parses the `Retry-After` header (delay in seconds or HTTP date)
+pub(crate) fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(header::RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let retry_at = DateTime::parse_from_rfc2822(value).ok()?;
+
+    // a date in the past means the request can be retried immediately
+    Some(
+        (retry_at.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
 // this overwrites the default retry strategy to handle 401 invalid JWT token errors that need to be retried
 // it is not possible to access the response body due to async / sync code limitations
 // therefore all 401 requests are retried
@@ -352,3 +636,102 @@ fn default_on_request_success(success: &reqwest::Response) -> Option<Retryable>
         Some(Retryable::Fatal)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(body: &str) -> DracoonErrorResponse {
+        serde_json::from_str(body).unwrap()
+    }
+
+    fn retry_after_headers(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::RETRY_AFTER, value.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_parse_retry_after_seconds() {
+        let headers = retry_after_headers("2");
+
+        assert_eq!(parse_retry_after(&headers), Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn test_parse_retry_after_http_date() {
+        let retry_at = Utc::now() + chrono::Duration::seconds(30);
+        let headers = retry_after_headers(&retry_at.to_rfc2822());
+
+        let retry_after = parse_retry_after(&headers).unwrap();
+
+        assert!(retry_after <= Duration::from_secs(30));
+        assert!(retry_after >= Duration::from_secs(28));
+    }
+
+    #[test]
+    fn test_parse_retry_after_http_date_in_past() {
+        let headers = retry_after_headers("Wed, 21 Oct 2015 07:28:00 GMT");
+
+        assert_eq!(parse_retry_after(&headers), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_parse_retry_after_invalid() {
+        assert_eq!(parse_retry_after(&retry_after_headers("soon")), None);
+        assert_eq!(parse_retry_after(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_error_code_node_not_found() {
+        let err = parse_error(
+            r#"{"code": 404, "message": "Not Found", "debugInfo": "Node not found", "errorCode": -40001}"#,
+        );
+
+        assert_eq!(err.error_code(), DracoonApiErrorCode::NodeNotFound);
+        assert_eq!(err.raw_error_code(), Some(-40001));
+    }
+
+    #[test]
+    fn test_error_code_user_locked() {
+        let err = parse_error(
+            r#"{"code": 401, "message": "Unauthorized", "debugInfo": "User is locked", "errorCode": -70020}"#,
+        );
+
+        assert_eq!(err.error_code(), DracoonApiErrorCode::UserLocked);
+    }
+
+    #[test]
+    fn test_error_code_file_key_not_found() {
+        let err = parse_error(
+            r#"{"code": 404, "message": "Not Found", "debugInfo": "File key not found", "errorCode": -40761}"#,
+        );
+
+        assert_eq!(err.error_code(), DracoonApiErrorCode::FileKeyNotFound);
+    }
+
+    #[test]
+    fn test_error_code_other() {
+        let err = parse_error(
+            r#"{"code": 400, "message": "Bad Request", "debugInfo": "Invalid email address", "errorCode": -10002}"#,
+        );
+
+        assert_eq!(err.error_code(), DracoonApiErrorCode::Other(-10002));
+        assert_eq!(err.error_code().code(), Some(-10002));
+    }
+
+    #[test]
+    fn test_error_code_unspecified() {
+        let err = parse_error(r#"{"code": 500, "message": "Internal Server Error"}"#);
+
+        assert_eq!(err.error_code(), DracoonApiErrorCode::Unspecified);
+        assert_eq!(err.error_code().code(), None);
+    }
+
+    #[test]
+    fn test_error_code_roundtrip() {
+        for code in [-20501, -40001, -40755, -40761, -70020, -70501] {
+            assert_eq!(DracoonApiErrorCode::from(code).code(), Some(code));
+        }
+    }
+}
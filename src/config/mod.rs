@@ -1,6 +1,7 @@
 mod models;
 
 use async_trait::async_trait;
+use futures_util::future::try_join;
 use reqwest::header;
 
 use crate::constants::{
@@ -25,6 +26,8 @@ pub trait Config {
 
     async fn get_password_policies(&self) -> Result<PasswordPoliciesConfig, DracoonClientError>;
 
+    async fn get_login_policies(&self) -> Result<LoginPoliciesConfig, DracoonClientError>;
+
     async fn get_classification_policies(
         &self,
     ) -> Result<ClassificationPoliciesConfig, DracoonClientError>;
@@ -143,6 +146,16 @@ impl Config for ConfigEndpoint<Connected> {
         PasswordPoliciesConfig::from_response(response).await
     }
 
+    async fn get_login_policies(&self) -> Result<LoginPoliciesConfig, DracoonClientError> {
+        let (password_policies, general_settings) =
+            try_join(self.get_password_policies(), self.get_general_settings()).await?;
+
+        Ok(LoginPoliciesConfig::new(
+            password_policies,
+            general_settings,
+        ))
+    }
+
     async fn get_algorithms(&self) -> Result<AlgorithmVersionInfoList, DracoonClientError> {
         let url_part = format!("/{DRACOON_API_PREFIX}/{CONFIG_BASE}/{CONFIG_ALGORITHMS}");
 
@@ -500,6 +513,65 @@ mod tests {
         assert_eq!(updated_by.user_type, UserType::Internal);
     }
 
+    #[tokio::test]
+    async fn test_get_login_policies() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let password_policies_res =
+            include_str!("../tests/responses/config/password_policies_ok.json")
+                .replace(
+                    r#""maxNumberOfLoginFailures": 0"#,
+                    r#""maxNumberOfLoginFailures": 5"#,
+                )
+                .replace(r#""lockoutPeriod": 0"#, r#""lockoutPeriod": 10"#);
+        let general_settings_res =
+            include_str!("../tests/responses/config/general_settings_ok.json")
+                .replace(
+                    r#""restrictionEnabled": false"#,
+                    r#""restrictionEnabled": true"#,
+                )
+                .replace(
+                    r#""accessTokenValidity": 0"#,
+                    r#""accessTokenValidity": 3600"#,
+                )
+                .replace(
+                    r#""refreshTokenValidity": 0"#,
+                    r#""refreshTokenValidity": 86400"#,
+                );
+
+        let password_policies_mock = mock_server
+            .mock("GET", "/api/v4/config/info/policies/passwords")
+            .with_status(200)
+            .with_body(password_policies_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let general_settings_mock = mock_server
+            .mock("GET", "/api/v4/config/info/general")
+            .with_status(200)
+            .with_body(general_settings_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let login_policies = client.config().get_login_policies().await.unwrap();
+
+        password_policies_mock.assert();
+        general_settings_mock.assert();
+
+        let user_lockout = login_policies.user_lockout.unwrap();
+        assert!(user_lockout.enabled);
+        assert_eq!(user_lockout.max_number_of_login_failures, Some(5));
+        assert_eq!(user_lockout.lockout_period, Some(10));
+
+        let password_expiration = login_policies.password_expiration.unwrap();
+        assert!(password_expiration.enabled);
+
+        let token_restrictions = login_policies.auth_token_restrictions.unwrap();
+        assert_eq!(token_restrictions.restriction_enabled, Some(true));
+        assert_eq!(token_restrictions.access_token_validity, Some(3600));
+        assert_eq!(token_restrictions.refresh_token_validity, Some(86400));
+    }
+
     #[tokio::test]
     async fn test_get_algorithms() {
         let (client, mut mock_server) = get_connected_client().await;
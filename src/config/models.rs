@@ -214,6 +214,38 @@ pub struct PasswordPoliciesConfig {
     pub encryption_password_policies: Option<EncryptionPasswordPolicies>,
 }
 
+/// Login related policies (lockout, session lifetimes) for login UIs.
+/// Aggregated from password policies and general settings.
+#[derive(Debug, Clone)]
+pub struct LoginPoliciesConfig {
+    pub user_lockout: Option<UserLockout>,
+    pub password_expiration: Option<PasswordExpiration>,
+    pub auth_token_restrictions: Option<AuthTokenRestrictions>,
+}
+
+impl LoginPoliciesConfig {
+    pub fn new(
+        password_policies: PasswordPoliciesConfig,
+        general_settings: GeneralSettingsInfo,
+    ) -> Self {
+        let (user_lockout, password_expiration) = password_policies
+            .login_password_policies
+            .map(|policies| {
+                (
+                    Some(policies.user_lockout),
+                    Some(policies.password_expiration),
+                )
+            })
+            .unwrap_or_default();
+
+        Self {
+            user_lockout,
+            password_expiration,
+            auth_token_restrictions: general_settings.auth_token_restrictions,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Feature {
@@ -4,7 +4,10 @@
 pub const GRANT_TYPE_PASSWORD: &str = "password";
 pub const GRANT_TYPE_AUTH_CODE: &str = "authorization_code";
 pub const GRANT_TYPE_REFRESH_TOKEN: &str = "refresh_token";
+pub const GRANT_TYPE_CLIENT_CREDENTIALS: &str = "client_credentials";
 pub const TOKEN_TYPE_HINT_ACCESS: &str = "access_token";
+// OAuth2 error returned for invalid, expired or revoked grants (e.g. refresh token)
+pub const OAUTH_ERROR_INVALID_GRANT: &str = "invalid_grant";
 
 // constants for API urls
 // AUTH
@@ -15,6 +18,8 @@ pub const TOKEN_TYPE_HINT_ACCESS_TOKEN: &str = "access_token";
 pub const TOKEN_TYPE_HINT_REFRESH_TOKEN: &str = "refresh_token";
 pub const MAX_TOKEN_COUNT: u8 = 5;
 pub const MIN_TOKEN_COUNT: u8 = 1;
+// max. offset between server and local time (in seconds) before a clock skew is reported
+pub const CLOCK_SKEW_THRESHOLD: i64 = 30;
 
 // API
 pub const DRACOON_API_PREFIX: &str = "api/v4";
@@ -31,6 +36,11 @@ pub const FILES_S3_COMPLETE: &str = "s3";
 pub const FOLDERS_BASE: &str = "folders";
 pub const NODES_DOWNLOAD_URL: &str = "downloads";
 pub const NODES_SEARCH: &str = "search";
+pub const NODES_FAVORITE: &str = "favorite";
+pub const NODES_COMMENTS: &str = "comments";
+pub const NODES_DELETED: &str = "deleted_nodes";
+pub const NODES_DELETED_VERSIONS: &str = "versions";
+pub const NODES_DELETED_RESTORE: &str = "actions/restore";
 pub const MISSING_FILE_KEYS: &str = "missingFileKeys";
 pub const FILES_KEYS: &str = "keys";
 pub const ROOMS_BASE: &str = "rooms";
@@ -55,11 +65,32 @@ pub const SHARES_DOWNLOAD: &str = "downloads";
 
 // DEFAULTS
 pub const DEFAULT_UPLOAD_CHUNK_SIZE: usize = 1024 * 1024 * 32; // 32 MB
+pub const S3_MAX_UPLOAD_PARTS: u64 = 10_000;
+pub const S3_MAX_UPLOAD_PART_SIZE: u64 = 1024 * 1024 * 1024 * 5; // 5 GB
 pub const DEFAULT_DOWNLOAD_CHUNK_SIZE: usize = 1024 * 1024 * 1024; // 1 GB
+pub const DEFAULT_DOWNLOAD_BYTES_MAX_SIZE: u64 = 1024 * 1024 * 100; // 100 MB
+pub const MAX_DOWNLOAD_REDIRECTS: usize = 10;
+pub const DOWNLOAD_TOKEN_QUERY_PARAM: &str = "access_token";
 pub const DEFAULT_UPLOAD_GRANULARITY: usize = 1024; // default upload stream granularity
 pub const POLLING_START_DELAY: u64 = 300;
+// max. time to wait for an S3 upload to complete in milliseconds (30 minutes)
+pub const UPLOAD_STATUS_TIMEOUT: u64 = 30 * 60 * 1000;
 // defines how many keys (users) distributed per file on upload
 pub const MISSING_KEYS_BATCH: usize = 50;
+// max. attempts without progress before distributing all missing keys is aborted
+pub const MISSING_KEYS_MAX_STABLE_ITERATIONS: u32 = 3;
+// classification levels (1: public, 2: internal, 3: confidential, 4: strictly confidential)
+pub const NODE_CLASSIFICATIONS: [u8; 4] = [1, 2, 3, 4];
+// defines how many pages are fetched concurrently when listing all nodes
+pub const NODES_PAGINATION_CONCURRENCY: usize = 4;
+// defines how many nodes are fetched concurrently when looking up nodes by id
+pub const NODES_LOOKUP_CONCURRENCY: usize = 5;
+// defines how many nodes are transferred concurrently when reporting results per node
+pub const NODES_TRANSFER_CONCURRENCY: usize = 5;
+// defines how many rooms are updated concurrently in batch operations
+pub const ROOMS_BATCH_CONCURRENCY: usize = 5;
+// defines how many S3 parts are uploaded concurrently (per file)
+pub const DEFAULT_UPLOAD_CONCURRENCY: usize = 4;
 
 // EVENTLOG
 pub const EVENTLOG_BASE: &str = "eventlog";
@@ -74,6 +105,11 @@ pub const USER_BASE: &str = "user";
 pub const USER_ACCOUNT: &str = "account";
 pub const USER_ACCOUNT_KEYPAIR: &str = "keypair";
 pub const USER_ACCOUNT_CUSTOMER: &str = "customer";
+pub const USER_SUBSCRIPTIONS: &str = "subscriptions";
+pub const USER_SUBSCRIPTIONS_NODES: &str = "nodes";
+pub const USER_OAUTH: &str = "oauth";
+pub const USER_OAUTH_AUTHORIZATIONS: &str = "authorizations";
+pub const USER_OAUTH_APPROVALS: &str = "approvals";
 
 // GROUPS
 pub const GROUPS_BASE: &str = "groups";
@@ -95,6 +131,8 @@ pub const PROVISIONING_TOKEN_HEADER: &str = "X-Sds-Service-Token";
 pub const ROLES_BASE: &str = "roles";
 pub const ROLES_GROUPS: &str = "groups";
 pub const ROLES_USERS: &str = "users";
+pub const ROLE_CONFIG_MANAGER: &str = "CONFIG_MANAGER";
+pub const ROLE_ROOM_MANAGER: &str = "ROOM_MANAGER";
 
 // SETTINGS
 pub const SETTINGS_BASE: &str = "settings";
@@ -130,6 +168,8 @@ pub const PUBLIC_INFO: &str = "info";
 pub const PUBLIC_SHARES_BASE: &str = "shares";
 pub const PUBLIC_DOWNLOAD_SHARES: &str = "downloads";
 pub const PUBLIC_UPLOAD_SHARES: &str = "uploads";
+// public link to a download share (web app)
+pub const PUBLIC_DOWNLOAD_SHARE_LINK: &str = "public/download-shares";
 
 /// user agent header
 pub const APP_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "|", env!("CARGO_PKG_VERSION"));
@@ -137,4 +177,8 @@ pub const APP_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "|", env!("CARG
 // retry config
 pub const MAX_RETRIES: u32 = 5;
 pub const MIN_RETRY_DELAY: u64 = 600; // in milliseconds (0.6 seconds)
+pub const RETRY_DELAY_LOWER_BOUND: u64 = 300; // in milliseconds (0.3 seconds)
 pub const MAX_RETRY_DELAY: u64 = 20 * 1000; // in milliseconds (20 seconds)
+pub const RETRY_MULTIPLIER: u32 = 2; // base of the exponential backoff
+pub const MIN_RETRY_MULTIPLIER: u32 = 1;
+pub const MAX_RETRY_MULTIPLIER: u32 = 10;
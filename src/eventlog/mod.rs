@@ -40,6 +40,8 @@ pub trait Eventlog {
     ///    .with_sort(EventlogSortBy::Time(SortOrder::Desc))
     ///    .with_user_id(1)
     ///    .with_operation_type(1)
+    ///    .with_operation_types([2, 3])
+    ///    .with_object_id(123)
     ///    .with_status(EventStatus::Success)
     ///    .with_date_end(chrono::Utc::now())
     ///    .build();
@@ -83,14 +85,18 @@ impl Eventlog for EventlogEndpoint<Connected> {
         let mut api_url = self.client().build_api_url(&url_part);
 
         if !params.is_empty() {
+            let operation_types = params.operation_types();
+
             api_url
                 .query_pairs_mut()
                 .extend_pairs(params.limit.map(|v| ("limit", v.to_string())))
                 .extend_pairs(params.offset.map(|v| ("offset", v.to_string())))
                 .extend_pairs(params.sort.map(|v| ("sort", v.to_sort_string())))
                 .extend_pairs(params.user_id.map(|v| ("user_id", v.to_string())))
-                .extend_pairs(params.operation_type.map(|v| ("type", v.to_string())))
+                .extend_pairs(operation_types.into_iter().map(|v| ("type", v.to_string())))
+                .extend_pairs(params.object_id.map(|v| ("object_id", v.to_string())))
                 .extend_pairs(params.status.map(|v| ("status", (v as i64).to_string())))
+                .extend_pairs(params.user_client.as_ref().map(|v| ("user_client", v)))
                 .extend_pairs(params.date_start.map(|v| {
                     (
                         "date_start",
@@ -367,6 +373,40 @@ mod tests {
         assert_eq!(events.items.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_get_events_with_multiple_operation_types() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let response = include_str!("../tests/responses/eventlog/events_ok.json");
+
+        let events_mock = mock_server
+            .mock("GET", "/api/v4/eventlog/events")
+            .match_query(Matcher::Regex(
+                "^user_id=4&type=1&type=2&type=3&object_id=5$".into(),
+            ))
+            .with_status(200)
+            .with_body(response)
+            .with_header("content-type", "application/json")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let params = EventlogParams::builder()
+            .with_operation_type(1)
+            .with_operation_types([1, 2, 3])
+            .with_user_id(4)
+            .with_object_id(5)
+            .build();
+
+        assert_eq!(params.operation_types(), vec![1, 2, 3]);
+
+        let events = client.eventlog().get_events(params).await;
+
+        events_mock.assert();
+
+        assert!(events.is_ok());
+    }
+
     #[tokio::test]
     async fn test_get_events_with_status_success() {
         let (client, mut mock_server) = get_connected_client().await;
@@ -116,6 +116,8 @@ pub struct EventlogParams {
     pub date_end: Option<DateTime<Utc>>,
     pub user_id: Option<i64>,
     pub operation_type: Option<i64>,
+    pub operation_types: Vec<i64>,
+    pub object_id: Option<i64>,
     pub status: Option<EventStatus>,
     pub user_client: Option<String>,
 }
@@ -125,6 +127,19 @@ impl EventlogParams {
         EventlogParamsBuilder::new()
     }
 
+    /// Returns all operation types to filter by (without duplicates)
+    pub fn operation_types(&self) -> Vec<i64> {
+        let mut operation_types = Vec::with_capacity(self.operation_types.len() + 1);
+
+        for operation_type in self.operation_type.iter().chain(&self.operation_types) {
+            if !operation_types.contains(operation_type) {
+                operation_types.push(*operation_type);
+            }
+        }
+
+        operation_types
+    }
+
     pub fn is_empty(&self) -> bool {
         self.offset.is_none()
             && self.limit.is_none()
@@ -133,6 +148,8 @@ impl EventlogParams {
             && self.date_end.is_none()
             && self.user_id.is_none()
             && self.operation_type.is_none()
+            && self.operation_types.is_empty()
+            && self.object_id.is_none()
             && self.status.is_none()
             && self.user_client.is_none()
     }
@@ -147,6 +164,8 @@ pub struct EventlogParamsBuilder {
     pub date_end: Option<DateTime<Utc>>,
     pub user_id: Option<i64>,
     pub operation_type: Option<i64>,
+    pub operation_types: Vec<i64>,
+    pub object_id: Option<i64>,
     pub status: Option<EventStatus>,
     pub user_client: Option<String>,
 }
@@ -191,6 +210,18 @@ impl EventlogParamsBuilder {
         self
     }
 
+    /// Adds operation types to filter by (emitted as repeated `type` params).
+    /// Can be combined with `with_operation_type`.
+    pub fn with_operation_types(mut self, operation_types: impl IntoIterator<Item = i64>) -> Self {
+        self.operation_types.extend(operation_types);
+        self
+    }
+
+    pub fn with_object_id(mut self, object_id: i64) -> Self {
+        self.object_id = Some(object_id);
+        self
+    }
+
     pub fn with_status(mut self, status: EventStatus) -> Self {
         self.status = Some(status);
         self
@@ -210,6 +241,8 @@ impl EventlogParamsBuilder {
             date_end: self.date_end,
             user_id: self.user_id,
             operation_type: self.operation_type,
+            operation_types: self.operation_types,
+            object_id: self.object_id,
             status: self.status,
             user_client: self.user_client,
         }
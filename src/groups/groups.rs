@@ -1,5 +1,10 @@
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
+use async_stream::try_stream;
 use async_trait::async_trait;
+use futures_util::stream::BoxStream;
 use reqwest::header;
+use tracing::{debug, error};
 
 use crate::{
     client::Connected,
@@ -18,34 +23,58 @@ impl Groups for GroupsEndpoint<Connected> {
         &self,
         params: Option<ListAllParams>,
     ) -> Result<GroupList, DracoonClientError> {
-        let params = params.unwrap_or_default();
-        let url_part = format!("/{DRACOON_API_PREFIX}/{GROUPS_BASE}");
+        self.get_groups_page(&params.unwrap_or_default()).await
+    }
 
-        let mut api_url = self.client().build_api_url(&url_part);
+    async fn get_all_groups(
+        &self,
+        params: Option<ListAllParams>,
+    ) -> Result<GroupList, DracoonClientError> {
+        let mut params = params.unwrap_or_default();
+        let mut groups = self.get_groups_page(&params).await?;
+        let mut offset = params.offset.unwrap_or(0) + groups.items.len() as u64;
 
-        let filters = params.filter_to_string();
-        let sorts = params.sort_to_string();
+        while offset < groups.range.total {
+            params.offset = Some(offset);
+            let next_groups = self.get_groups_page(&params).await?;
 
-        api_url
-            .query_pairs_mut()
-            .extend_pairs(params.limit.map(|v| ("limit", v.to_string())))
-            .extend_pairs(params.offset.map(|v| ("offset", v.to_string())))
-            .extend_pairs(params.sort.map(|_| ("sort", sorts)))
-            .extend_pairs(params.filter.map(|_| ("filter", filters)))
-            .finish();
+            if next_groups.items.is_empty() {
+                break;
+            }
 
-        let response = self
-            .client()
-            .http
-            .get(api_url)
-            .header(
-                header::AUTHORIZATION,
-                self.client().get_auth_header().await?,
-            )
-            .send()
-            .await?;
+            offset += next_groups.items.len() as u64;
+            groups.items.extend(next_groups.items);
+        }
 
-        GroupList::from_response(response).await
+        groups.range.limit = groups.items.len() as u64;
+
+        Ok(groups)
+    }
+
+    fn get_groups_stream(
+        &self,
+        params: Option<ListAllParams>,
+    ) -> BoxStream<'_, Result<Group, DracoonClientError>> {
+        Box::pin(try_stream! {
+            let mut params = params.unwrap_or_default();
+
+            loop {
+                let groups = self.get_groups_page(&params).await?;
+                let offset = params.offset.unwrap_or(0) + groups.items.len() as u64;
+                let total = groups.range.total;
+                let is_empty = groups.items.is_empty();
+
+                for group in groups.items {
+                    yield group;
+                }
+
+                if is_empty || offset >= total {
+                    break;
+                }
+
+                params.offset = Some(offset);
+            }
+        })
     }
 
     async fn create_group(&self, group: CreateGroupRequest) -> Result<Group, DracoonClientError> {
@@ -245,4 +274,148 @@ impl Groups for GroupsEndpoint<Connected> {
 
         LastAdminGroupRoomList::from_response(response).await
     }
+
+    async fn find_duplicate_groups(&self) -> Result<Vec<Vec<Group>>, DracoonClientError> {
+        let groups = self.get_all_groups(None).await?;
+
+        let mut clusters: Vec<Vec<Group>> = Vec::new();
+        let mut cluster_idx: HashMap<String, usize> = HashMap::new();
+
+        for group in groups.items {
+            match cluster_idx.entry(group.name.to_lowercase()) {
+                Entry::Occupied(entry) => clusters[*entry.get()].push(group),
+                Entry::Vacant(entry) => {
+                    entry.insert(clusters.len());
+                    clusters.push(vec![group]);
+                }
+            }
+        }
+
+        clusters.retain(|groups| groups.len() > 1);
+
+        Ok(clusters)
+    }
+
+    async fn merge_groups(
+        &self,
+        keep_id: u64,
+        merge_ids: Vec<u64>,
+    ) -> Result<MergeGroupsResult, DracoonClientError> {
+        let mut member_ids: HashSet<_> = self
+            .get_all_group_member_ids(keep_id)
+            .await?
+            .into_iter()
+            .collect();
+
+        // never merge (delete) the group to keep
+        let mut seen = HashSet::new();
+        let merge_ids: Vec<_> = merge_ids
+            .into_iter()
+            .filter(|merge_id| *merge_id != keep_id && seen.insert(*merge_id))
+            .collect();
+
+        // collect all members before any group is deleted
+        let mut user_ids = Vec::new();
+        for merge_id in &merge_ids {
+            for user_id in self.get_all_group_member_ids(*merge_id).await? {
+                if member_ids.insert(user_id) {
+                    user_ids.push(user_id);
+                }
+            }
+        }
+
+        if !user_ids.is_empty() {
+            debug!(
+                "Adding {} users of groups {:?} to group {}",
+                user_ids.len(),
+                merge_ids,
+                keep_id
+            );
+            self.add_group_users(keep_id, user_ids.into()).await?;
+        }
+
+        let mut merged_ids = Vec::with_capacity(merge_ids.len());
+        let mut failed = Vec::new();
+
+        for merge_id in merge_ids {
+            match self.delete_group(merge_id).await {
+                Ok(()) => merged_ids.push(merge_id),
+                Err(err) => {
+                    error!("Error deleting merged group {}: {}", merge_id, err);
+                    failed.push((merge_id, err));
+                }
+            }
+        }
+
+        Ok(MergeGroupsResult {
+            group: self.get_group(keep_id).await?,
+            merged_ids,
+            failed,
+        })
+    }
+}
+
+impl GroupsEndpoint<Connected> {
+    /// Fetches the user ids of all group members (all pages)
+    /// Users without a valid id (e.g. deleted users) are skipped
+    async fn get_all_group_member_ids(
+        &self,
+        group_id: u64,
+    ) -> Result<Vec<u64>, DracoonClientError> {
+        let mut users = self.get_group_users(group_id, None).await?;
+        let mut offset = users.items.len() as u64;
+
+        while offset < users.range.total {
+            let params = ListAllParams::builder().with_offset(offset).build();
+            let next_users = self.get_group_users(group_id, Some(params)).await?;
+
+            if next_users.items.is_empty() {
+                break;
+            }
+
+            offset += next_users.items.len() as u64;
+            users.items.extend(next_users.items);
+        }
+
+        Ok(users
+            .items
+            .into_iter()
+            .filter(|user| user.is_member)
+            .filter_map(|user| u64::try_from(user.user_info.id).ok())
+            .collect())
+    }
+
+    /// Fetches a single page of groups
+    async fn get_groups_page(
+        &self,
+        params: &ListAllParams,
+    ) -> Result<GroupList, DracoonClientError> {
+        let url_part = format!("/{DRACOON_API_PREFIX}/{GROUPS_BASE}");
+
+        let mut api_url = self.client().build_api_url(&url_part);
+
+        let filters = params.filter_to_string();
+        let sorts = params.sort_to_string();
+
+        api_url
+            .query_pairs_mut()
+            .extend_pairs(params.limit.map(|v| ("limit", v.to_string())))
+            .extend_pairs(params.offset.map(|v| ("offset", v.to_string())))
+            .extend_pairs(params.sort.as_ref().map(|_| ("sort", sorts)))
+            .extend_pairs(params.filter.as_ref().map(|_| ("filter", filters)))
+            .finish();
+
+        let response = self
+            .client()
+            .http
+            .get(api_url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .send()
+            .await?;
+
+        GroupList::from_response(response).await
+    }
 }
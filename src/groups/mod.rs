@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use futures_util::stream::BoxStream;
 
 #[allow(clippy::module_inception)]
 mod groups;
@@ -42,6 +43,57 @@ pub trait Groups {
         &self,
         params: Option<ListAllParams>,
     ) -> Result<GroupList, DracoonClientError>;
+    /// Get a list of all groups (including member count `cnt_users`) - all pages are fetched.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Groups};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let groups = dracoon.groups().get_all_groups(None).await.unwrap();
+    ///
+    /// for group in groups.items {
+    ///     println!("{}: {} members", group.name, group.cnt_users.unwrap_or(0));
+    /// }
+    /// # }
+    /// ```
+    async fn get_all_groups(
+        &self,
+        params: Option<ListAllParams>,
+    ) -> Result<GroupList, DracoonClientError>;
+    /// Returns a stream of all groups - pages are fetched as the stream is consumed.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Groups};
+    /// # use futures_util::TryStreamExt;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let mut groups = dracoon.groups().get_groups_stream(None);
+    ///
+    /// while let Some(group) = groups.try_next().await.unwrap() {
+    ///     println!("{}: {} members", group.name, group.cnt_users.unwrap_or(0));
+    /// }
+    /// # }
+    /// ```
+    fn get_groups_stream(
+        &self,
+        params: Option<ListAllParams>,
+    ) -> BoxStream<'_, Result<Group, DracoonClientError>>;
     /// Create a group.
     /// ```no_run
     /// # use dco3::{Dracoon, auth::OAuth2Flow, Groups, groups::{CreateGroupRequest}};
@@ -225,4 +277,60 @@ pub trait Groups {
         &self,
         group_id: u64,
     ) -> Result<LastAdminGroupRoomList, DracoonClientError>;
+    /// Finds groups sharing the same name (case-insensitive) - all groups are fetched.
+    /// Returns one cluster per duplicated name (groups without duplicates are not returned).
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Groups};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let duplicates = dracoon.groups().find_duplicate_groups().await.unwrap();
+    ///
+    /// for groups in duplicates {
+    ///     let ids: Vec<_> = groups.iter().map(|group| group.id).collect();
+    ///     println!("{}: {:?}", groups[0].name, ids);
+    /// }
+    /// # }
+    /// ```
+    async fn find_duplicate_groups(&self) -> Result<Vec<Vec<Group>>, DracoonClientError>;
+    /// Merges groups into the group to keep: all members of the merged groups are added to
+    /// the kept group, then the merged groups are deleted.
+    /// Members of all groups are collected and added before any group is deleted - if this fails,
+    /// no group is deleted. Groups that cannot be deleted are reported in `failed`.
+    /// Room permissions of the merged groups are not moved and are lost when the groups are
+    /// deleted - grant the kept group access to these rooms first (see `Rooms::update_room_groups`).
+    /// Returns the kept group (including all members) and the merged group ids.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Groups};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let result = dracoon.groups().merge_groups(123, vec![456, 789]).await.unwrap();
+    ///
+    /// for (group_id, err) in result.failed {
+    ///     println!("Group {} not deleted: {}", group_id, err);
+    /// }
+    /// # }
+    /// ```
+    async fn merge_groups(
+        &self,
+        keep_id: u64,
+        merge_ids: Vec<u64>,
+    ) -> Result<MergeGroupsResult, DracoonClientError>;
 }
@@ -57,6 +57,17 @@ impl FromResponse for GroupList {
     }
 }
 
+/// Result of merging groups (see `merge_groups`)
+#[derive(Debug)]
+pub struct MergeGroupsResult {
+    /// the kept group (including all added members)
+    pub group: Group,
+    /// ids of the merged (deleted) groups
+    pub merged_ids: Vec<u64>,
+    /// groups that could not be deleted - their members are already added to the kept group
+    pub failed: Vec<(u64, DracoonClientError)>,
+}
+
 #[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateGroupRequest {
@@ -159,6 +159,30 @@
 //! }
 //! ```
 //!
+//! ### Client Credentials
+//!
+//! ```no_run
+//! use dco3::{Dracoon, OAuth2Flow};
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!
+//!  // service-to-service: only the client id and secret are used
+//!  let dracoon = Dracoon::builder()
+//!   .with_base_url("https://dracoon.team")
+//!   .with_client_id("client_id")
+//!   .with_client_secret("client_secret")
+//!   .build()
+//!   .unwrap()
+//!   .connect(OAuth2Flow::client_credentials())
+//!   .await
+//!   .unwrap();
+//!
+//!  // there is no refresh token - a new access token is requested once expired
+//!
+//! }
+//! ```
+//!
 //!
 //! ## Error handling
 //!
@@ -424,29 +448,32 @@
 //! ## Examples
 //! For an example client implementation, see the [dccmd-rs](https://github.com/unbekanntes-pferd/dccmd-rs) repository.
 
-use std::{marker::PhantomData, sync::Arc};
+use std::{marker::PhantomData, sync::Arc, time::Instant};
 
-use client::{GetClient, Provisioning};
+use chrono::FixedOffset;
+use client::{GetClient, HealthStatus, JitterMode, Provisioning, TokenHealth};
 use config::ConfigEndpoint;
 use dco3_crypto::PlainUserKeyPairContainer;
 use eventlog::EventlogEndpoint;
 use groups::GroupsEndpoint;
-use nodes::NodesEndpoint;
+use nodes::{DownloadAuthMode, NodesEndpoint};
 use provisioning::ProvisioningEndpoint;
 use public::{PublicEndpoint, SystemInfo};
-use reqwest::Url;
+use reqwest::{header, Method, Response, Url};
 use roles::RolesEndpoint;
 use secrecy::{ExposeSecret, SecretBox, SecretString};
 use settings::SettingsEndpoint;
 use shares::SharesEndpoint;
 use system::SystemEndpoint;
+use tokio::sync::Mutex;
+use tracing::{error, warn};
 use user::UserEndpoint;
 use users::UsersEndpoint;
 
 use self::{
     client::{Connected, Disconnected},
     client::{DracoonClient, DracoonClientBuilder},
-    user::models::UserAccount,
+    user::models::{UserAccount, UserContext},
 };
 
 // re-export traits and base models
@@ -457,14 +484,17 @@ pub use self::{
     eventlog::Eventlog,
     groups::Groups,
     models::*,
-    nodes::{Download, Folders, MissingFileKeys, Nodes, Rooms, Upload},
+    nodes::{
+        Download, Folders, MissingFileKeys, NodeComments, Nodes, PendingUploads, RecycleBin,
+        ResumableUpload, Rooms, Upload, UploadDirectory,
+    },
     provisioning::CustomerProvisioning,
     public::{Public, PublicDownload, PublicUpload},
     roles::Roles,
     settings::RescueKeyPair,
     shares::{DownloadShares, UploadShares},
     system::AuthenticationMethods,
-    user::{User, UserAccountKeyPairs},
+    user::{Subscriptions, User, UserAccountKeyPairs},
     users::Users,
 };
 
@@ -493,6 +523,7 @@ pub struct Dracoon<State = Disconnected> {
     state: PhantomData<State>,
     user_info: Container<UserAccount>,
     keypair: Container<SecretBox<WrappedUserKeypair>>,
+    keypair_fetch: Arc<Mutex<()>>,
     system_info: Container<SystemInfo>,
     encryption_secret: Option<SecretString>,
     endpoints: Endpoints<State>,
@@ -569,18 +600,35 @@ impl DracoonBuilder {
         self
     }
 
-    /// Sets a custom min. retry delay
+    /// Sets a custom min. retry delay in milliseconds (300 - 20000, default: 600)
+    /// Building fails with `DracoonClientError::InvalidRetryConfig` if it is out of range
+    /// or exceeds the (default) max. retry delay.
     pub fn with_min_retry_delay(mut self, min_retry_delay: u64) -> Self {
         self.client_builder = self.client_builder.with_min_retry_delay(min_retry_delay);
         self
     }
 
-    /// Sets a custom max. retry delay
+    /// Sets a custom max. retry delay in milliseconds (300 - 20000, default: 20000)
+    /// Building fails with `DracoonClientError::InvalidRetryConfig` if it is out of range
+    /// or below the (default) min. retry delay.
     pub fn with_max_retry_delay(mut self, max_retry_delay: u64) -> Self {
         self.client_builder = self.client_builder.with_max_retry_delay(max_retry_delay);
         self
     }
 
+    /// Sets a custom retry multiplier (base of the exponential backoff, 1 - 10, default: 2)
+    /// Building fails with `DracoonClientError::InvalidRetryConfig` for other values.
+    pub fn with_retry_multiplier(mut self, retry_multiplier: u32) -> Self {
+        self.client_builder = self.client_builder.with_retry_multiplier(retry_multiplier);
+        self
+    }
+
+    /// Sets how jitter is applied to the retry intervals (default: bounded)
+    pub fn with_retry_jitter(mut self, retry_jitter: JitterMode) -> Self {
+        self.client_builder = self.client_builder.with_retry_jitter(retry_jitter);
+        self
+    }
+
     /// Sets X-SDS-Service-token for DRACOON customer provisioning
     pub fn with_provisioning_token(mut self, provisioning_token: impl Into<String>) -> Self {
         self.client_builder = self
@@ -589,6 +637,95 @@ impl DracoonBuilder {
         self
     }
 
+    /// Sets how the access token is passed when downloading from a download url
+    /// (default: no token - download urls are presigned)
+    pub fn with_download_auth_mode(mut self, download_auth_mode: DownloadAuthMode) -> Self {
+        self.client_builder = self
+            .client_builder
+            .with_download_auth_mode(download_auth_mode);
+        self
+    }
+
+    /// Sets the timezone (offset) timestamps are sent in for create and update requests
+    /// (folders, rooms and file uploads) - default: UTC
+    pub fn with_timestamp_timezone(mut self, timezone: FixedOffset) -> Self {
+        self.client_builder = self.client_builder.with_timestamp_timezone(timezone);
+        self
+    }
+
+    /// Enables or disables gzip / brotli response compression (default: enabled)
+    /// Only applies to API responses - downloads always return the raw bytes.
+    pub fn with_compression(mut self, compression: bool) -> Self {
+        self.client_builder = self.client_builder.with_compression(compression);
+        self
+    }
+
+    /// Sets a proxy (url) for all requests (API, uploads and downloads)
+    /// Building fails with `DracoonClientError::InvalidProxyUrl` if the url cannot be parsed.
+    pub fn with_proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.client_builder = self.client_builder.with_proxy(proxy);
+        self
+    }
+
+    /// Sets basic auth credentials for the proxy (requires a proxy)
+    /// The password is kept as secret and never logged.
+    pub fn with_proxy_auth(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.client_builder = self.client_builder.with_proxy_auth(username, password);
+        self
+    }
+
+    /// Adds a custom middleware (e.g. tracing or metrics) to the API and download clients
+    /// Middleware is appended in the order it is added - the retry middleware always runs first,
+    /// so every custom middleware is called for each (retried) attempt.
+    /// Uploads to S3 urls are sent without middleware.
+    pub fn with_middleware(mut self, middleware: impl reqwest_middleware::Middleware) -> Self {
+        self.client_builder = self.client_builder.with_middleware(middleware);
+        self
+    }
+
+    /// Sets a callback invoked after each request with method and path, elapsed time and status
+    /// The elapsed time includes all retried attempts of a request.
+    /// OAuth token requests (e.g. token refresh) are not observed unless enabled via `with_observe_auth_requests`.
+    /// ```no_run
+    /// # use dco3::Dracoon;
+    /// # use std::sync::Arc;
+    /// let dracoon = Dracoon::builder()
+    ///    .with_base_url("https://dracoon.team")
+    ///    .with_client_id("client_id")
+    ///    .with_client_secret("client_secret")
+    ///    .with_request_observer(Arc::new(|request, elapsed, status| {
+    ///        println!("{request}: {status} ({elapsed:?})");
+    ///    }))
+    ///    .build()
+    ///    .unwrap();
+    /// ```
+    pub fn with_request_observer(mut self, observer: client::RequestObserver) -> Self {
+        self.client_builder = self.client_builder.with_request_observer(observer);
+        self
+    }
+
+    /// Enables or disables observing OAuth token requests (default: disabled)
+    pub fn with_observe_auth_requests(mut self, observe_auth_requests: bool) -> Self {
+        self.client_builder = self
+            .client_builder
+            .with_observe_auth_requests(observe_auth_requests);
+        self
+    }
+
+    /// Sets the max. size (in bytes) of API response bodies (default: no limit)
+    /// Parsing a larger body fails with `DracoonClientError::ResponseTooLarge`.
+    /// Downloads are streamed and not affected.
+    pub fn with_max_response_body_size(mut self, max_response_body_size: usize) -> Self {
+        self.client_builder = self
+            .client_builder
+            .with_max_response_body_size(max_response_body_size);
+        self
+    }
+
     /// Sets the token rotation for the client (use amount of tokens per client)
     #[doc(hidden = "Experimental")]
     pub fn with_token_rotation(mut self, token_rotation: u8) -> Self {
@@ -610,7 +747,8 @@ impl DracoonBuilder {
             client: dracoon,
             state: PhantomData,
             user_info: Container::new(),
-            keypair: Container::new(),
+            keypair: endpoints.user.keypair().clone(),
+            keypair_fetch: endpoints.user.keypair_fetch().clone(),
             system_info: Container::new(),
             encryption_secret: self.encryption_secret,
             endpoints,
@@ -627,7 +765,8 @@ impl DracoonBuilder {
             client: dracoon,
             state: PhantomData,
             user_info: Container::new(),
-            keypair: Container::new(),
+            keypair: endpoints.user.keypair().clone(),
+            keypair_fetch: endpoints.user.keypair_fetch().clone(),
             system_info: Container::new(),
             encryption_secret: None,
             endpoints,
@@ -653,7 +792,8 @@ impl Dracoon<Disconnected> {
             client: connected_client,
             state: PhantomData,
             user_info: Container::new(),
-            keypair: Container::new(),
+            keypair: endpoints.user.keypair().clone(),
+            keypair_fetch: endpoints.user.keypair_fetch().clone(),
             system_info: Container::new(),
             encryption_secret: self.encryption_secret,
             endpoints,
@@ -693,6 +833,76 @@ impl Dracoon<Connected> {
         self.client.get_refresh_token().await
     }
 
+    /// Returns the health of the access token (validity, remaining lifetime and detected clock skew)
+    pub async fn token_health(&self) -> TokenHealth {
+        self.client.token_health().await
+    }
+
+    /// Checks if DRACOON is reachable (software version) and the client is authenticated (user account)
+    /// Errors are not returned but reflected in the status (including timings).
+    pub async fn health_check(&self) -> HealthStatus {
+        let start = Instant::now();
+        let version = self.public().get_software_version().await;
+        let reachability_latency = start.elapsed();
+
+        let (is_reachable, api_version) = match version {
+            Ok(version) => (true, Some(version.rest_api_version)),
+            // any HTTP error response means DRACOON was reached
+            Err(err) => {
+                warn!("Health check: software version request failed: {}", err);
+                (err.is_http_error(), None)
+            }
+        };
+
+        if !is_reachable {
+            return HealthStatus {
+                is_reachable,
+                is_authenticated: false,
+                api_version,
+                reachability_latency,
+                auth_latency: None,
+            };
+        }
+
+        let start = Instant::now();
+        let account = self.user().get_user_account().await;
+        let auth_latency = start.elapsed();
+
+        if let Err(err) = &account {
+            warn!("Health check: user account request failed: {}", err);
+        }
+
+        HealthStatus {
+            is_reachable,
+            is_authenticated: account.is_ok(),
+            api_version,
+            reachability_latency,
+            auth_latency: Some(auth_latency),
+        }
+    }
+
+    /// Returns the scopes granted by the authorization server for the connection
+    pub async fn granted_scopes(&self) -> Vec<String> {
+        self.client.granted_scopes().await
+    }
+
+    /// Returns the number of connections (access tokens) used in the token rotation
+    /// including the main connection (1 if token rotation is disabled)
+    pub async fn rotation_token_count(&self) -> usize {
+        self.client.rotation_token_count().await
+    }
+
+    /// Proactively refreshes all connections used in the token rotation
+    pub async fn refresh_all_rotation_tokens(&self) -> Result<(), DracoonClientError> {
+        self.client.refresh_all_rotation_tokens().await
+    }
+
+    /// Refreshes only the expired connections used in the token rotation
+    /// Returns the number of refreshed connections
+    pub async fn rotate_and_refresh_expired(&self) -> Result<usize, DracoonClientError> {
+        self.client.refresh_expired_rotation_tokens().await
+    }
+
     pub async fn get_user_info(&self) -> Result<UserAccount, DracoonClientError> {
         if self.user_info.is_none().await {
             let user_info = self.user().get_user_account().await?;
@@ -703,6 +913,18 @@ impl Dracoon<Connected> {
         Ok(user_info)
     }
 
+    /// Returns the authenticated user account bundled with the effective roles.
+    /// Reuses the cached user account (see `get_user_info`) - the account is only requested once.
+    pub async fn get_user_context(&self) -> Result<UserContext, DracoonClientError> {
+        Ok(self.get_user_info().await?.into())
+    }
+
+    /// Returns the id of the currently authenticated user.
+    /// The user account is only requested once and cached afterwards.
+    pub async fn current_user_id(&self) -> Result<u64, DracoonClientError> {
+        Ok(self.get_user_info().await?.id)
+    }
+
     pub async fn get_system_info(&self) -> Result<SystemInfo, DracoonClientError> {
         if self.system_info.is_none().await {
             let system_info = self.public().get_system_info().await?;
@@ -719,19 +941,106 @@ impl Dracoon<Connected> {
         secret: Option<String>,
     ) -> Result<PlainUserKeyPairContainer, DracoonClientError> {
         if self.keypair.is_none().await {
-            if let Some(secret) = secret {
+            let Some(secret) = secret else {
+                return Err(DracoonClientError::MissingEncryptionSecret);
+            };
+
+            // concurrent callers wait for the first fetch instead of fetching (and decrypting) again
+            let _guard = self.keypair_fetch.lock().await;
+
+            if self.keypair.is_none().await {
                 let keypair = self.user().get_user_keypair(&secret).await?;
                 self.keypair
                     .set(SecretBox::new(Box::new(WrappedUserKeypair::new(keypair))))
                     .await;
-            } else {
-                return Err(DracoonClientError::MissingEncryptionSecret);
             }
         }
 
-        let keypair = self.keypair.get().await.expect("No keypair set");
+        // the keypair might have been cleared in the meantime
+        let keypair = self
+            .keypair
+            .get()
+            .await
+            .ok_or(DracoonClientError::MissingEncryptionSecret)?;
         Ok(keypair.expose_secret().keypair().clone())
     }
+
+    /// Removes the cached keypair - encrypted transfers require a secret again
+    /// (see `get_keypair` or `set_keypair_secret`).
+    pub async fn clear_keypair(&self) {
+        let _guard = self.keypair_fetch.lock().await;
+        self.keypair.clear().await;
+    }
+
+    /// Fetches and decrypts the keypair with the given secret and replaces the cached keypair.
+    /// Use this if the encryption password changed - the cached keypair is kept on error.
+    pub async fn set_keypair_secret(&self, secret: String) -> Result<(), DracoonClientError> {
+        let _guard = self.keypair_fetch.lock().await;
+
+        let keypair = self.user().get_user_keypair(&secret).await?;
+        self.keypair
+            .set(SecretBox::new(Box::new(WrappedUserKeypair::new(keypair))))
+            .await;
+
+        Ok(())
+    }
+
+    /// Sends an authenticated request to an endpoint that is not (yet) covered by this crate.
+    /// This is an escape hatch: the path is joined with the base url and retries apply,
+    /// paths pointing to another origin (e.g. absolute urls) are rejected with `InvalidPath`,
+    /// but the response is returned as is (including error status codes) and must be parsed by the caller.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow};
+    /// # use reqwest::Method;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let response = dracoon
+    ///     .raw_request(Method::GET, "/api/v4/nodes?parent_id=0", None)
+    ///     .await
+    ///     .unwrap();
+    /// let body = response.text().await.unwrap();
+    /// # }
+    /// ```
+    pub async fn raw_request(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<Response, DracoonClientError> {
+        let base_url = self.get_base_url();
+        let api_url = base_url
+            .join(path)
+            .map_err(|_| DracoonClientError::InvalidPath(path.to_string()))?;
+
+        // absolute or scheme-relative paths replace the host - never send the token elsewhere
+        if api_url.origin() != base_url.origin() {
+            error!("Refusing raw request to foreign origin: {}", path);
+            return Err(DracoonClientError::InvalidPath(path.to_string()));
+        }
+
+        let mut request = self
+            .client
+            .http
+            .request(method, api_url)
+            .header(header::AUTHORIZATION, self.get_auth_header().await?);
+
+        if let Some(body) = body {
+            request = request
+                .header(header::CONTENT_TYPE, "application/json")
+                .json(&body);
+        }
+
+        Ok(request.send().await?)
+    }
 }
 
 impl Dracoon<Provisioning> {
@@ -819,7 +1128,9 @@ pub mod auth {
 
     pub mod models {
         /// re-export client models for auth
-        pub use crate::client::models::{DracoonAuthErrorResponse, DracoonErrorResponse};
+        pub use crate::client::models::{
+            DracoonApiErrorCode, DracoonAuthErrorResponse, DracoonErrorResponse,
+        };
     }
 }
 
@@ -1,8 +1,9 @@
 //! This module implments basic models for the DRACOON API.
 use std::fmt::Debug;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use dco3_crypto::PlainUserKeyPairContainer;
+use openssl::hash::MessageDigest;
 use secrecy::{zeroize::Zeroize, CloneableSecret};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -19,6 +20,41 @@ use super::client::errors::DracoonClientError;
 
 pub trait ConnectedClient {}
 
+/// Expected hash (hex encoded) of a file's (plain) content - used to verify transfers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContentHash {
+    Md5(String),
+    Sha256(String),
+}
+
+impl ContentHash {
+    pub fn md5(hash: impl Into<String>) -> Self {
+        ContentHash::Md5(hash.into())
+    }
+
+    pub fn sha256(hash: impl Into<String>) -> Self {
+        ContentHash::Sha256(hash.into())
+    }
+
+    pub fn value(&self) -> &str {
+        match self {
+            ContentHash::Md5(hash) | ContentHash::Sha256(hash) => hash,
+        }
+    }
+
+    /// Compares the hash (case-insensitive) with a computed hex encoded hash
+    pub fn matches(&self, hash: &str) -> bool {
+        self.value().eq_ignore_ascii_case(hash)
+    }
+
+    pub(crate) fn message_digest(&self) -> MessageDigest {
+        match self {
+            ContentHash::Md5(_) => MessageDigest::md5(),
+            ContentHash::Sha256(_) => MessageDigest::sha256(),
+        }
+    }
+}
+
 // struct for internal mutability
 #[derive(Debug, Clone, Default)]
 pub struct Container<T: Clone> {
@@ -48,6 +84,11 @@ impl<T: Clone> Container<T> {
         lock.clone()
     }
 
+    pub async fn clear(&self) {
+        let mut lock = self.data.write().await;
+        *lock = None;
+    }
+
     pub async fn is_some(&self) -> bool {
         let lock = self.data.read().await;
         lock.is_some()
@@ -247,6 +288,21 @@ impl ObjectExpiration {
             expire_at: Some(expire_at.to_rfc3339()),
         }
     }
+
+    /// Expiration at the given point in time
+    pub fn at(expire_at: DateTime<Utc>) -> Self {
+        Self::new(expire_at)
+    }
+
+    /// Expiration in the given amount of days (from now)
+    pub fn in_days(days: u32) -> Self {
+        Self::new(Utc::now() + Duration::days(days.into()))
+    }
+
+    /// Expiration in the given amount of hours (from now)
+    pub fn in_hours(hours: u32) -> Self {
+        Self::new(Utc::now() + Duration::hours(hours.into()))
+    }
 }
 
 impl AsRef<ObjectExpiration> for ObjectExpiration {
@@ -546,6 +602,72 @@ mod tests {
 
         assert!(query.is_err());
     }
+
+    #[test]
+    fn test_object_expiration_at() {
+        let expire_at: DateTime<Utc> = "2030-01-01T00:00:00Z".parse().unwrap();
+        let expiration = ObjectExpiration::at(expire_at);
+
+        assert!(expiration.enable_expiration);
+        assert_eq!(
+            expiration.expire_at,
+            Some("2030-01-01T00:00:00+00:00".to_string())
+        );
+    }
+
+    #[test]
+    fn test_object_expiration_in_days() {
+        let before = Utc::now() + Duration::days(7);
+        let expiration = ObjectExpiration::in_days(7);
+        let after = Utc::now() + Duration::days(7);
+
+        assert!(expiration.enable_expiration);
+
+        let expire_at: DateTime<Utc> =
+            DateTime::parse_from_rfc3339(expiration.expire_at.as_ref().unwrap())
+                .unwrap()
+                .into();
+        assert!(expire_at >= before && expire_at <= after);
+    }
+
+    #[test]
+    fn test_object_expiration_in_hours() {
+        let before = Utc::now() + Duration::hours(12);
+        let expiration = ObjectExpiration::in_hours(12);
+        let after = Utc::now() + Duration::hours(12);
+
+        assert!(expiration.enable_expiration);
+
+        let expire_at: DateTime<Utc> =
+            DateTime::parse_from_rfc3339(expiration.expire_at.as_ref().unwrap())
+                .unwrap()
+                .into();
+        assert!(expire_at >= before && expire_at <= after);
+    }
+
+    #[test]
+    fn test_object_expiration_serialization() {
+        let expire_at: DateTime<Utc> = "2030-01-01T00:00:00Z".parse().unwrap();
+        let expiration = serde_json::to_value(ObjectExpiration::at(expire_at)).unwrap();
+
+        assert_eq!(
+            expiration,
+            serde_json::json!({
+                "enableExpiration": true,
+                "expireAt": "2030-01-01T00:00:00+00:00"
+            })
+        );
+
+        let no_expiration = serde_json::to_value(ObjectExpiration::default()).unwrap();
+
+        assert_eq!(
+            no_expiration,
+            serde_json::json!({
+                "enableExpiration": false,
+                "expireAt": null
+            })
+        );
+    }
 }
 
 #[derive(Clone)]
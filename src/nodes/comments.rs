@@ -0,0 +1,267 @@
+use async_trait::async_trait;
+use reqwest::header;
+
+use crate::{
+    client::{errors::DracoonClientError, Connected},
+    constants::{DRACOON_API_PREFIX, NODES_BASE, NODES_COMMENTS},
+    models::ListAllParams,
+    utils::FromResponse,
+};
+
+use super::{
+    models::{Comment, CommentList, CommentRequest},
+    NodeComments, NodesEndpoint,
+};
+
+#[async_trait]
+impl NodeComments for NodesEndpoint<Connected> {
+    async fn get_node_comments(
+        &self,
+        node_id: u64,
+        params: Option<ListAllParams>,
+    ) -> Result<CommentList, DracoonClientError> {
+        let url_part = format!("/{DRACOON_API_PREFIX}/{NODES_BASE}/{node_id}/{NODES_COMMENTS}");
+        let mut api_url = self.client().build_api_url(&url_part);
+
+        let params = params.unwrap_or_default();
+
+        api_url
+            .query_pairs_mut()
+            .extend_pairs(params.limit.map(|limit| ("limit", limit.to_string())))
+            .extend_pairs(params.offset.map(|offset| ("offset", offset.to_string())))
+            .finish();
+
+        let response = self
+            .client()
+            .http
+            .get(api_url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .header(header::CONTENT_TYPE, "application/json")
+            .send()
+            .await?;
+
+        CommentList::from_response(response).await
+    }
+
+    async fn create_comment(
+        &self,
+        node_id: u64,
+        text: impl Into<String> + Send,
+    ) -> Result<Comment, DracoonClientError> {
+        let url_part = format!("/{DRACOON_API_PREFIX}/{NODES_BASE}/{node_id}/{NODES_COMMENTS}");
+
+        let api_url = self.client().build_api_url(&url_part);
+
+        let req: CommentRequest = text.into().into();
+
+        let response = self
+            .client()
+            .http
+            .post(api_url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&req)
+            .send()
+            .await?;
+
+        Comment::from_response(response).await
+    }
+
+    async fn update_comment(
+        &self,
+        comment_id: u64,
+        text: impl Into<String> + Send,
+    ) -> Result<Comment, DracoonClientError> {
+        let url_part = format!("/{DRACOON_API_PREFIX}/{NODES_BASE}/{NODES_COMMENTS}/{comment_id}");
+
+        let api_url = self.client().build_api_url(&url_part);
+
+        let req: CommentRequest = text.into().into();
+
+        let response = self
+            .client()
+            .http
+            .put(api_url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&req)
+            .send()
+            .await?;
+
+        Comment::from_response(response).await
+    }
+
+    async fn delete_comment(&self, comment_id: u64) -> Result<(), DracoonClientError> {
+        let url_part = format!("/{DRACOON_API_PREFIX}/{NODES_BASE}/{NODES_COMMENTS}/{comment_id}");
+
+        let api_url = self.client().build_api_url(&url_part);
+
+        let response = self
+            .client()
+            .http
+            .delete(api_url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .send()
+            .await?;
+
+        if response.status().is_server_error() || response.status().is_client_error() {
+            return Err(DracoonClientError::from_response(response)
+                .await
+                .expect("Could not parse error response"));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{nodes::UserType, tests::dracoon::get_connected_client};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_node_comments() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let comments_res = include_str!("../tests/responses/nodes/node_comments_ok.json");
+
+        let comments_mock = mock_server
+            .mock("GET", "/api/v4/nodes/123/comments?offset=0")
+            .with_status(200)
+            .with_body(comments_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let comments = client.nodes().get_node_comments(123, None).await.unwrap();
+
+        comments_mock.assert();
+
+        assert_eq!(comments.range.total, 2);
+        assert_eq!(comments.items.len(), 2);
+
+        let comment = &comments.items[0];
+        assert_eq!(comment.id, 1);
+        assert_eq!(comment.text, "Looks good!");
+        assert_eq!(comment.created_by.id, 2);
+        assert_eq!(comment.created_by.user_type, UserType::Internal);
+        assert!(!comment.is_changed);
+        assert!(comment.updated_by.is_none());
+
+        let comment = &comments.items[1];
+        assert_eq!(comment.id, 2);
+        assert!(comment.is_changed);
+        assert!(comment.updated_at.is_some());
+        assert_eq!(comment.updated_by.as_ref().unwrap().id, 3);
+    }
+
+    #[tokio::test]
+    async fn test_get_node_comments_with_params() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let comments_res = include_str!("../tests/responses/nodes/node_comments_ok.json");
+
+        let comments_mock = mock_server
+            .mock("GET", "/api/v4/nodes/123/comments?limit=10&offset=20")
+            .with_status(200)
+            .with_body(comments_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let params = ListAllParams::builder()
+            .with_offset(20)
+            .with_limit(10)
+            .build();
+
+        let comments = client
+            .nodes()
+            .get_node_comments(123, Some(params))
+            .await
+            .unwrap();
+
+        comments_mock.assert();
+
+        assert_eq!(comments.items.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_create_comment() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let comment_res = include_str!("../tests/responses/nodes/node_comment_ok.json");
+
+        let comment_mock = mock_server
+            .mock("POST", "/api/v4/nodes/123/comments")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "text": "Looks good!"
+            })))
+            .with_status(201)
+            .with_body(comment_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let comment = client
+            .nodes()
+            .create_comment(123, "Looks good!")
+            .await
+            .unwrap();
+
+        comment_mock.assert();
+
+        assert_eq!(comment.id, 1);
+        assert_eq!(comment.text, "Looks good!");
+    }
+
+    #[tokio::test]
+    async fn test_update_comment() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let comment_res = include_str!("../tests/responses/nodes/node_comment_ok.json");
+
+        let comment_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/comments/1")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "text": "Looks good!"
+            })))
+            .with_status(200)
+            .with_body(comment_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let comment = client
+            .nodes()
+            .update_comment(1, "Looks good!".to_string())
+            .await
+            .unwrap();
+
+        comment_mock.assert();
+
+        assert_eq!(comment.id, 1);
+    }
+
+    #[tokio::test]
+    async fn test_delete_comment() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let comment_mock = mock_server
+            .mock("DELETE", "/api/v4/nodes/comments/1")
+            .with_status(204)
+            .create();
+
+        client.nodes().delete_comment(1).await.unwrap();
+
+        comment_mock.assert();
+    }
+}
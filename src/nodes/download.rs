@@ -1,23 +1,36 @@
 use super::{
-    models::{DownloadProgressCallback, DownloadUrlResponse, Node},
-    Download,
+    models::{DownloadAuthMode, DownloadProgressCallback, DownloadUrlResponse, Node},
+    Download, Nodes,
 };
 use crate::{
-    client::{errors::DracoonClientError, Connected},
+    client::{errors::DracoonClientError, models::DracoonErrorResponse, Connected},
     constants::{
-        DEFAULT_DOWNLOAD_CHUNK_SIZE, DRACOON_API_PREFIX, FILES_BASE, FILES_FILE_KEY, NODES_BASE,
-        NODES_DOWNLOAD_URL,
+        DEFAULT_DOWNLOAD_BYTES_MAX_SIZE, DEFAULT_DOWNLOAD_CHUNK_SIZE, DOWNLOAD_TOKEN_QUERY_PARAM,
+        DRACOON_API_PREFIX, FILES_BASE, FILES_FILE_KEY, NODES_BASE, NODES_DOWNLOAD_URL,
     },
     utils::{build_s3_error, FromResponse},
     Dracoon,
 };
 use async_trait::async_trait;
 use dco3_crypto::{ChunkedEncryption, Decrypter, DracoonCrypto, DracoonRSACrypto, FileKey};
-use futures_util::TryStreamExt;
-use reqwest::header::{self, CONTENT_RANGE, RANGE};
-use std::cmp::min;
-use tokio::io::{AsyncWrite, AsyncWriteExt};
-use tracing::{debug, error};
+use flate2::write::MultiGzDecoder;
+use futures_util::{ready, TryStreamExt};
+use reqwest::{
+    header::{self, CONTENT_RANGE, RANGE},
+    StatusCode,
+};
+use reqwest_middleware::RequestBuilder;
+use std::{
+    cmp::min,
+    io::Write,
+    path::{Path, PathBuf},
+    pin::Pin,
+    task::{Context, Poll},
+    time::SystemTime,
+};
+use tokio::io::{AsyncWrite, AsyncWriteExt, BufWriter};
+use tracing::{debug, error, warn};
+use url::Url;
 
 #[async_trait]
 impl Download for Dracoon<Connected> {
@@ -29,12 +42,496 @@ impl Download for Dracoon<Connected> {
         chunksize: Option<usize>,
     ) -> Result<(), DracoonClientError> {
         if node.is_encrypted.unwrap_or(false) {
-            self.download_encrypted(node, writer, callback, chunksize)
-                .await
+            return self
+                .download_encrypted(node, writer, callback, chunksize)
+                .await;
+        }
+
+        // a resolvable file key means the node is encrypted - only a 404 means no file key
+        match self.get_file_key(node.id).await {
+            Ok(_) if node.is_encrypted.is_none() => {
+                self.download_encrypted(node, writer, callback, chunksize)
+                    .await
+            }
+            Ok(_) => {
+                error!(
+                    "Node {} is encrypted but was passed as unencrypted",
+                    node.id
+                );
+                Err(DracoonClientError::EncryptionStateMismatch {
+                    node_id: node.id,
+                    node_encrypted: false,
+                    file_key_available: true,
+                })
+            }
+            Err(err) if err.is_not_found() => {
+                self.download_unencrypted(node, writer, callback, chunksize)
+                    .await
+            }
+            Err(err) => {
+                error!("Error getting file key for node {}: {}", node.id, err);
+                Err(err)
+            }
+        }
+    }
+
+    async fn download_unpacked<'w>(
+        &'w self,
+        node: &Node,
+        writer: &'w mut (dyn AsyncWrite + Send + Unpin),
+        callback: Option<DownloadProgressCallback>,
+        chunksize: Option<usize>,
+    ) -> Result<(), DracoonClientError> {
+        let mut writer = UnpackingWriter::new(writer);
+
+        self.download(node, &mut writer, callback, chunksize)
+            .await?;
+
+        writer.finish().await.map_err(|err| {
+            error!("Error inflating download of node {}: {}", node.id, err);
+            DracoonClientError::IoError
+        })
+    }
+
+    async fn download_range<'w>(
+        &'w self,
+        node: &Node,
+        writer: &'w mut (dyn AsyncWrite + Send + Unpin),
+        range: std::ops::Range<u64>,
+        mut callback: Option<DownloadProgressCallback>,
+    ) -> Result<(), DracoonClientError> {
+        if node.is_encrypted.unwrap_or(false) {
+            error!("Range download of encrypted node {} not supported", node.id);
+            return Err(DracoonClientError::EncryptedRangeDownload { node_id: node.id });
+        }
+
+        let size = match node.size {
+            Some(size) => size,
+            None => self.nodes().get_node(node.id).await?.size.unwrap_or(0),
+        };
+
+        if range.is_empty() || range.end > size {
+            error!(
+                "Invalid range {}..{} for node {} ({} bytes)",
+                range.start, range.end, node.id, size
+            );
+            return Err(DracoonClientError::InvalidRange {
+                start: range.start,
+                end: range.end,
+                size,
+            });
+        }
+
+        let url = self.get_download_url(node.id).await?.download_url;
+
+        let content_length = range.end - range.start;
+
+        let response = self
+            .build_download_request(&url)
+            .await?
+            .header(RANGE, format!("bytes={}-{}", range.start, range.end - 1))
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Error while downloading range: {}", err);
+                err
+            })?;
+
+        if response.error_for_status_ref().is_err() {
+            let error = build_s3_error(response).await;
+            return Err(error);
+        }
+
+        // the whole file is returned if the range is ignored - skip the leading bytes
+        let mut position = if response.status() == StatusCode::PARTIAL_CONTENT {
+            range.start
         } else {
-            self.download_unencrypted(node, writer, callback, chunksize)
-                .await
+            debug!("Range ignored - skipping {} bytes", range.start);
+            0
+        };
+
+        let mut stream = response.bytes_stream();
+
+        while let Some(chunk) = stream.try_next().await? {
+            let chunk_start = position;
+            position += chunk.len() as u64;
+
+            // only write the part of the chunk within the range
+            let start = range.start.saturating_sub(chunk_start);
+            let end = min(range.end, position).saturating_sub(chunk_start);
+
+            if start < end {
+                #[allow(clippy::cast_possible_truncation)]
+                let bytes = &chunk[start as usize..end as usize];
+
+                writer
+                    .write_all(bytes)
+                    .await
+                    .or(Err(DracoonClientError::IoError))?;
+
+                if let Some(ref mut callback) = callback {
+                    callback(bytes.len() as u64, content_length);
+                }
+            }
+
+            if position >= range.end {
+                break;
+            }
+        }
+
+        if position < range.end {
+            error!(
+                "Incomplete range download of node {} ({} of {} bytes)",
+                node.id,
+                position.saturating_sub(range.start),
+                content_length
+            );
+            return Err(DracoonClientError::IoError);
+        }
+
+        Ok(())
+    }
+
+    async fn download_path<'w>(
+        &'w self,
+        path: &str,
+        writer: &'w mut (dyn AsyncWrite + Send + Unpin),
+        callback: Option<DownloadProgressCallback>,
+        chunksize: Option<usize>,
+    ) -> Result<(), DracoonClientError> {
+        let Some(node) = self.nodes().get_node_from_path(path).await? else {
+            error!("Node not found: {}", path);
+            return Err(DracoonClientError::Http(DracoonErrorResponse::new(
+                404,
+                "Node not found",
+            )));
+        };
+
+        self.download(&node, writer, callback, chunksize).await
+    }
+
+    async fn download_bytes(
+        &self,
+        node: &Node,
+        max_size: Option<u64>,
+    ) -> Result<Vec<u8>, DracoonClientError> {
+        let max_size = max_size.unwrap_or(DEFAULT_DOWNLOAD_BYTES_MAX_SIZE);
+
+        if let Some(size) = node.size.filter(|size| *size > max_size) {
+            error!("File too large: {} bytes (max. {} bytes)", size, max_size);
+            return Err(DracoonClientError::FileTooLarge { size, max_size });
+        }
+
+        let mut writer = CappedBuffer::new(max_size);
+
+        match self.download(node, &mut writer, None, None).await {
+            Ok(()) => Ok(writer.buffer),
+            // the node size might be missing or outdated - the writer enforces the max. size
+            Err(_) if writer.exceeded_size.is_some() => {
+                let size = writer.exceeded_size.unwrap_or_default();
+                error!("File too large: {} bytes (max. {} bytes)", size, max_size);
+                Err(DracoonClientError::FileTooLarge { size, max_size })
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn download_to_path(
+        &self,
+        node: &Node,
+        path: impl AsRef<Path> + Send,
+        callback: Option<DownloadProgressCallback>,
+        chunksize: Option<usize>,
+    ) -> Result<(), DracoonClientError> {
+        let path = path.as_ref();
+        let partial_path = partial_download_path(path);
+
+        // download to a new file next to the target - an existing file is only replaced on success
+        let file = tokio::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&partial_path)
+            .await
+            .map_err(|err| {
+                error!("Error creating file {}: {}", partial_path.display(), err);
+                DracoonClientError::IoError
+            })?;
+
+        let mut writer = BufWriter::new(file);
+
+        let res = match self.download(node, &mut writer, callback, chunksize).await {
+            Ok(()) => finish_file(&mut writer).await,
+            Err(err) => Err(err),
+        };
+
+        let res = match res {
+            Ok(()) => {
+                if let Some(timestamp_modification) = node.timestamp_modification {
+                    set_modified(writer, &partial_path, timestamp_modification.into()).await;
+                } else {
+                    drop(writer);
+                }
+
+                tokio::fs::rename(&partial_path, path).await.map_err(|err| {
+                    error!(
+                        "Error moving download {} to {}: {}",
+                        partial_path.display(),
+                        path.display(),
+                        err
+                    );
+                    DracoonClientError::IoError
+                })
+            }
+            Err(err) => {
+                drop(writer);
+                Err(err)
+            }
+        };
+
+        if res.is_err() {
+            // only the partial file is removed - it was created by this call
+            if let Err(remove_err) = tokio::fs::remove_file(&partial_path).await {
+                error!(
+                    "Error removing partial download {}: {}",
+                    partial_path.display(),
+                    remove_err
+                );
+            }
+        }
+
+        res
+    }
+
+    async fn generate_download_url(&self, node_id: u64) -> Result<String, DracoonClientError> {
+        let node = self.nodes().get_node(node_id).await?;
+
+        if node.is_encrypted.unwrap_or(false) {
+            error!(
+                "Cannot generate download url for encrypted node {}",
+                node_id
+            );
+            return Err(DracoonClientError::EncryptedDownloadUrl { node_id });
+        }
+
+        let download_url = self.get_download_url(node_id).await?;
+
+        Ok(download_url.download_url)
+    }
+}
+
+/// Returns a unique path for a partial download next to the target (same file system for `rename`)
+fn partial_download_path(path: &Path) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let nanos = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+
+    path.with_file_name(format!(".{file_name}.{}.{nanos}.part", std::process::id()))
+}
+
+/// Sets the modification time of a downloaded file (errors are only logged)
+async fn set_modified(writer: BufWriter<tokio::fs::File>, path: &Path, modified: SystemTime) {
+    let file = writer.into_inner().into_std().await;
+
+    match tokio::task::spawn_blocking(move || file.set_modified(modified)).await {
+        Ok(Ok(())) => (),
+        Ok(Err(err)) => warn!(
+            "Error setting modification time of {}: {}",
+            path.display(),
+            err
+        ),
+        Err(err) => warn!(
+            "Error setting modification time of {}: {}",
+            path.display(),
+            err
+        ),
+    }
+}
+
+/// helper to flush and sync a downloaded file
+async fn finish_file(writer: &mut BufWriter<tokio::fs::File>) -> Result<(), DracoonClientError> {
+    writer.flush().await.map_err(|err| {
+        error!("Error flushing file: {}", err);
+        DracoonClientError::IoError
+    })?;
+
+    writer.get_ref().sync_all().await.map_err(|err| {
+        error!("Error syncing file: {}", err);
+        DracoonClientError::IoError
+    })
+}
+
+/// In-memory writer that fails once more than `max_size` bytes are written
+struct CappedBuffer {
+    buffer: Vec<u8>,
+    max_size: u64,
+    exceeded_size: Option<u64>,
+}
+
+impl CappedBuffer {
+    fn new(max_size: u64) -> Self {
+        Self {
+            buffer: Vec::new(),
+            max_size,
+            exceeded_size: None,
+        }
+    }
+}
+
+impl AsyncWrite for CappedBuffer {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let size = (self.buffer.len() + buf.len()) as u64;
+
+        if size > self.max_size {
+            self.exceeded_size = Some(size);
+            return Poll::Ready(Err(std::io::Error::other("max. size exceeded")));
+        }
+
+        self.buffer.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// gzip magic bytes (RFC 1952)
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+enum UnpackState {
+    /// collecting the first bytes to detect compressed content
+    Detecting(Vec<u8>),
+    Gzip(Box<MultiGzDecoder<Vec<u8>>>),
+    Raw,
+}
+
+/// Writer that inflates gzip compressed content on the fly and
+/// passes any other content through unchanged
+struct UnpackingWriter<'w> {
+    inner: &'w mut (dyn AsyncWrite + Send + Unpin),
+    state: UnpackState,
+    /// inflated bytes not yet written to the inner writer
+    pending: Vec<u8>,
+}
+
+impl<'w> UnpackingWriter<'w> {
+    fn new(inner: &'w mut (dyn AsyncWrite + Send + Unpin)) -> Self {
+        Self {
+            inner,
+            state: UnpackState::Detecting(Vec::with_capacity(GZIP_MAGIC.len())),
+            pending: Vec::new(),
+        }
+    }
+
+    fn poll_drain(&mut self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        while !self.pending.is_empty() {
+            let written = ready!(Pin::new(&mut *self.inner).poll_write(cx, &self.pending))?;
+
+            if written == 0 {
+                return Poll::Ready(Err(std::io::ErrorKind::WriteZero.into()));
+            }
+
+            self.pending.drain(..written);
+        }
+
+        Poll::Ready(Ok(()))
+    }
+
+    fn unpack(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        if let UnpackState::Detecting(header) = &mut self.state {
+            header.extend_from_slice(buf);
+
+            if header.len() < GZIP_MAGIC.len() {
+                return Ok(());
+            }
+
+            let header = std::mem::take(header);
+
+            if header.starts_with(&GZIP_MAGIC) {
+                debug!("Detected gzip compressed content");
+                let mut decoder = MultiGzDecoder::new(Vec::new());
+                decoder.write_all(&header)?;
+                self.pending.append(decoder.get_mut());
+                self.state = UnpackState::Gzip(Box::new(decoder));
+            } else {
+                self.pending = header;
+                self.state = UnpackState::Raw;
+            }
+
+            return Ok(());
+        }
+
+        match &mut self.state {
+            UnpackState::Gzip(decoder) => {
+                decoder.write_all(buf)?;
+                self.pending.append(decoder.get_mut());
+            }
+            UnpackState::Raw => self.pending.extend_from_slice(buf),
+            UnpackState::Detecting(_) => unreachable!("detection is handled above"),
+        }
+
+        Ok(())
+    }
+
+    /// Writes any remaining (inflated) bytes - required once all content is written
+    async fn finish(&mut self) -> std::io::Result<()> {
+        match std::mem::replace(&mut self.state, UnpackState::Raw) {
+            // content shorter than the magic bytes is never compressed
+            UnpackState::Detecting(header) => self.pending.extend_from_slice(&header),
+            UnpackState::Gzip(mut decoder) => {
+                decoder.try_finish()?;
+                self.pending.append(decoder.get_mut());
+            }
+            UnpackState::Raw => (),
         }
+
+        std::future::poll_fn(|cx| self.poll_drain(cx)).await
+    }
+}
+
+impl AsyncWrite for UnpackingWriter<'_> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+
+        // inflated content is written before accepting more input
+        ready!(this.poll_drain(cx))?;
+
+        this.unpack(buf)?;
+
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+
+        ready!(this.poll_drain(cx))?;
+
+        Pin::new(&mut *this.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+
+        ready!(this.poll_drain(cx))?;
+
+        Pin::new(&mut *this.inner).poll_shutdown(cx)
     }
 }
 
@@ -47,6 +544,12 @@ trait DownloadInternal {
 
     async fn get_file_key(&self, node_id: u64) -> Result<FileKey, DracoonClientError>;
 
+    /// Builds the request for a download url and passes the access token
+    /// according to the configured `DownloadAuthMode`
+    /// The token is only sent to download urls with the same origin as the base url.
+    async fn build_download_request(&self, url: &str)
+        -> Result<RequestBuilder, DracoonClientError>;
+
     async fn download_unencrypted(
         &self,
         node: &Node,
@@ -88,6 +591,48 @@ impl DownloadInternal for Dracoon<Connected> {
         DownloadUrlResponse::from_response(response).await
     }
 
+    async fn build_download_request(
+        &self,
+        url: &str,
+    ) -> Result<RequestBuilder, DracoonClientError> {
+        let download_auth_mode = self.client.get_download_auth_mode();
+
+        if download_auth_mode == DownloadAuthMode::Presigned {
+            return Ok(self.client.raw_http.get(url));
+        }
+
+        // never pass the token to a foreign origin (e.g. S3 or a CDN)
+        let mut download_url = match Url::parse(url) {
+            Ok(download_url) if download_url.origin() == self.get_base_url().origin() => {
+                download_url
+            }
+            _ => {
+                debug!("Download url has a foreign origin - access token is not sent");
+                return Ok(self.client.raw_http.get(url));
+            }
+        };
+
+        let auth_header = self.get_auth_header().await?;
+
+        let request = match download_auth_mode {
+            DownloadAuthMode::QueryParam => {
+                let token = auth_header.strip_prefix("Bearer ").unwrap_or(&auth_header);
+                download_url
+                    .query_pairs_mut()
+                    .append_pair(DOWNLOAD_TOKEN_QUERY_PARAM, token);
+
+                self.client.raw_http.get(download_url)
+            }
+            _ => self
+                .client
+                .raw_http
+                .get(download_url)
+                .header(header::AUTHORIZATION, auth_header),
+        };
+
+        Ok(request)
+    }
+
     async fn download_unencrypted(
         &self,
         node: &Node,
@@ -101,9 +646,8 @@ impl DownloadInternal for Dracoon<Connected> {
         let content_length = if let Some(size) = node.size {
             size
         } else {
-            self.client
-                .http
-                .get(&url)
+            self.build_download_request(&url)
+                .await?
                 .header(RANGE, "bytes=0-0")
                 .send()
                 .await
@@ -143,9 +687,8 @@ impl DownloadInternal for Dracoon<Connected> {
 
             // get chunk
             let response = self
-                .client
-                .http
-                .get(url)
+                .build_download_request(&url)
+                .await?
                 .header(RANGE, range)
                 .send()
                 .await
@@ -192,7 +735,27 @@ impl DownloadInternal for Dracoon<Connected> {
         chunksize: Option<usize>,
     ) -> Result<(), DracoonClientError> {
         // get file key
-        let file_key = self.get_file_key(node.id).await?;
+        let file_key = match self.get_file_key(node.id).await {
+            Ok(file_key) => file_key,
+            Err(err) if err.is_not_found() => {
+                // no file key - either missing for the user or the node is not encrypted at all
+                let actual_node = self.nodes().get_node(node.id).await?;
+                if actual_node.is_encrypted.unwrap_or(false) {
+                    return Err(err);
+                }
+
+                error!(
+                    "Node {} is not encrypted but was passed as encrypted",
+                    node.id
+                );
+                return Err(DracoonClientError::EncryptionStateMismatch {
+                    node_id: node.id,
+                    node_encrypted: true,
+                    file_key_available: false,
+                });
+            }
+            Err(err) => return Err(err),
+        };
 
         let keypair = self.get_keypair(None).await?;
 
@@ -204,9 +767,8 @@ impl DownloadInternal for Dracoon<Connected> {
         let content_length = if let Some(size) = node.size {
             size
         } else {
-            self.client
-                .http
-                .get(&url)
+            self.build_download_request(&url)
+                .await?
                 .header(RANGE, "bytes=0-0")
                 .send()
                 .await
@@ -251,9 +813,8 @@ impl DownloadInternal for Dracoon<Connected> {
 
             // get chunk
             let response = self
-                .client
-                .http
-                .get(url)
+                .build_download_request(&url)
+                .await?
                 .header(RANGE, range)
                 .send()
                 .await
@@ -317,10 +878,11 @@ mod tests {
     // separate from test folder due to internal trait (DownloadInternal)
 
     use dco3_crypto::{Encrypt, FileKeyVersion};
+    use mockito::Matcher;
 
     use super::*;
 
-    use crate::tests::dracoon::get_connected_client;
+    use crate::{tests::dracoon::get_connected_client, OAuth2Flow};
 
     #[tokio::test]
     async fn test_get_download_url() {
@@ -344,6 +906,69 @@ mod tests {
         assert_eq!(download_url.download_url, download_url_str);
     }
 
+    #[tokio::test]
+    async fn test_generate_download_url() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let node_res = include_str!("../tests/responses/nodes/node_ok.json");
+        let download_url_res = include_str!("../tests/responses/download/download_url_ok.json");
+
+        let node_mock = mock_server
+            .mock("GET", "/api/v4/nodes/1234")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(node_res)
+            .create();
+
+        let download_url_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/1234/downloads")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(download_url_res)
+            .create();
+
+        let download_url = dracoon.generate_download_url(1234).await.unwrap();
+
+        node_mock.assert();
+        download_url_mock.assert();
+
+        assert_eq!(
+            download_url,
+            "https://test.dracoon.com/not/real/download_url"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_download_url_encrypted_node() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let node_res = include_str!("../tests/responses/nodes/node_ok.json")
+            .replace(r#""isEncrypted": false"#, r#""isEncrypted": true"#);
+
+        let node_mock = mock_server
+            .mock("GET", "/api/v4/nodes/1234")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(node_res)
+            .create();
+
+        let download_url_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/1234/downloads")
+            .expect(0)
+            .create();
+
+        let err = dracoon.generate_download_url(1234).await.unwrap_err();
+
+        node_mock.assert();
+        download_url_mock.assert();
+
+        assert_eq!(
+            err,
+            DracoonClientError::EncryptedDownloadUrl { node_id: 1234 }
+        );
+        assert!(err.is_encrypted_download_url());
+    }
+
     #[tokio::test]
     async fn test_get_file_key() {
         let (dracoon, mut mock_server) = get_connected_client().await;
@@ -415,13 +1040,779 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_download_encrypted() {
+    async fn test_download_unencrypted_node_with_file_key() {
         let (dracoon, mut mock_server) = get_connected_client().await;
 
-        // create bytes for mocking byte response
-        let mock_bytes: [u8; 16] = [
-            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
-        ];
+        let node_json = include_str!("../tests/responses/nodes/node_ok.json");
+        let node: Node = serde_json::from_str(node_json).unwrap();
+
+        let file_key_res = include_str!("../tests/responses/download/file_key_ok.json");
+
+        let file_key_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/2/user_file_key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(file_key_res)
+            .create();
+
+        let download_url_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/2/downloads")
+            .expect(0)
+            .create();
+
+        let mut writer = tokio::io::BufWriter::new(Vec::new());
+
+        let err = dracoon
+            .download(&node, &mut writer, None, None)
+            .await
+            .unwrap_err();
+
+        file_key_mock.assert();
+        download_url_mock.assert();
+
+        assert_eq!(
+            err,
+            DracoonClientError::EncryptionStateMismatch {
+                node_id: 2,
+                node_encrypted: false,
+                file_key_available: true,
+            }
+        );
+        assert!(writer.buffer().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_download_unencrypted_node_file_key_error() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let node_json = include_str!("../tests/responses/nodes/node_ok.json");
+        let node: Node = serde_json::from_str(node_json).unwrap();
+
+        let file_key_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/2/user_file_key")
+            .with_status(403)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/error_forbidden.json"))
+            .create();
+
+        let download_url_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/2/downloads")
+            .expect(0)
+            .create();
+
+        let mut writer = tokio::io::BufWriter::new(Vec::new());
+
+        let err = dracoon
+            .download(&node, &mut writer, None, None)
+            .await
+            .unwrap_err();
+
+        file_key_mock.assert();
+        download_url_mock.assert();
+
+        assert!(err.is_forbidden());
+        assert!(writer.buffer().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_download_encrypted_node_without_file_key() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let node_json = include_str!("../tests/responses/nodes/node_ok.json");
+        let mut node: Node = serde_json::from_str(node_json).unwrap();
+        node.is_encrypted = Some(true);
+
+        let file_key_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/2/user_file_key")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/error_not_found.json"))
+            .create();
+
+        let node_mock = mock_server
+            .mock("GET", "/api/v4/nodes/2")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(node_json)
+            .create();
+
+        let download_url_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/2/downloads")
+            .expect(0)
+            .create();
+
+        let mut writer = tokio::io::BufWriter::new(Vec::new());
+
+        let err = dracoon
+            .download(&node, &mut writer, None, None)
+            .await
+            .unwrap_err();
+
+        file_key_mock.assert();
+        node_mock.assert();
+        download_url_mock.assert();
+
+        assert_eq!(
+            err,
+            DracoonClientError::EncryptionStateMismatch {
+                node_id: 2,
+                node_encrypted: true,
+                file_key_available: false,
+            }
+        );
+        assert!(writer.buffer().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_download_bytes() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let mock_bytes: [u8; 16] = [
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+
+        let node_json = include_str!("../tests/responses/nodes/node_ok.json");
+        let node: Node = serde_json::from_str(node_json).unwrap();
+
+        let file_key_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/2/user_file_key")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/error_not_found.json"))
+            .create();
+
+        let download_url = format!("{}some/download/url", dracoon.get_base_url());
+        let download_url_res =
+            include_str!("../tests/responses/download/download_url_ok_template.json")
+                .replace("$url", &download_url);
+
+        let download_url_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/2/downloads")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(download_url_res)
+            .create();
+
+        let download_mock = mock_server
+            .mock("GET", "/some/download/url")
+            .with_status(200)
+            .with_header("content-type", "application/octet-stream")
+            .with_body(mock_bytes)
+            .create();
+
+        let bytes = dracoon.download_bytes(&node, None).await.unwrap();
+
+        file_key_mock.assert();
+        download_url_mock.assert();
+        download_mock.assert();
+
+        assert_eq!(bytes, mock_bytes.to_vec());
+    }
+
+    /// mocks a gzip encoded download of node 2 and returns the original content
+    async fn mock_gzip_download(
+        dracoon: &Dracoon<Connected>,
+        mock_server: &mut mockito::ServerGuard,
+    ) -> (Node, Vec<u8>, Vec<mockito::Mock>) {
+        let content = b"some compressible content - ".repeat(64);
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&content).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+        node.size = Some(compressed.len() as u64);
+
+        let file_key_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/2/user_file_key")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/error_not_found.json"))
+            .create();
+
+        let download_url = format!("{}some/download/url", dracoon.get_base_url());
+        let download_url_res =
+            include_str!("../tests/responses/download/download_url_ok_template.json")
+                .replace("$url", &download_url);
+
+        let download_url_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/2/downloads")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(download_url_res)
+            .create();
+
+        let download_mock = mock_server
+            .mock("GET", "/some/download/url")
+            .with_status(200)
+            .with_header("content-type", "application/octet-stream")
+            .with_header("content-encoding", "gzip")
+            .with_body(compressed)
+            .create();
+
+        (
+            node,
+            content,
+            vec![file_key_mock, download_url_mock, download_mock],
+        )
+    }
+
+    #[tokio::test]
+    async fn test_download_unpacked_gzip_encoded() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let (node, content, mocks) = mock_gzip_download(&dracoon, &mut mock_server).await;
+
+        let mut buffer = Vec::new();
+
+        dracoon
+            .download_unpacked(&node, &mut buffer, None, None)
+            .await
+            .unwrap();
+
+        mocks.iter().for_each(|mock| mock.assert());
+
+        assert_eq!(buffer, content);
+    }
+
+    #[tokio::test]
+    async fn test_download_gzip_encoded_keeps_raw_bytes() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let (node, content, mocks) = mock_gzip_download(&dracoon, &mut mock_server).await;
+
+        let mut buffer = Vec::new();
+
+        dracoon
+            .download(&node, &mut buffer, None, None)
+            .await
+            .unwrap();
+
+        mocks.iter().for_each(|mock| mock.assert());
+
+        assert_eq!(buffer.len() as u64, node.size.unwrap());
+        assert!(buffer.starts_with(&GZIP_MAGIC));
+        assert_ne!(buffer, content);
+    }
+
+    #[tokio::test]
+    async fn test_unpacking_writer_split_writes() {
+        let content = b"some compressible content - ".repeat(16);
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&content).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        // the magic bytes are split across writes
+        let mut buffer = Vec::new();
+        let mut writer = UnpackingWriter::new(&mut buffer);
+        for chunk in compressed.chunks(1) {
+            writer.write_all(chunk).await.unwrap();
+        }
+        writer.finish().await.unwrap();
+
+        assert_eq!(buffer, content);
+
+        // uncompressed content is passed through unchanged
+        let mut buffer = Vec::new();
+        let mut writer = UnpackingWriter::new(&mut buffer);
+        writer.write_all(&content).await.unwrap();
+        writer.finish().await.unwrap();
+
+        assert_eq!(buffer, content);
+
+        // content shorter than the magic bytes
+        let mut buffer = Vec::new();
+        let mut writer = UnpackingWriter::new(&mut buffer);
+        writer.write_all(&[0x1f]).await.unwrap();
+        writer.finish().await.unwrap();
+
+        assert_eq!(buffer, vec![0x1f]);
+    }
+
+    /// mocks the download url request of the unencrypted node 2
+    fn mock_range_download(
+        dracoon: &Dracoon<Connected>,
+        mock_server: &mut mockito::ServerGuard,
+    ) -> Vec<mockito::Mock> {
+        let download_url = format!("{}some/download/url", dracoon.get_base_url());
+        let download_url_res =
+            include_str!("../tests/responses/download/download_url_ok_template.json")
+                .replace("$url", &download_url);
+
+        let download_url_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/2/downloads")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(download_url_res)
+            .create();
+
+        vec![download_url_mock]
+    }
+
+    #[tokio::test]
+    async fn test_download_range() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+
+        let mocks = mock_range_download(&dracoon, &mut mock_server);
+
+        let download_mock = mock_server
+            .mock("GET", "/some/download/url")
+            .match_header("range", "bytes=4-11")
+            .with_status(206)
+            .with_header("content-type", "application/octet-stream")
+            .with_header("content-range", "bytes 4-11/16")
+            .with_body([4, 5, 6, 7, 8, 9, 10, 11])
+            .create();
+
+        let progress = std::sync::Arc::new(std::sync::Mutex::new(0));
+        let progress_clone = progress.clone();
+
+        let mut buffer = Vec::new();
+
+        dracoon
+            .download_range(
+                &node,
+                &mut buffer,
+                4..12,
+                Some(Box::new(move |bytes, total| {
+                    assert_eq!(total, 8);
+                    *progress_clone.lock().unwrap() += bytes;
+                })),
+            )
+            .await
+            .unwrap();
+
+        mocks.iter().for_each(|mock| mock.assert());
+        download_mock.assert();
+
+        assert_eq!(buffer, vec![4, 5, 6, 7, 8, 9, 10, 11]);
+        assert_eq!(*progress.lock().unwrap(), 8);
+    }
+
+    #[tokio::test]
+    async fn test_download_range_ignored_by_server() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+
+        let mocks = mock_range_download(&dracoon, &mut mock_server);
+
+        // the full content is returned
+        let download_mock = mock_server
+            .mock("GET", "/some/download/url")
+            .with_status(200)
+            .with_header("content-type", "application/octet-stream")
+            .with_body((0..16).collect::<Vec<u8>>())
+            .create();
+
+        let mut buffer = Vec::new();
+
+        dracoon
+            .download_range(&node, &mut buffer, 12..16, None)
+            .await
+            .unwrap();
+
+        mocks.iter().for_each(|mock| mock.assert());
+        download_mock.assert();
+
+        assert_eq!(buffer, vec![12, 13, 14, 15]);
+    }
+
+    #[tokio::test]
+    async fn test_download_range_exceeds_size() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+
+        let download_url_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/2/downloads")
+            .expect(0)
+            .create();
+
+        let mut buffer = Vec::new();
+
+        let err = dracoon
+            .download_range(&node, &mut buffer, 8..17, None)
+            .await
+            .unwrap_err();
+
+        download_url_mock.assert();
+
+        assert!(err.is_invalid_range());
+        assert_eq!(
+            err,
+            DracoonClientError::InvalidRange {
+                start: 8,
+                end: 17,
+                size: 16
+            }
+        );
+        assert!(buffer.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_download_range_encrypted_node() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let node: Node = serde_json::from_str(include_str!(
+            "../tests/responses/nodes/node_encrypted_ok.json"
+        ))
+        .unwrap();
+
+        let download_url_mock = mock_server
+            .mock("POST", mockito::Matcher::Regex("/downloads".into()))
+            .expect(0)
+            .create();
+
+        let mut buffer = Vec::new();
+
+        let err = dracoon
+            .download_range(&node, &mut buffer, 0..16, None)
+            .await
+            .unwrap_err();
+
+        download_url_mock.assert();
+
+        assert!(err.is_encrypted_range_download());
+        assert_eq!(
+            err,
+            DracoonClientError::EncryptedRangeDownload { node_id: node.id }
+        );
+    }
+
+    fn temp_download_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("dco3_{}_{}", std::process::id(), name))
+    }
+
+    #[tokio::test]
+    async fn test_download_to_path() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let mock_bytes: [u8; 16] = [
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+
+        let node_json = include_str!("../tests/responses/nodes/node_ok.json");
+        let node: Node = serde_json::from_str(node_json).unwrap();
+
+        let file_key_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/2/user_file_key")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/error_not_found.json"))
+            .create();
+
+        let download_url = format!("{}some/download/url", dracoon.get_base_url());
+        let download_url_res =
+            include_str!("../tests/responses/download/download_url_ok_template.json")
+                .replace("$url", &download_url);
+
+        let download_url_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/2/downloads")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(download_url_res)
+            .create();
+
+        let download_mock = mock_server
+            .mock("GET", "/some/download/url")
+            .with_status(200)
+            .with_header("content-type", "application/octet-stream")
+            .with_body(mock_bytes)
+            .create();
+
+        let path = temp_download_path("download_to_path.bin");
+        tokio::fs::write(&path, b"existing content").await.unwrap();
+
+        dracoon
+            .download_to_path(&node, &path, None, None)
+            .await
+            .unwrap();
+
+        file_key_mock.assert();
+        download_url_mock.assert();
+        download_mock.assert();
+
+        let bytes = tokio::fs::read(&path).await.unwrap();
+        let modified = tokio::fs::metadata(&path)
+            .await
+            .unwrap()
+            .modified()
+            .unwrap();
+        tokio::fs::remove_file(&path).await.unwrap();
+
+        assert_eq!(bytes, mock_bytes.to_vec());
+        assert_eq!(
+            modified,
+            SystemTime::from(node.timestamp_modification.unwrap())
+        );
+        assert_no_partial_downloads(&path);
+    }
+
+    #[tokio::test]
+    async fn test_download_to_path_removes_partial_file() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let node_json = include_str!("../tests/responses/nodes/node_ok.json");
+        let node: Node = serde_json::from_str(node_json).unwrap();
+
+        let file_key_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/2/user_file_key")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/error_not_found.json"))
+            .create();
+
+        let download_url_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/2/downloads")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/error_not_found.json"))
+            .create();
+
+        let path = temp_download_path("download_to_path_error.bin");
+
+        let err = dracoon
+            .download_to_path(&node, &path, None, None)
+            .await
+            .unwrap_err();
+
+        file_key_mock.assert();
+        download_url_mock.assert();
+
+        assert!(err.is_not_found());
+        assert!(!path.exists());
+        assert_no_partial_downloads(&path);
+    }
+
+    #[tokio::test]
+    async fn test_download_to_path_keeps_existing_file_on_error() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let node_json = include_str!("../tests/responses/nodes/node_ok.json");
+        let node: Node = serde_json::from_str(node_json).unwrap();
+
+        let file_key_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/2/user_file_key")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/error_not_found.json"))
+            .create();
+
+        let download_url = format!("{}some/download/url", dracoon.get_base_url());
+        let download_url_res =
+            include_str!("../tests/responses/download/download_url_ok_template.json")
+                .replace("$url", &download_url);
+
+        let download_url_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/2/downloads")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(download_url_res)
+            .create();
+
+        let download_mock = mock_server
+            .mock("GET", "/some/download/url")
+            .with_status(403)
+            .create();
+
+        let path = temp_download_path("download_to_path_existing.bin");
+        tokio::fs::write(&path, b"existing content").await.unwrap();
+
+        let res = dracoon.download_to_path(&node, &path, None, None).await;
+
+        file_key_mock.assert();
+        download_url_mock.assert();
+        download_mock.assert();
+
+        let bytes = tokio::fs::read(&path).await.unwrap();
+        tokio::fs::remove_file(&path).await.unwrap();
+
+        assert!(res.is_err());
+        assert_eq!(bytes, b"existing content".to_vec());
+        assert_no_partial_downloads(&path);
+    }
+
+    fn assert_no_partial_downloads(path: &std::path::Path) {
+        let prefix = format!(".{}.", path.file_name().unwrap().to_string_lossy());
+        let partial = std::fs::read_dir(path.parent().unwrap())
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .any(|entry| entry.file_name().to_string_lossy().starts_with(&prefix));
+
+        assert!(!partial);
+    }
+
+    #[tokio::test]
+    async fn test_download_bytes_too_large() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let node_json = include_str!("../tests/responses/nodes/node_ok.json");
+        let node: Node = serde_json::from_str(node_json).unwrap();
+
+        let download_url_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/2/downloads")
+            .expect(0)
+            .create();
+
+        let err = dracoon.download_bytes(&node, Some(8)).await.unwrap_err();
+
+        download_url_mock.assert();
+
+        assert_eq!(
+            err,
+            DracoonClientError::FileTooLarge {
+                size: 16,
+                max_size: 8
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_download_bytes_too_large_without_size() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let mock_bytes: [u8; 16] = [
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+
+        let node_json = include_str!("../tests/responses/nodes/node_ok.json");
+        let mut node: Node = serde_json::from_str(node_json).unwrap();
+        node.size = None;
+
+        let file_key_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/2/user_file_key")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/error_not_found.json"))
+            .create();
+
+        let download_url = format!("{}some/download/url", dracoon.get_base_url());
+        let download_url_res =
+            include_str!("../tests/responses/download/download_url_ok_template.json")
+                .replace("$url", &download_url);
+
+        let download_url_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/2/downloads")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(download_url_res)
+            .create();
+
+        let download_mock = mock_server
+            .mock("GET", "/some/download/url")
+            .with_status(200)
+            .with_header("content-type", "application/octet-stream")
+            .with_header("content-range", "bytes 0-15/16")
+            .with_body(mock_bytes)
+            .create();
+
+        let err = dracoon.download_bytes(&node, Some(8)).await.unwrap_err();
+
+        assert!(err.is_file_too_large());
+    }
+
+    #[tokio::test]
+    async fn test_download_path() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let mock_bytes: [u8; 16] = [
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+
+        let search_mock = mock_server
+            .mock("GET", "/api/v4/nodes/search")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("search_string".into(), "test".into()),
+                Matcher::UrlEncoded("depth_level".into(), "2".into()),
+                Matcher::UrlEncoded("filter".into(), "parentPath:eq:/some/path/".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/nodes/nodes_ok.json"))
+            .create();
+
+        let file_key_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/2/user_file_key")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/error_not_found.json"))
+            .create();
+
+        let download_url = format!("{}some/download/url", dracoon.get_base_url());
+        let download_url_res =
+            include_str!("../tests/responses/download/download_url_ok_template.json")
+                .replace("$url", &download_url);
+
+        let download_url_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/2/downloads")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(download_url_res)
+            .create();
+
+        let download_mock = mock_server
+            .mock("GET", "/some/download/url")
+            .with_status(200)
+            .with_header("content-type", "application/octet-stream")
+            .with_body(mock_bytes)
+            .create();
+
+        let mut writer = tokio::io::BufWriter::new(Vec::with_capacity(16));
+
+        dracoon
+            .download_path("/some/path/test", &mut writer, None, None)
+            .await
+            .unwrap();
+
+        search_mock.assert();
+        file_key_mock.assert();
+        download_url_mock.assert();
+        download_mock.assert();
+
+        assert_eq!(writer.buffer(), mock_bytes.to_vec());
+    }
+
+    #[tokio::test]
+    async fn test_download_path_not_found() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let search_mock = mock_server
+            .mock("GET", "/api/v4/nodes/search")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!(
+                "../tests/responses/nodes/nodes_search_no_result.json"
+            ))
+            .create();
+
+        let mut writer = tokio::io::BufWriter::new(Vec::new());
+
+        let res = dracoon
+            .download_path("/some/path/test", &mut writer, None, None)
+            .await;
+
+        search_mock.assert();
+
+        assert!(res.unwrap_err().is_not_found());
+    }
+
+    #[tokio::test]
+    async fn test_download_encrypted() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        // create bytes for mocking byte response
+        let mock_bytes: [u8; 16] = [
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
         let mock_bytes_compare = mock_bytes;
 
         let mock_bytes_encrypted = DracoonCrypto::encrypt(mock_bytes).unwrap();
@@ -723,4 +2114,175 @@ mod tests {
         // Verify that the decrypted data matches the original mock bytes
         assert_eq!(writer.buffer(), &mock_bytes_compare.to_vec());
     }
+
+    async fn get_connected_client_with_download_auth_mode(
+        download_auth_mode: DownloadAuthMode,
+    ) -> (Dracoon<Connected>, mockito::ServerGuard) {
+        let mut mock_server = mockito::Server::new_async().await;
+
+        let auth_res = include_str!("../client/tests/auth_ok.json");
+
+        mock_server
+            .mock("POST", "/oauth/token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(auth_res)
+            .create();
+
+        let dracoon = Dracoon::builder()
+            .with_base_url(mock_server.url())
+            .with_client_id("client_id")
+            .with_client_secret("client_secret")
+            .with_download_auth_mode(download_auth_mode)
+            .build()
+            .unwrap()
+            .connect(OAuth2Flow::authorization_code("auth_code"))
+            .await
+            .unwrap();
+
+        (dracoon, mock_server)
+    }
+
+    #[tokio::test]
+    async fn test_download_unencrypted_token_in_header() {
+        let (dracoon, mut mock_server) =
+            get_connected_client_with_download_auth_mode(DownloadAuthMode::Header).await;
+
+        let mock_bytes: [u8; 16] = [
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+
+        let download_mock = mock_server
+            .mock("GET", "/some/download/url")
+            .match_query(Matcher::Missing)
+            .match_header("authorization", "Bearer access_token")
+            .with_status(200)
+            .with_header("content-type", "application/octet-stream")
+            .with_body(mock_bytes)
+            .create();
+
+        let download_url = format!("{}some/download/url", dracoon.get_base_url());
+
+        let mut writer = tokio::io::BufWriter::new(Vec::with_capacity(16));
+
+        let node_json = include_str!("../tests/responses/nodes/node_ok.json");
+        let node: Node = serde_json::from_str(node_json).unwrap();
+
+        let download_url_res =
+            include_str!("../tests/responses/download/download_url_ok_template.json");
+        let download_url_res = download_url_res.replace("$url", &download_url);
+
+        mock_server
+            .mock("POST", "/api/v4/nodes/files/2/downloads")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(download_url_res)
+            .create();
+
+        dracoon
+            .download_unencrypted(&node, &mut writer, None, None)
+            .await
+            .unwrap();
+
+        download_mock.assert();
+
+        assert_eq!(writer.buffer(), mock_bytes.to_vec());
+    }
+
+    #[tokio::test]
+    async fn test_download_unencrypted_token_in_query() {
+        let (dracoon, mut mock_server) =
+            get_connected_client_with_download_auth_mode(DownloadAuthMode::QueryParam).await;
+
+        let mock_bytes: [u8; 16] = [
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+
+        let download_mock = mock_server
+            .mock("GET", "/some/download/url")
+            .match_query(Matcher::UrlEncoded(
+                "access_token".into(),
+                "access_token".into(),
+            ))
+            .match_header("authorization", Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/octet-stream")
+            .with_body(mock_bytes)
+            .create();
+
+        let download_url = format!("{}some/download/url", dracoon.get_base_url());
+
+        let mut writer = tokio::io::BufWriter::new(Vec::with_capacity(16));
+
+        let node_json = include_str!("../tests/responses/nodes/node_ok.json");
+        let node: Node = serde_json::from_str(node_json).unwrap();
+
+        let download_url_res =
+            include_str!("../tests/responses/download/download_url_ok_template.json");
+        let download_url_res = download_url_res.replace("$url", &download_url);
+
+        mock_server
+            .mock("POST", "/api/v4/nodes/files/2/downloads")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(download_url_res)
+            .create();
+
+        dracoon
+            .download_unencrypted(&node, &mut writer, None, None)
+            .await
+            .unwrap();
+
+        download_mock.assert();
+
+        assert_eq!(writer.buffer(), mock_bytes.to_vec());
+    }
+
+    #[tokio::test]
+    async fn test_download_unencrypted_no_token_for_foreign_origin() {
+        let (dracoon, mut mock_server) =
+            get_connected_client_with_download_auth_mode(DownloadAuthMode::Header).await;
+
+        // download url on another origin (e.g. S3)
+        let mut foreign_server = mockito::Server::new_async().await;
+
+        let mock_bytes: [u8; 16] = [
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+
+        let download_mock = foreign_server
+            .mock("GET", "/some/download/url")
+            .match_header("authorization", Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/octet-stream")
+            .with_body(mock_bytes)
+            .create();
+
+        let download_url = format!("{}/some/download/url", foreign_server.url());
+
+        let mut writer = tokio::io::BufWriter::new(Vec::with_capacity(16));
+
+        let node_json = include_str!("../tests/responses/nodes/node_ok.json");
+        let node: Node = serde_json::from_str(node_json).unwrap();
+
+        let download_url_res =
+            include_str!("../tests/responses/download/download_url_ok_template.json");
+        let download_url_res = download_url_res.replace("$url", &download_url);
+
+        mock_server
+            .mock("POST", "/api/v4/nodes/files/2/downloads")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(download_url_res)
+            .create();
+
+        dracoon
+            .download_unencrypted(&node, &mut writer, None, None)
+            .await
+            .unwrap();
+
+        download_mock.assert();
+
+        assert_eq!(writer.buffer(), mock_bytes.to_vec());
+    }
 }
@@ -4,7 +4,7 @@ use reqwest::header;
 use crate::{
     client::{errors::DracoonClientError, Connected},
     constants::{DRACOON_API_PREFIX, FOLDERS_BASE, NODES_BASE},
-    utils::FromResponse,
+    utils::{FromResponse, WithTimestampTimezone},
 };
 
 use super::{
@@ -15,6 +15,8 @@ use super::{
 #[async_trait]
 impl Folders for NodesEndpoint<Connected> {
     async fn create_folder(&self, req: CreateFolderRequest) -> Result<Node, DracoonClientError> {
+        let req = req.with_timestamp_timezone(self.client().get_timestamp_timezone());
+
         let url_part = format!("/{DRACOON_API_PREFIX}/{NODES_BASE}/{FOLDERS_BASE}");
 
         let api_url = self.client().build_api_url(&url_part);
@@ -39,6 +41,8 @@ impl Folders for NodesEndpoint<Connected> {
         folder_id: u64,
         req: UpdateFolderRequest,
     ) -> Result<Node, DracoonClientError> {
+        let req = req.with_timestamp_timezone(self.client().get_timestamp_timezone());
+
         let url_part = format!("/{DRACOON_API_PREFIX}/{NODES_BASE}/{FOLDERS_BASE}/{folder_id}");
 
         let api_url = self.client().build_api_url(&url_part);
@@ -67,6 +71,7 @@ mod tests {
     use crate::{
         nodes::{NodeType, UserType},
         tests::dracoon::get_connected_client,
+        Dracoon, OAuth2Flow,
     };
 
     use super::*;
@@ -147,12 +152,105 @@ mod tests {
             .with_header("content-type", "application/json")
             .create();
 
-        let folder = CreateFolderRequest::builder("test", 123).build();
+        let folder = CreateFolderRequest::builder("test", 123).build().unwrap();
+        let folder = dracoon.nodes().create_folder(folder).await.unwrap();
+
+        assert_folder(&folder);
+    }
+
+    #[tokio::test]
+    async fn test_create_folder_with_typed_timestamps() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let folder_res = include_str!("../tests/responses/nodes/folder_ok.json");
+
+        let folder_mock = mock_server
+            .mock("POST", "/api/v4/nodes/folders")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"timestampCreation": "2020-01-01T00:00:00+00:00", "timestampModification": "2021-06-01T12:30:00+02:00"}"#.into(),
+            ))
+            .with_status(200)
+            .with_body(folder_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let timestamp_creation = DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+
+        let folder = CreateFolderRequest::builder("test", 123)
+            .with_timestamp_creation(timestamp_creation)
+            .with_timestamp_modification("2021-06-01T12:30:00+02:00")
+            .build()
+            .unwrap();
+        let folder = dracoon.nodes().create_folder(folder).await.unwrap();
+
+        folder_mock.assert();
+        assert_folder(&folder);
+    }
+
+    #[tokio::test]
+    async fn test_create_folder_with_timestamp_timezone() {
+        let mut mock_server = mockito::Server::new_async().await;
+
+        mock_server
+            .mock("POST", "/oauth/token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../client/tests/auth_ok.json"))
+            .create();
+
+        let dracoon = Dracoon::builder()
+            .with_base_url(mock_server.url())
+            .with_client_id("client_id")
+            .with_client_secret("client_secret")
+            .with_timestamp_timezone(chrono::FixedOffset::east_opt(2 * 3600).unwrap())
+            .build()
+            .unwrap()
+            .connect(OAuth2Flow::authorization_code("auth_code"))
+            .await
+            .unwrap();
+
+        let folder_res = include_str!("../tests/responses/nodes/folder_ok.json");
+
+        let folder_mock = mock_server
+            .mock("POST", "/api/v4/nodes/folders")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"timestampCreation": "2020-01-01T02:00:00+02:00"}"#.into(),
+            ))
+            .with_status(200)
+            .with_body(folder_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let timestamp_creation = DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+
+        let folder = CreateFolderRequest::builder("test", 123)
+            .with_timestamp_creation(timestamp_creation)
+            .build()
+            .unwrap();
         let folder = dracoon.nodes().create_folder(folder).await.unwrap();
 
+        folder_mock.assert();
         assert_folder(&folder);
     }
 
+    #[test]
+    fn test_create_folder_malformed_timestamp() {
+        let err = CreateFolderRequest::builder("test", 123)
+            .with_timestamp_creation("01.01.2020 00:00")
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            DracoonClientError::InvalidTimestamp("01.01.2020 00:00".into())
+        );
+        assert!(err.is_invalid_timestamp());
+    }
+
     #[tokio::test]
     async fn test_update_folder() {
         let (dracoon, mock_server) = get_connected_client().await;
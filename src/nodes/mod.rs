@@ -3,14 +3,23 @@
 pub use self::{models::*, rooms::models::*};
 use super::{client::errors::DracoonClientError, models::ListAllParams};
 use async_trait::async_trait;
-use tokio::io::{AsyncRead, AsyncWrite, BufReader};
+use futures_util::stream::BoxStream;
+use std::{collections::HashMap, path::Path, time::Duration};
+use tokio::{
+    io::{AsyncRead, AsyncSeek, AsyncWrite, BufReader},
+    sync::mpsc,
+};
 
+pub mod comments;
 pub mod download;
 pub mod folders;
 pub mod models;
 pub mod nodes;
+pub mod pending_uploads;
+pub mod recycle_bin;
 pub mod rooms;
 pub mod upload;
+pub mod upload_directory;
 
 /// This trait provides methods to manage nodes.
 /// Specifically, there's a method to obtain a node for a given path and
@@ -60,6 +69,98 @@ pub trait Nodes {
         room_manager: Option<bool>,
         params: Option<ListAllParams>,
     ) -> Result<NodeList, DracoonClientError>;
+    /// Get all nodes by parent id - all pages are fetched (max. 4 requests in parallel).
+    /// Filters and sorting of the passed params are applied to every page, the offset is overridden.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes, nodes::NodesFilter, models::ListAllParams};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let params = ListAllParams::builder()
+    ///    .with_filter(NodesFilter::is_file())
+    ///    .build();
+    ///
+    /// let nodes = dracoon.nodes().get_all_nodes(Some(123), None, Some(params)).await.unwrap();
+    /// assert_eq!(nodes.items.len() as u64, nodes.range.total);
+    /// # }
+    /// ```
+    async fn get_all_nodes(
+        &self,
+        parent_id: Option<u64>,
+        room_manager: Option<bool>,
+        params: Option<ListAllParams>,
+    ) -> Result<NodeList, DracoonClientError>;
+    /// Returns a stream of all nodes by parent id - pages are fetched as the stream is consumed.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes};
+    /// # use futures_util::TryStreamExt;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let mut nodes = dracoon.nodes().get_nodes_stream(Some(123), None, None);
+    ///
+    /// while let Some(node) = nodes.try_next().await.unwrap() {
+    ///     println!("{}", node.name);
+    /// }
+    /// # }
+    /// ```
+    fn get_nodes_stream(
+        &self,
+        parent_id: Option<u64>,
+        room_manager: Option<bool>,
+        params: Option<ListAllParams>,
+    ) -> BoxStream<'_, Result<Node, DracoonClientError>>;
+    /// Sends all nodes by parent id into a (bounded) channel - pages are fetched sequentially.
+    /// Sending waits for capacity (backpressure), so at most one page is held in memory.
+    /// Stops early if the receiver is dropped. Returns the number of sent nodes.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes, nodes::Node};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let (tx, mut rx) = tokio::sync::mpsc::channel::<Node>(100);
+    ///
+    /// let consumer = tokio::spawn(async move {
+    ///     while let Some(node) = rx.recv().await {
+    ///         println!("{}", node.name);
+    ///     }
+    /// });
+    ///
+    /// let count = dracoon.nodes().get_nodes_to_channel(Some(123), None, None, tx).await.unwrap();
+    /// consumer.await.unwrap();
+    /// # }
+    /// ```
+    async fn get_nodes_to_channel(
+        &self,
+        parent_id: Option<u64>,
+        room_manager: Option<bool>,
+        params: Option<ListAllParams>,
+        tx: mpsc::Sender<Node>,
+    ) -> Result<u64, DracoonClientError>;
     /// Searches for a node via given path.
     /// ```no_run
     /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes, nodes::{NodesFilter, NodesSortBy}, models::{ListAllParams, SortOrder}};
@@ -140,9 +241,12 @@ pub trait Nodes {
     /// # }
     /// ```
     async fn get_node(&self, node_id: u64) -> Result<Node, DracoonClientError>;
-    /// Deletes a node by id.
+    /// Waits until a node is available (e.g. after creating a node or finishing an upload).
+    /// The node is polled with exponential backoff as long as it is not found (404).
+    /// Returns `DracoonClientError::NodeNotAvailable` if the node is not available within the timeout.
     /// ```no_run
     /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes};
+    /// # use std::time::Duration;
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
@@ -154,11 +258,20 @@ pub trait Nodes {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    /// dracoon.nodes().delete_node(123).await.unwrap();
+    /// let node = dracoon
+    ///    .nodes()
+    ///    .wait_for_node(123, Duration::from_secs(10))
+    ///    .await
+    ///    .unwrap();
     /// # }
     /// ```
-    async fn delete_node(&self, node_id: u64) -> Result<(), DracoonClientError>;
-    /// Deletes multiple nodes by ids.
+    async fn wait_for_node(
+        &self,
+        node_id: u64,
+        timeout: Duration,
+    ) -> Result<Node, DracoonClientError>;
+    /// Returns the full path of a node by id (e.g. `/Room/Folder/file.txt`).
+    /// The path is not URL encoded and can be passed to `get_node_from_path`.
     /// ```no_run
     /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes};
     /// # #[tokio::main]
@@ -172,13 +285,13 @@ pub trait Nodes {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    /// let node_ids = vec![123, 456];
-    /// dracoon.nodes().delete_nodes(node_ids.into()).await.unwrap();
+    /// let path = dracoon.nodes().get_node_path(123).await.unwrap();
     /// # }
     /// ```
-
-    async fn delete_nodes(&self, req: DeleteNodesRequest) -> Result<(), DracoonClientError>;
-    /// Move nodes to a target parent node (folder or room).
+    async fn get_node_path(&self, node_id: u64) -> Result<String, DracoonClientError>;
+    /// Returns the node and all its ancestors ordered root-first (e.g. room, folder, file).
+    /// A top level room only returns the room itself.
+    /// Fails if a parent in the chain is not accessible.
     /// ```no_run
     /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes};
     /// # #[tokio::main]
@@ -192,16 +305,14 @@ pub trait Nodes {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    /// let node_ids = vec![123, 456];
-    /// dracoon.nodes().move_nodes(node_ids.into(), 789).await.unwrap();
+    /// let ancestors = dracoon.nodes().get_node_ancestors(123).await.unwrap();
+    /// let breadcrumbs = ancestors.iter().map(|node| node.name.as_str()).collect::<Vec<_>>().join(" > ");
     /// # }
     /// ```
-    async fn move_nodes(
-        &self,
-        req: TransferNodesRequest,
-        target_parent_id: u64,
-    ) -> Result<Node, DracoonClientError>;
-    /// Copy nodes to a target parent node (folder or room).
+    async fn get_node_ancestors(&self, node_id: u64) -> Result<Vec<Node>, DracoonClientError>;
+    /// Returns all nodes of the given ids that (still) exist.
+    /// Nodes that are not found are skipped - the order of the returned nodes is not guaranteed.
+    /// Any other error (e.g. authentication) fails the whole lookup.
     /// ```no_run
     /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes};
     /// # #[tokio::main]
@@ -215,25 +326,14 @@ pub trait Nodes {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    /// let node_ids = vec![123, 456];
-    /// dracoon.nodes().copy_nodes(node_ids.into(), 789).await.unwrap();
+    /// let nodes = dracoon.nodes().get_nodes_by_ids(&[1, 2, 3]).await.unwrap();
     /// # }
     /// ```
-    async fn copy_nodes(
-        &self,
-        req: TransferNodesRequest,
-        target_parent_id: u64,
-    ) -> Result<Node, DracoonClientError>;
-}
-
-#[async_trait]
-pub trait MissingFileKeys {
-    /// Distributes missing file keys using the user keypair.
-    /// Returns the total amount missing keys.
-    /// If the total amount is larger than 100, more keys need distribution
-    /// and the method should be called again.
+    async fn get_nodes_by_ids(&self, ids: &[u64]) -> Result<Vec<Node>, DracoonClientError>;
+    /// Returns a summary of a node's counters (comments, shares, deleted versions) and favorite status.
+    /// The node is only requested once.
     /// ```no_run
-    /// # use dco3::{Dracoon, OAuth2Flow, MissingFileKeys};
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes};
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
@@ -245,36 +345,15 @@ pub trait MissingFileKeys {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    /// let mut missing_keys = dracoon.distribute_missing_keys(None, None, None).await.unwrap();
-    ///
-    /// while missing_keys > 100 {
-    /// // loop until no more keys need distribution
-    /// missing_keys = dracoon.distribute_missing_keys(None, None, None).await.unwrap();
-    /// }
-    ///
-    /// // distribute missing keys for a specific room
-    /// let missing_room_keys = dracoon.distribute_missing_keys(Some(123), None, None).await.unwrap();
-    ///
-    /// // distribute missing keys for a specific file
-    /// let missing_file_keys = dracoon.distribute_missing_keys(None, Some(123), None).await.unwrap();
-    ///
-    /// // distribute missing keys for a specific user
-    /// let missing_user_keys = dracoon.distribute_missing_keys(None, None, Some(123)).await.unwrap();
-    ///
+    /// let stats = dracoon.nodes().get_node_stats(123).await.unwrap();
+    /// println!("Comments: {}", stats.cnt_comments);
     /// # }
-    async fn distribute_missing_keys(
-        &self,
-        room_id: Option<u64>,
-        file_id: Option<u64>,
-        user_id: Option<u64>,
-    ) -> Result<u64, DracoonClientError>;
-}
-
-#[async_trait]
-pub trait Folders {
-    /// Creates a folder in the provided parent room.
+    /// ```
+    async fn get_node_stats(&self, node_id: u64) -> Result<NodeStats, DracoonClientError>;
+    /// Returns the number of files per classification level (1 - 4) in a room (including all sub nodes).
+    /// A filtered search is done for each classification level.
     /// ```no_run
-    /// # use dco3::{Dracoon, auth::OAuth2Flow, Folders, nodes::CreateFolderRequest};
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes};
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
@@ -286,17 +365,19 @@ pub trait Folders {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    /// let folder = CreateFolderRequest::builder("My Folder", 123)
-    ///                                .with_classification(1)
-    ///                                .with_notes("My notes")
-    ///                                .build();
-    /// let folder = dracoon.nodes().create_folder(folder).await.unwrap();
+    /// let breakdown = dracoon.nodes().classification_breakdown(123).await.unwrap();
+    /// let confidential = breakdown.get(&3).copied().unwrap_or(0);
     /// # }
     /// ```
-    async fn create_folder(&self, req: CreateFolderRequest) -> Result<Node, DracoonClientError>;
-    /// Updates a folder with given params by id.
+    async fn classification_breakdown(
+        &self,
+        room_id: u64,
+    ) -> Result<HashMap<u8, u64>, DracoonClientError>;
+    /// Returns the total size (in bytes) of all files in a room or folder (including all sub nodes).
+    /// The depth can be limited (0: direct children only, -1 or `None`: full tree).
+    /// The files are fetched via search (paginated).
     /// ```no_run
-    /// # use dco3::{Dracoon, auth::OAuth2Flow, Folders, nodes::UpdateFolderRequest};
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes};
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
@@ -308,40 +389,20 @@ pub trait Folders {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    /// let update = UpdateFolderRequest::builder()
-    ///                              .with_name("My Folder")
-    ///                              .with_classification(2)
-    ///                              .build();
-    /// dracoon.nodes().update_folder(123, update).await.unwrap();
+    /// let size = dracoon.nodes().get_recursive_size(123, None).await.unwrap();
+    ///
+    /// // only files in the room or folder and one level below
+    /// let size = dracoon.nodes().get_recursive_size(123, Some(1)).await.unwrap();
     /// # }
     /// ```
-    async fn update_folder(
+    async fn get_recursive_size(
         &self,
-        folder_id: u64,
-        req: UpdateFolderRequest,
-    ) -> Result<Node, DracoonClientError>;
-}
-/// This trait provides methods to manage rooms.
-///
-///  - Create a room
-///  - Update a room
-///  - Configure a room
-///  - Get the policies of a room
-///  - Set the policies of a room
-///  - Encrypt a room
-///  - Get groups of a room
-///  - Add groups to a room
-///  - Delete groups from a room
-///  - Get users of a room
-///  - Add users to a room
-///  - Delete users from a room
-///  
-///  To delete a room, use the `delete_node` method from the `Nodes` trait.
-#[async_trait]
-pub trait Rooms {
-    /// Creates a room.
+        node_id: u64,
+        max_depth: Option<i8>,
+    ) -> Result<u64, DracoonClientError>;
+    /// Deletes a node by id.
     /// ```no_run
-    /// # use dco3::{Dracoon, auth::OAuth2Flow, Rooms, nodes::CreateRoomRequest};
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes};
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
@@ -353,20 +414,13 @@ pub trait Rooms {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    /// let room = CreateRoomRequest::builder("My Room")
-    ///                              .with_parent_id(123)
-    ///                              .with_classification(1)
-    ///                              .build();
-    /// let room = dracoon.nodes().create_room(room).await.unwrap();
+    /// dracoon.nodes().delete_node(123).await.unwrap();
     /// # }
     /// ```
-    async fn create_room(
-        &self,
-        create_room_req: CreateRoomRequest,
-    ) -> Result<Node, DracoonClientError>;
-    /// Updates a room by id.
+    async fn delete_node(&self, node_id: u64) -> Result<(), DracoonClientError>;
+    /// Deletes multiple nodes by ids.
     /// ```no_run
-    /// # use dco3::{Dracoon, auth::OAuth2Flow, Rooms, nodes::UpdateRoomRequest};
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes};
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
@@ -378,20 +432,19 @@ pub trait Rooms {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    /// let room = UpdateRoomRequest::builder()
-    ///                              .with_name("My new Room")
-    ///                              .build();
-    /// let room = dracoon.nodes().update_room(123, room).await.unwrap();
+    /// let node_ids = vec![123, 456];
+    /// dracoon.nodes().delete_nodes(node_ids.into()).await.unwrap();
     /// # }
     /// ```
-    async fn update_room(
-        &self,
-        room_id: u64,
-        update_room_req: UpdateRoomRequest,
-    ) -> Result<Node, DracoonClientError>;
-    /// Configures a room by id.
+
+    async fn delete_nodes(&self, req: DeleteNodesRequest) -> Result<(), DracoonClientError>;
+    /// Move nodes to a target parent node (folder or room).
+    /// Moving nodes between encrypted and unencrypted parents is refused with
+    /// `DracoonClientError::EncryptionBoundaryCrossed`.
+    /// To check this, the target parent and each node to move are fetched before the move
+    /// (one additional request per node, up to 5 concurrently).
     /// ```no_run
-    /// # use dco3::{Dracoon, auth::OAuth2Flow, Rooms, nodes::ConfigRoomRequest};
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes};
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
@@ -403,21 +456,18 @@ pub trait Rooms {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    /// let room = ConfigRoomRequest::builder()
-    ///                              .with_inherit_permissions(true)
-    ///                              .with_recycle_bin_retention_period(30)
-    ///                              .build();
-    /// let room = dracoon.nodes().config_room(123, room).await.unwrap();
+    /// let node_ids = vec![123, 456];
+    /// dracoon.nodes().move_nodes(node_ids.into(), 789).await.unwrap();
     /// # }
     /// ```
-    async fn config_room(
+    async fn move_nodes(
         &self,
-        room_id: u64,
-        config_room_req: ConfigRoomRequest,
+        req: TransferNodesRequest,
+        target_parent_id: u64,
     ) -> Result<Node, DracoonClientError>;
-    /// Gets the policies of a room by id.
+    /// Copy nodes to a target parent node (folder or room).
     /// ```no_run
-    /// # use dco3::{Dracoon, auth::OAuth2Flow, Rooms, nodes::RoomPolicies};
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes};
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
@@ -429,40 +479,56 @@ pub trait Rooms {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    ///  let policies = dracoon.nodes().get_room_policies(123).await.unwrap();
+    /// let node_ids = vec![123, 456];
+    /// dracoon.nodes().copy_nodes(node_ids.into(), 789).await.unwrap();
     /// # }
     /// ```
-    async fn get_room_policies(&self, room_id: u64) -> Result<RoomPolicies, DracoonClientError>;
-    /// Set the policies of a room by id.
+    async fn copy_nodes(
+        &self,
+        req: TransferNodesRequest,
+        target_parent_id: u64,
+    ) -> Result<Node, DracoonClientError>;
+    /// Copy nodes to a target parent node (folder or room) and report the result per node.
+    /// Each node is copied with a separate request - a conflict (node already exists at target,
+    /// e.g. with `ResolutionStrategy::Fail`) is returned as [DracoonClientError::NodeConflict].
+    /// The optional callback is called after each node (transferred, total).
+    /// The copy requests respond once the nodes are copied - there is no job to poll.
     /// ```no_run
-    /// # use dco3::{Dracoon, auth::OAuth2Flow, Rooms, nodes::RoomPoliciesRequest};
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes, nodes::{ResolutionStrategy, TransferNodesRequest}};
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
     /// #  .with_base_url("https://dracoon.team")
     /// #  .with_client_id("client_id")
-    /// #  .with_client_secret("client")
+    /// #  .with_client_secret("client_secret")
     /// #  .build()
     /// #  .unwrap()
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    /// let new_policies = RoomPoliciesRequest::builder()
-    ///                            .with_default_expiration_period(60 * 60 * 24 * 30)
-    ///                            .with_virus_protection_enabled(true)
-    ///                            .build();
-    /// dracoon.nodes().update_room_policies(123, new_policies).await.unwrap();
+    /// let req = TransferNodesRequest::new_from_ids(vec![123, 456])
+    ///     .with_resolution_strategy(ResolutionStrategy::Fail)
+    ///     .build();
+    ///
+    /// let callback = Box::new(|done: u64, total: u64| println!("{done}/{total}"));
+    /// let results = dracoon.nodes().copy_nodes_detailed(req, 789, Some(callback)).await;
+    ///
+    /// for conflict in results.iter().filter(|res| res.is_conflict()) {
+    ///    println!("Node {} already exists", conflict.node_id);
+    /// }
     /// # }
     /// ```
-    //
-    async fn update_room_policies(
+    async fn copy_nodes_detailed(
         &self,
-        room_id: u64,
-        policy_room_req: RoomPoliciesRequest,
-    ) -> Result<(), DracoonClientError>;
-    /// Encrypts a room by id.
+        req: TransferNodesRequest,
+        target_parent_id: u64,
+        mut callback: Option<TransferProgressCallback>,
+    ) -> Vec<TransferResult>;
+    /// Mark a node as favorite.
+    /// Returns a 403 Forbidden error (see [DracoonClientError::is_forbidden]) if the user
+    /// has no read permission on the node.
     /// ```no_run
-    /// # use dco3::{Dracoon, auth::OAuth2Flow, Rooms, nodes::EncryptRoomRequest};
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes};
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
@@ -474,21 +540,15 @@ pub trait Rooms {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    /// let encryption = EncryptRoomRequest::builder(true)
-    ///                           .try_with_data_room_rescue_key("Secret123")
-    ///                           .unwrap()
-    ///                           .build();
-    /// let room = dracoon.nodes().encrypt_room(123, encryption).await.unwrap();
+    /// dracoon.nodes().mark_favorite(123).await.unwrap();
     /// # }
     /// ```
-    async fn encrypt_room(
-        &self,
-        room_id: u64,
-        encrypt_room_req: EncryptRoomRequest,
-    ) -> Result<Node, DracoonClientError>;
-    /// Gets groups of a room by id with optional params.
+    async fn mark_favorite(&self, node_id: u64) -> Result<(), DracoonClientError>;
+    /// Remove the favorite mark of a node.
+    /// Returns a 403 Forbidden error (see [DracoonClientError::is_forbidden]) if the user
+    /// has no read permission on the node.
     /// ```no_run
-    /// # use dco3::{Dracoon, auth::OAuth2Flow, Rooms};
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes};
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
@@ -500,19 +560,74 @@ pub trait Rooms {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    /// let groups = dracoon.nodes().get_room_groups(123, None).await.unwrap();
+    /// dracoon.nodes().unmark_favorite(123).await.unwrap();
     /// # }
     /// ```
-    async fn get_room_groups(
+    async fn unmark_favorite(&self, node_id: u64) -> Result<(), DracoonClientError>;
+    /// Get all nodes marked as favorite by the user.
+    /// Passed filters are combined with the favorite filter.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes, ListAllParams};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let favorites = dracoon.nodes().get_favorites(None).await.unwrap();
+    ///
+    /// // paging is supported
+    /// let params = ListAllParams::builder()
+    ///   .with_offset(0)
+    ///   .with_limit(100)
+    ///   .build();
+    /// let favorites = dracoon.nodes().get_favorites(Some(params)).await.unwrap();
+    /// # }
+    /// ```
+    async fn get_favorites(
         &self,
-        room_id: u64,
         params: Option<ListAllParams>,
-    ) -> Result<RoomGroupList, DracoonClientError>;
-
-    /// Updates room groups by id.
-    /// Gets groups of a room by id with optional params.
+    ) -> Result<NodeList, DracoonClientError>;
+    /// Moves all files of a source room into date-based folders of a target room.
+    /// Files are grouped by their creation timestamp (`timestamp_creation`, falls back to `created_at`)
+    /// formatted with the given strftime-like pattern (e.g. "%Y-%m") - a "/" in the pattern creates nested folders.
+    /// Missing folders are created, existing folders are reused.
+    /// Returns the folders the files were moved to.
+    /// An invalid pattern is rejected with `DracoonClientError::InvalidDatePattern`.
     /// ```no_run
-    /// # use dco3::{Dracoon, OAuth2Flow, Rooms, nodes::{RoomGroupsAddBatchRequestItem, NodePermissions}};
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// // moves the files of room 123 into folders like "2024/03" in room 456
+    /// let folders = dracoon.nodes().organize_by_date(123, 456, "%Y/%m").await.unwrap();
+    /// # }
+    /// ```
+    async fn organize_by_date(
+        &self,
+        source_room_id: u64,
+        target_room_id: u64,
+        pattern: &str,
+    ) -> Result<Vec<Node>, DracoonClientError>;
+    /// Lists all descendants of a node (or of all top level rooms if no root is passed)
+    /// and returns the node ids with their full path (e.g. "/room/folder/file.txt").
+    /// Rooms and folders are always listed - files are only included if `include_files` is true.
+    /// Nodes are listed level by level and page by page, only the pending rooms and folders are kept in memory.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Nodes};
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
@@ -524,22 +639,32 @@ pub trait Rooms {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
+    /// // all rooms and folders below room 123
+    /// let paths = dracoon.nodes().list_all_paths(Some(123), false).await.unwrap();
     ///
-    /// // add a a list of updates
-    /// let group_updates = vec![RoomGroupsAddBatchRequestItem::new(123, NodePermissions::new_with_read_permissions(), None)];
-    /// dracoon.nodes().update_room_groups(123, group_updates.into()).await.unwrap();
+    /// for (node_id, path) in paths {
+    ///    println!("{node_id}: {path}");
+    /// }
+    ///
+    /// // all nodes (including files) of all rooms
+    /// let paths = dracoon.nodes().list_all_paths(None, true).await.unwrap();
     /// # }
     /// ```
-    async fn update_room_groups(
+    async fn list_all_paths(
         &self,
-        room_id: u64,
-        room_groups_update_req: RoomGroupsAddBatchRequest,
-    ) -> Result<(), DracoonClientError>;
+        root_id: Option<u64>,
+        include_files: bool,
+    ) -> Result<Vec<(u64, String)>, DracoonClientError>;
+}
 
-    /// Deletes room groups by id.
-    /// Gets groups of a room by id with optional params.
+#[async_trait]
+pub trait MissingFileKeys {
+    /// Distributes missing file keys using the user keypair.
+    /// Returns the total amount missing keys.
+    /// If the total amount is larger than 100, more keys need distribution
+    /// and the method should be called again.
     /// ```no_run
-    /// # use dco3::{Dracoon, OAuth2Flow, Rooms};
+    /// # use dco3::{Dracoon, OAuth2Flow, MissingFileKeys};
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
@@ -551,21 +676,39 @@ pub trait Rooms {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    /// // You can use a vec
-    /// let group_ids = vec![1, 2, 3];
-    /// dracoon.nodes().delete_room_groups(123, group_ids.into()).await.unwrap();
+    /// let mut missing_keys = dracoon.distribute_missing_keys(None, None, None).await.unwrap();
+    ///
+    /// while missing_keys > 100 {
+    /// // loop until no more keys need distribution
+    /// missing_keys = dracoon.distribute_missing_keys(None, None, None).await.unwrap();
+    /// }
+    ///
+    /// // alternatively, use `distribute_all_missing_keys` to distribute all keys
+    ///
+    /// // distribute missing keys for a specific room
+    /// let missing_room_keys = dracoon.distribute_missing_keys(Some(123), None, None).await.unwrap();
+    ///
+    /// // distribute missing keys for a specific file
+    /// let missing_file_keys = dracoon.distribute_missing_keys(None, Some(123), None).await.unwrap();
+    ///
+    /// // distribute missing keys for a specific user
+    /// let missing_user_keys = dracoon.distribute_missing_keys(None, None, Some(123)).await.unwrap();
+    ///
     /// # }
-    /// ```
-    async fn delete_room_groups(
+    async fn distribute_missing_keys(
         &self,
-        room_id: u64,
-        room_groups_del_req: RoomGroupsDeleteBatchRequest,
-    ) -> Result<(), DracoonClientError>;
+        room_id: Option<u64>,
+        file_id: Option<u64>,
+        user_id: Option<u64>,
+    ) -> Result<u64, DracoonClientError>;
 
-    /// Gets users of a room by id with optional params.
-    /// Gets groups of a room by id with optional params.
+    /// Distributes all missing file keys using the user keypair.
+    /// Missing keys are distributed in batches until no more keys are missing.
+    /// Returns the total amount of distributed keys.
+    /// If the amount of missing keys does not decrease for several batches (e.g. because
+    /// keys cannot be distributed), an error is returned instead of looping forever.
     /// ```no_run
-    /// # use dco3::{Dracoon, OAuth2Flow, Rooms};
+    /// # use dco3::{Dracoon, OAuth2Flow, MissingFileKeys};
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
@@ -577,20 +720,62 @@ pub trait Rooms {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
+    /// let distributed_keys = dracoon.distribute_all_missing_keys(None, None, None).await.unwrap();
+    ///
+    /// // distribute all missing keys for a specific room
+    /// let distributed_room_keys = dracoon.distribute_all_missing_keys(Some(123), None, None).await.unwrap();
+    /// # }
+    /// ```
+    async fn distribute_all_missing_keys(
+        &self,
+        room_id: Option<u64>,
+        file_id: Option<u64>,
+        user_id: Option<u64>,
+    ) -> Result<u64, DracoonClientError>;
 
-    /// let users = dracoon.nodes().get_room_users(123, None).await.unwrap();
+    /// Copies nodes to a target parent and distributes the file keys of the copied files
+    /// to all users of the target room (if the target is encrypted).
+    /// The file keys are decrypted with the user keypair and re-encrypted for each user
+    /// using the missing keys flow (see `distribute_all_missing_keys`) - only keys of the
+    /// copied files (including files within copied folders) are distributed.
+    /// The copied nodes are determined by listing the target parent before and after the copy.
+    /// If the distribution fails, the copied nodes are still returned and the error is
+    /// passed in `key_distribution`.
+    /// ```no_run
+    /// # use dco3::{Dracoon, OAuth2Flow, MissingFileKeys};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .with_encryption_password("TopSecret1234!")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let node_ids = vec![1, 2, 3];
+    /// let result = dracoon.copy_nodes_with_keys(node_ids.into(), 789).await.unwrap();
+    ///
+    /// if let Err(err) = result.key_distribution {
+    ///    println!("Copied {} nodes, key distribution failed: {}", result.nodes.len(), err);
+    /// }
     /// # }
     /// ```
-    async fn get_room_users(
+    async fn copy_nodes_with_keys(
         &self,
-        room_id: u64,
-        params: Option<ListAllParams>,
-    ) -> Result<RoomUserList, DracoonClientError>;
+        req: TransferNodesRequest,
+        target_parent_id: u64,
+    ) -> Result<CopyWithKeysResult, DracoonClientError>;
+}
 
-    /// Updates room users by id.
-    /// Gets groups of a room by id with optional params.
+/// This trait provides methods to manage node comments.
+#[async_trait]
+pub trait NodeComments {
+    /// Returns the comments of a node.
     /// ```no_run
-    /// # use dco3::{Dracoon, OAuth2Flow, Rooms, nodes::{RoomUsersAddBatchRequestItem, NodePermissions}};
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, NodeComments, ListAllParams};
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
@@ -602,21 +787,24 @@ pub trait Rooms {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
+    /// let comments = dracoon.nodes().get_node_comments(123, None).await.unwrap();
     ///
-    /// // add a a list of updates
-    /// let user_updates = vec![RoomUsersAddBatchRequestItem::new(123, NodePermissions::new_with_read_permissions())];
-    /// dracoon.nodes().update_room_users(123, user_updates.into()).await.unwrap();
+    /// // paging is supported
+    /// let params = ListAllParams::builder()
+    ///   .with_offset(0)
+    ///   .with_limit(100)
+    ///   .build();
+    /// let comments = dracoon.nodes().get_node_comments(123, Some(params)).await.unwrap();
     /// # }
     /// ```
-    async fn update_room_users(
+    async fn get_node_comments(
         &self,
-        room_id: u64,
-        room_users_update_req: RoomUsersAddBatchRequest,
-    ) -> Result<(), DracoonClientError>;
-    /// Deletes room users by id.
-    /// Gets groups of a room by id with optional params.
+        node_id: u64,
+        params: Option<ListAllParams>,
+    ) -> Result<CommentList, DracoonClientError>;
+    /// Creates a comment on a node.
     /// ```no_run
-    /// # use dco3::{Dracoon, OAuth2Flow, Rooms};
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, NodeComments};
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
@@ -628,20 +816,17 @@ pub trait Rooms {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    /// // You can use a vec
-    /// let user_ids = vec![1, 2, 3];
-    /// dracoon.nodes().delete_room_users(123, user_ids.into()).await.unwrap();
+    /// let comment = dracoon.nodes().create_comment(123, "Looks good!").await.unwrap();
     /// # }
     /// ```
-    async fn delete_room_users(
+    async fn create_comment(
         &self,
-        room_id: u64,
-        room_users_del_req: RoomUsersDeleteBatchRequest,
-    ) -> Result<(), DracoonClientError>;
-    /// Deletes room users by id.
-    /// Gets groups of a room by id with optional params.
+        node_id: u64,
+        text: impl Into<String> + Send,
+    ) -> Result<Comment, DracoonClientError>;
+    /// Updates the text of a comment (only possible for the author).
     /// ```no_run
-    /// # use dco3::{Dracoon, OAuth2Flow, Rooms, nodes::RoomGuestUserInvitation};
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, NodeComments};
     /// # #[tokio::main]
     /// # async fn main() {
     /// # let dracoon = Dracoon::builder()
@@ -653,94 +838,972 @@ pub trait Rooms {
     /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
     /// #  .await
     /// #  .unwrap();
-    /// // You can use a vec
-    /// let guest = RoomGuestUserInvitation::new("email", "first name", "last name");
-    /// dracoon.nodes().invite_guest_users(123, vec![guest].into()).await.unwrap();
+    /// let comment = dracoon.nodes().update_comment(1, "Looks great!").await.unwrap();
+    /// assert!(comment.is_changed);
     /// # }
     /// ```
-    async fn invite_guest_users(
+    async fn update_comment(
         &self,
-        room_id: u64,
-        invite_req: RoomGuestUserAddRequest,
-    ) -> Result<(), DracoonClientError>;
-}
-/// This trait represents the download functionality and provides
-/// a signle method to download a stream of bytes to a writer.
-/// This rquires a mutable reference to the client because the download method
-/// needs to be able to check for the secret and set it for the client if encryption is used.
-#[async_trait]
-pub trait Download {
-    /// Downloads a file (node) to the given writer buffer
-    /// Example
+        comment_id: u64,
+        text: impl Into<String> + Send,
+    ) -> Result<Comment, DracoonClientError>;
+    /// Deletes a comment.
     /// ```no_run
-    /// use dco3::{Dracoon, OAuth2Flow, Download, Nodes};
-    ///
-    /// #[tokio::main]
-    /// async fn main() {
-    ///    let client = Dracoon::builder()
-    ///      .with_base_url("https://dracoon.team")
-    ///      .with_client_id("client_id")
-    ///      .with_client_secret("client_secret")
-    ///      // if you do not pass a password, encrypted uploads will fail with an error
-    ///      .with_encryption_password("encryption_password")
-    ///      .build()
-    ///      .unwrap()
-    ///      .connect(OAuth2Flow::password_flow("username", "password"))
-    ///      .await
-    ///      .unwrap();
-    ///
-    ///   let node_id = 123u64;
-    ///
-    ///   let node = client.nodes().get_node(node_id).await.unwrap();
-    ///
-    ///   let mut writer = tokio::io::BufWriter::new(tokio::fs::File::create("test.txt").await.unwrap());
-    ///
-    ///   client.download(&node, &mut writer, None, None).await.unwrap();
-    ///
-    ///   // or with progress callback (boxed closure)
-    ///   client.download(&node, &mut writer, Some(Box::new(|progress, total| {
-    ///    println!("Download progress: {}", progress);
-    ///    println!("File total: {}", total);
-    ///  })), None).await.unwrap();
-    /// // or with chunksize
-    /// let  chunksize: usize = 1024 * 1024 * 10;
-    /// client.download(&node, &mut writer, None, Some(chunksize)).await.unwrap();
-    /// }
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, NodeComments};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// dracoon.nodes().delete_comment(1).await.unwrap();
+    /// # }
     /// ```
-    async fn download<'w>(
-        &'w self,
-        node: &Node,
-        writer: &'w mut (dyn AsyncWrite + Send + Unpin),
-        mut callback: Option<DownloadProgressCallback>,
-        chunksize: Option<usize>,
-    ) -> Result<(), DracoonClientError>;
+    async fn delete_comment(&self, comment_id: u64) -> Result<(), DracoonClientError>;
 }
 
-/// This trait represents the upload functionality and provides
-/// a single method to upload a stream of bytes by passing a buffered reader.
-/// This rquires a mutable reference to the client because the upload method
-/// needs to be able to check for the secret and set it for the client if encryption is used.
+/// This trait provides methods to manage the recycle bin (deleted nodes) of a room.
 #[async_trait]
-pub trait Upload<R: AsyncRead> {
-    /// Uploads a stream (buffered reader) with given file meta info to the given parent node
-    /// # Example
+pub trait RecycleBin {
+    /// Returns the deleted nodes of a room or folder.
+    /// Deleted nodes are grouped by name - each entry contains the amount of deleted versions
+    /// (see `get_deleted_node_versions`).
     /// ```no_run
-    /// use dco3::{Dracoon, OAuth2Flow, Upload, Nodes, nodes::{FileMeta, UploadOptions, ResolutionStrategy}};
-    /// #[cfg(not(doctest))]
-    /// #[tokio::main]
-    /// async fn main() {
-    ///    let client = Dracoon::builder()
-    ///      .with_base_url("https://dracoon.team")
-    ///      .with_client_id("client_id")
-    ///      .with_client_secret("client_secret")
-    ///      // if you do not pass a password, encrypted uploads will fail with an error
-    ///      .with_encryption_password("encryption_password")
-    ///      .build()
-    ///      .unwrap()
-    ///      .connect(OAuth2Flow::password_flow("username", "password"))
-    ///      .await
-    ///      .unwrap();
-    ///
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, RecycleBin, ListAllParams};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let deleted_nodes = dracoon.nodes().get_deleted_nodes(123, None).await.unwrap();
+    ///
+    /// // paging is supported
+    /// let params = ListAllParams::builder()
+    ///   .with_offset(0)
+    ///   .with_limit(100)
+    ///   .build();
+    /// let deleted_nodes = dracoon.nodes().get_deleted_nodes(123, Some(params)).await.unwrap();
+    /// # }
+    /// ```
+    async fn get_deleted_nodes(
+        &self,
+        parent_id: u64,
+        params: Option<ListAllParams>,
+    ) -> Result<DeletedNodeSummaryList, DracoonClientError>;
+    /// Returns all deleted versions of a node (by type and name) in a room or folder.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, RecycleBin, nodes::NodeType};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let versions = dracoon.nodes().get_deleted_node_versions(123, NodeType::File, "report.pdf", None).await.unwrap();
+    /// # }
+    /// ```
+    async fn get_deleted_node_versions(
+        &self,
+        parent_id: u64,
+        node_type: NodeType,
+        name: &str,
+        params: Option<ListAllParams>,
+    ) -> Result<DeletedNodeVersionsList, DracoonClientError>;
+    /// Restores deleted nodes (versions).
+    /// Name conflicts with existing nodes are resolved with the given `ResolutionStrategy` (default: autorename) -
+    /// with `ResolutionStrategy::Fail`, a conflict is returned as `DracoonClientError::NodeConflict`.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, RecycleBin, nodes::{ResolutionStrategy, RestoreDeletedNodesRequest}};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// dracoon.nodes().restore_nodes(vec![1, 2, 3].into()).await.unwrap();
+    ///
+    /// // overwrite existing nodes with the same name
+    /// let req = RestoreDeletedNodesRequest::builder(vec![1, 2, 3])
+    ///   .with_resolution_strategy(ResolutionStrategy::Overwrite)
+    ///   .build();
+    /// dracoon.nodes().restore_nodes(req).await.unwrap();
+    /// # }
+    /// ```
+    async fn restore_nodes(
+        &self,
+        req: RestoreDeletedNodesRequest,
+    ) -> Result<(), DracoonClientError>;
+    /// Empties the recycle bin of a room (all deleted versions are removed permanently).
+    /// Requires the `delete_recycle_bin` permission.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, RecycleBin};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// dracoon.nodes().empty_recycle_bin(123).await.unwrap();
+    /// # }
+    /// ```
+    async fn empty_recycle_bin(&self, parent_id: u64) -> Result<(), DracoonClientError>;
+}
+
+/// This trait provides methods to list and cancel pending (in-progress) uploads of the current user.
+#[async_trait]
+pub trait PendingUploads {
+    /// Returns a list of pending uploads.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, PendingUploads};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let pending_uploads = dracoon.nodes().get_pending_uploads(None).await.unwrap();
+    /// # }
+    /// ```
+    async fn get_pending_uploads(
+        &self,
+        params: Option<ListAllParams>,
+    ) -> Result<PendingUploadList, DracoonClientError>;
+    /// Cancels pending uploads by upload id.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, PendingUploads};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let pending_uploads = dracoon.nodes().get_pending_uploads(None).await.unwrap();
+    /// let upload_ids = pending_uploads.items.into_iter().map(|upload| upload.upload_id).collect();
+    ///
+    /// dracoon.nodes().cancel_pending_uploads(upload_ids).await.unwrap();
+    /// # }
+    /// ```
+    async fn cancel_pending_uploads(
+        &self,
+        upload_ids: Vec<String>,
+    ) -> Result<(), DracoonClientError>;
+}
+
+#[async_trait]
+pub trait Folders {
+    /// Creates a folder in the provided parent room.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Folders, nodes::CreateFolderRequest};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let folder = CreateFolderRequest::builder("My Folder", 123)
+    ///                                .with_classification(1)
+    ///                                .with_notes("My notes")
+    ///                                .build()
+    ///                                .unwrap();
+    /// let folder = dracoon.nodes().create_folder(folder).await.unwrap();
+    /// # }
+    /// ```
+    async fn create_folder(&self, req: CreateFolderRequest) -> Result<Node, DracoonClientError>;
+    /// Updates a folder with given params by id.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Folders, nodes::UpdateFolderRequest};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let update = UpdateFolderRequest::builder()
+    ///                              .with_name("My Folder")
+    ///                              .with_classification(2)
+    ///                              .build();
+    /// dracoon.nodes().update_folder(123, update).await.unwrap();
+    /// # }
+    /// ```
+    async fn update_folder(
+        &self,
+        folder_id: u64,
+        req: UpdateFolderRequest,
+    ) -> Result<Node, DracoonClientError>;
+}
+/// This trait provides methods to manage rooms.
+///
+///  - Create a room
+///  - Update a room
+///  - Configure a room
+///  - Get the policies of a room
+///  - Set the policies of a room
+///  - Encrypt a room
+///  - Get groups of a room
+///  - Add groups to a room
+///  - Delete groups from a room
+///  - Get users of a room
+///  - Add users to a room
+///  - Delete users from a room
+///  
+///  To delete a room, use the `delete_node` method from the `Nodes` trait.
+#[async_trait]
+pub trait Rooms {
+    /// Creates a room.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Rooms, nodes::CreateRoomRequest};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let room = CreateRoomRequest::builder("My Room")
+    ///                              .with_parent_id(123)
+    ///                              .with_classification(1)
+    ///                              .build();
+    /// let room = dracoon.nodes().create_room(room).await.unwrap();
+    /// # }
+    /// ```
+    async fn create_room(
+        &self,
+        create_room_req: CreateRoomRequest,
+    ) -> Result<Node, DracoonClientError>;
+    /// Updates a room by id.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Rooms, nodes::UpdateRoomRequest};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let room = UpdateRoomRequest::builder()
+    ///                              .with_name("My new Room")
+    ///                              .build();
+    /// let room = dracoon.nodes().update_room(123, room).await.unwrap();
+    /// # }
+    /// ```
+    async fn update_room(
+        &self,
+        room_id: u64,
+        update_room_req: UpdateRoomRequest,
+    ) -> Result<Node, DracoonClientError>;
+    /// Configures a room by id.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Rooms, nodes::ConfigRoomRequest};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let room = ConfigRoomRequest::builder()
+    ///                              .with_inherit_permissions(true)
+    ///                              .with_recycle_bin_retention_period(30)
+    ///                              .build();
+    /// let room = dracoon.nodes().config_room(123, room).await.unwrap();
+    /// # }
+    /// ```
+    async fn config_room(
+        &self,
+        room_id: u64,
+        config_room_req: ConfigRoomRequest,
+    ) -> Result<Node, DracoonClientError>;
+    /// Gets the policies of a room by id.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Rooms, nodes::RoomPolicies};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    ///  let policies = dracoon.nodes().get_room_policies(123).await.unwrap();
+    /// # }
+    /// ```
+    async fn get_room_policies(&self, room_id: u64) -> Result<RoomPolicies, DracoonClientError>;
+    /// Gets the quota of a room by id (quota, used size and available space).
+    /// If no quota is set, `quota` and `available` are `None`.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Rooms};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    ///  let quota = dracoon.nodes().get_room_quota(123).await.unwrap();
+    ///  if let Some(available) = quota.available {
+    ///     println!("{} bytes left", available);
+    ///  }
+    /// # }
+    /// ```
+    async fn get_room_quota(&self, room_id: u64) -> Result<RoomQuota, DracoonClientError>;
+    /// Set the policies of a room by id.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Rooms, nodes::RoomPoliciesRequest};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let new_policies = RoomPoliciesRequest::builder()
+    ///                            .with_default_expiration_period(60 * 60 * 24 * 30)
+    ///                            .with_virus_protection_enabled(true)
+    ///                            .build();
+    /// dracoon.nodes().update_room_policies(123, new_policies).await.unwrap();
+    /// # }
+    /// ```
+    //
+    async fn update_room_policies(
+        &self,
+        room_id: u64,
+        policy_room_req: RoomPoliciesRequest,
+    ) -> Result<(), DracoonClientError>;
+    /// Sets the same policies for multiple rooms by id.
+    /// Rooms are updated concurrently (bounded); the result for each room is returned in the
+    /// order of the passed room ids.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Rooms, nodes::RoomPoliciesRequest};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let new_policies = RoomPoliciesRequest::builder()
+    ///                            .with_virus_protection_enabled(true)
+    ///                            .build();
+    /// let results = dracoon.nodes().update_rooms_policies(vec![1, 2, 3], new_policies).await;
+    ///
+    /// for (room_id, result) in results {
+    ///     if let Err(err) = result {
+    ///         println!("Failed to update room {room_id}: {err}");
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    async fn update_rooms_policies(
+        &self,
+        room_ids: Vec<u64>,
+        policy_room_req: RoomPoliciesRequest,
+    ) -> Vec<(u64, Result<(), DracoonClientError>)>;
+    /// Encrypts a room by id.
+    /// If no rescue key is provided, the user must have a keypair - otherwise
+    /// [DracoonClientError::MissingRoomEncryptionKey] is returned without encrypting the room.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Rooms, nodes::EncryptRoomRequest};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let encryption = EncryptRoomRequest::builder(true)
+    ///                           .try_with_data_room_rescue_key("Secret123")
+    ///                           .unwrap()
+    ///                           .build();
+    /// let room = dracoon.nodes().encrypt_room(123, encryption).await.unwrap();
+    /// # }
+    /// ```
+    async fn encrypt_room(
+        &self,
+        room_id: u64,
+        encrypt_room_req: EncryptRoomRequest,
+    ) -> Result<Node, DracoonClientError>;
+    /// Gets groups of a room by id with optional params.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Rooms};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let groups = dracoon.nodes().get_room_groups(123, None).await.unwrap();
+    /// # }
+    /// ```
+    async fn get_room_groups(
+        &self,
+        room_id: u64,
+        params: Option<ListAllParams>,
+    ) -> Result<RoomGroupList, DracoonClientError>;
+
+    /// Updates room groups by id.
+    /// Gets groups of a room by id with optional params.
+    /// ```no_run
+    /// # use dco3::{Dracoon, OAuth2Flow, Rooms, nodes::{RoomGroupsAddBatchRequestItem, NodePermissions}};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    ///
+    /// // add a a list of updates
+    /// let group_updates = vec![RoomGroupsAddBatchRequestItem::new(123, NodePermissions::new_with_read_permissions(), None)];
+    /// dracoon.nodes().update_room_groups(123, group_updates.into()).await.unwrap();
+    /// # }
+    /// ```
+    async fn update_room_groups(
+        &self,
+        room_id: u64,
+        room_groups_update_req: RoomGroupsAddBatchRequest,
+    ) -> Result<(), DracoonClientError>;
+
+    /// Deletes room groups by id.
+    /// Gets groups of a room by id with optional params.
+    /// ```no_run
+    /// # use dco3::{Dracoon, OAuth2Flow, Rooms};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// // You can use a vec
+    /// let group_ids = vec![1, 2, 3];
+    /// dracoon.nodes().delete_room_groups(123, group_ids.into()).await.unwrap();
+    /// # }
+    /// ```
+    async fn delete_room_groups(
+        &self,
+        room_id: u64,
+        room_groups_del_req: RoomGroupsDeleteBatchRequest,
+    ) -> Result<(), DracoonClientError>;
+
+    /// Gets users of a room by id with optional params.
+    /// Gets groups of a room by id with optional params.
+    /// ```no_run
+    /// # use dco3::{Dracoon, OAuth2Flow, Rooms};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+
+    /// let users = dracoon.nodes().get_room_users(123, None).await.unwrap();
+    /// # }
+    /// ```
+    async fn get_room_users(
+        &self,
+        room_id: u64,
+        params: Option<ListAllParams>,
+    ) -> Result<RoomUserList, DracoonClientError>;
+
+    /// Gets a single user of a room by id with explicit and effective permissions.
+    /// `is_inherited` is set if the user has no explicit permissions, but inherits them from a group.
+    /// ```no_run
+    /// # use dco3::{Dracoon, OAuth2Flow, Rooms};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let user = dracoon.nodes().get_room_user(123, 3).await.unwrap();
+    /// if user.is_inherited {
+    ///     println!("Permissions are inherited from a group");
+    /// }
+    /// # }
+    /// ```
+    async fn get_room_user(
+        &self,
+        room_id: u64,
+        user_id: i64,
+    ) -> Result<RoomUserItem, DracoonClientError>;
+
+    /// Updates room users by id.
+    /// Gets groups of a room by id with optional params.
+    /// ```no_run
+    /// # use dco3::{Dracoon, OAuth2Flow, Rooms, nodes::{RoomUsersAddBatchRequestItem, NodePermissions}};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    ///
+    /// // add a a list of updates
+    /// let user_updates = vec![RoomUsersAddBatchRequestItem::new(123, NodePermissions::new_with_read_permissions())];
+    /// dracoon.nodes().update_room_users(123, user_updates.into()).await.unwrap();
+    /// # }
+    /// ```
+    async fn update_room_users(
+        &self,
+        room_id: u64,
+        room_users_update_req: RoomUsersAddBatchRequest,
+    ) -> Result<(), DracoonClientError>;
+    /// Deletes room users by id.
+    /// Gets groups of a room by id with optional params.
+    /// ```no_run
+    /// # use dco3::{Dracoon, OAuth2Flow, Rooms};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// // You can use a vec
+    /// let user_ids = vec![1, 2, 3];
+    /// dracoon.nodes().delete_room_users(123, user_ids.into()).await.unwrap();
+    /// # }
+    /// ```
+    async fn delete_room_users(
+        &self,
+        room_id: u64,
+        room_users_del_req: RoomUsersDeleteBatchRequest,
+    ) -> Result<(), DracoonClientError>;
+    /// Deletes room users by id.
+    /// Gets groups of a room by id with optional params.
+    /// ```no_run
+    /// # use dco3::{Dracoon, OAuth2Flow, Rooms, nodes::RoomGuestUserInvitation};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// // You can use a vec
+    /// let guest = RoomGuestUserInvitation::new("email", "first name", "last name");
+    /// dracoon.nodes().invite_guest_users(123, vec![guest].into()).await.unwrap();
+    /// # }
+    /// ```
+    async fn invite_guest_users(
+        &self,
+        room_id: u64,
+        invite_req: RoomGuestUserAddRequest,
+    ) -> Result<(), DracoonClientError>;
+    /// Checks if a room name is available under the given parent (None: top level).
+    /// Rooms the user cannot see are not considered - creating the room may still fail with a conflict.
+    /// ```no_run
+    /// # use dco3::{Dracoon, OAuth2Flow, Rooms, nodes::CreateRoomRequest};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// if dracoon.nodes().room_name_available(Some(123), "My Room").await.unwrap() {
+    ///     let room = CreateRoomRequest::builder("My Room").with_parent_id(123).build();
+    ///     dracoon.nodes().create_room(room).await.unwrap();
+    /// }
+    /// # }
+    /// ```
+    async fn room_name_available(
+        &self,
+        parent_id: Option<u64>,
+        name: &str,
+    ) -> Result<bool, DracoonClientError>;
+}
+/// This trait represents the download functionality and provides
+/// a signle method to download a stream of bytes to a writer.
+/// This rquires a mutable reference to the client because the download method
+/// needs to be able to check for the secret and set it for the client if encryption is used.
+#[async_trait]
+pub trait Download {
+    /// Downloads a file (node) to the given writer buffer
+    /// The content is written as served (gzip compressed content is not inflated) -
+    /// use `download_unpacked` to inflate it.
+    /// For nodes not marked as encrypted, the file key is requested first (one additional request) - if a file key
+    /// is available, `DracoonClientError::EncryptionStateMismatch` is returned instead of writing ciphertext
+    /// (or the node is downloaded encrypted if its encryption state is unknown).
+    /// Any error other than 404 Not Found on the file key request is returned.
+    /// Example
+    /// ```no_run
+    /// use dco3::{Dracoon, OAuth2Flow, Download, Nodes};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = Dracoon::builder()
+    ///      .with_base_url("https://dracoon.team")
+    ///      .with_client_id("client_id")
+    ///      .with_client_secret("client_secret")
+    ///      // if you do not pass a password, encrypted uploads will fail with an error
+    ///      .with_encryption_password("encryption_password")
+    ///      .build()
+    ///      .unwrap()
+    ///      .connect(OAuth2Flow::password_flow("username", "password"))
+    ///      .await
+    ///      .unwrap();
+    ///
+    ///   let node_id = 123u64;
+    ///
+    ///   let node = client.nodes().get_node(node_id).await.unwrap();
+    ///
+    ///   let mut writer = tokio::io::BufWriter::new(tokio::fs::File::create("test.txt").await.unwrap());
+    ///
+    ///   client.download(&node, &mut writer, None, None).await.unwrap();
+    ///
+    ///   // or with progress callback (boxed closure)
+    ///   client.download(&node, &mut writer, Some(Box::new(|progress, total| {
+    ///    println!("Download progress: {}", progress);
+    ///    println!("File total: {}", total);
+    ///  })), None).await.unwrap();
+    /// // or with chunksize
+    /// let  chunksize: usize = 1024 * 1024 * 10;
+    /// client.download(&node, &mut writer, None, Some(chunksize)).await.unwrap();
+    /// }
+    /// ```
+    async fn download<'w>(
+        &'w self,
+        node: &Node,
+        writer: &'w mut (dyn AsyncWrite + Send + Unpin),
+        mut callback: Option<DownloadProgressCallback>,
+        chunksize: Option<usize>,
+    ) -> Result<(), DracoonClientError>;
+
+    /// Downloads a file (node) to the given writer buffer and inflates gzip compressed content on the fly.
+    /// Compressed content (e.g. served with `Content-Encoding: gzip` or a gzip archive) is detected by its
+    /// magic bytes - any other content is written unchanged.
+    /// The progress callback reports the downloaded (compressed) bytes.
+    /// Example
+    /// ```no_run
+    /// use dco3::{Dracoon, OAuth2Flow, Download, Nodes};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = Dracoon::builder()
+    ///      .with_base_url("https://dracoon.team")
+    ///      .with_client_id("client_id")
+    ///      .with_client_secret("client_secret")
+    ///      .build()
+    ///      .unwrap()
+    ///      .connect(OAuth2Flow::password_flow("username", "password"))
+    ///      .await
+    ///      .unwrap();
+    ///
+    ///   let node = client.nodes().get_node(123).await.unwrap();
+    ///
+    ///   let mut writer = tokio::io::BufWriter::new(tokio::fs::File::create("test.txt").await.unwrap());
+    ///
+    ///   client.download_unpacked(&node, &mut writer, None, None).await.unwrap();
+    /// }
+    /// ```
+    async fn download_unpacked<'w>(
+        &'w self,
+        node: &Node,
+        writer: &'w mut (dyn AsyncWrite + Send + Unpin),
+        mut callback: Option<DownloadProgressCallback>,
+        chunksize: Option<usize>,
+    ) -> Result<(), DracoonClientError>;
+
+    /// Downloads a byte range (end exclusive) of a file (node) to the given writer buffer
+    /// This can be used to resume interrupted downloads or to read the header of a file.
+    /// The range must not be empty and must not exceed the file size - otherwise
+    /// `DracoonClientError::InvalidRange` is returned.
+    /// Range downloads of encrypted nodes are not supported (`DracoonClientError::EncryptedRangeDownload`).
+    /// Example
+    /// ```no_run
+    /// use dco3::{Dracoon, OAuth2Flow, Download, Nodes};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = Dracoon::builder()
+    ///      .with_base_url("https://dracoon.team")
+    ///      .with_client_id("client_id")
+    ///      .with_client_secret("client_secret")
+    ///      .build()
+    ///      .unwrap()
+    ///      .connect(OAuth2Flow::password_flow("username", "password"))
+    ///      .await
+    ///      .unwrap();
+    ///
+    ///   let node = client.nodes().get_node(123).await.unwrap();
+    ///
+    ///   // first 1 KB of the file
+    ///   let mut header = Vec::new();
+    ///   client.download_range(&node, &mut header, 0..1024, None).await.unwrap();
+    /// }
+    /// ```
+    async fn download_range<'w>(
+        &'w self,
+        node: &Node,
+        writer: &'w mut (dyn AsyncWrite + Send + Unpin),
+        range: std::ops::Range<u64>,
+        mut callback: Option<DownloadProgressCallback>,
+    ) -> Result<(), DracoonClientError>;
+
+    /// Downloads a file by path (resolved via `get_node_from_path`) to the given writer buffer
+    /// Returns a 404 Not Found error if the path does not resolve to a node.
+    /// Example
+    /// ```no_run
+    /// use dco3::{Dracoon, OAuth2Flow, Download};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = Dracoon::builder()
+    ///      .with_base_url("https://dracoon.team")
+    ///      .with_client_id("client_id")
+    ///      .with_client_secret("client_secret")
+    ///      .build()
+    ///      .unwrap()
+    ///      .connect(OAuth2Flow::password_flow("username", "password"))
+    ///      .await
+    ///      .unwrap();
+    ///
+    ///   let mut writer = tokio::io::BufWriter::new(tokio::fs::File::create("test.txt").await.unwrap());
+    ///
+    ///   client.download_path("/some/room/test.txt", &mut writer, None, None).await.unwrap();
+    /// }
+    /// ```
+    async fn download_path<'w>(
+        &'w self,
+        path: &str,
+        writer: &'w mut (dyn AsyncWrite + Send + Unpin),
+        mut callback: Option<DownloadProgressCallback>,
+        chunksize: Option<usize>,
+    ) -> Result<(), DracoonClientError>;
+
+    /// Downloads a (small) file (node) into memory and returns the (decrypted) bytes.
+    /// Files exceeding the max. size (default: 100 MB) are rejected with `DracoonClientError::FileTooLarge`.
+    /// Example
+    /// ```no_run
+    /// use dco3::{Dracoon, OAuth2Flow, Download, Nodes};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = Dracoon::builder()
+    ///      .with_base_url("https://dracoon.team")
+    ///      .with_client_id("client_id")
+    ///      .with_client_secret("client_secret")
+    ///      .build()
+    ///      .unwrap()
+    ///      .connect(OAuth2Flow::password_flow("username", "password"))
+    ///      .await
+    ///      .unwrap();
+    ///
+    ///   let node_id = 123u64;
+    ///   let node = client.nodes().get_node(node_id).await.unwrap();
+    ///
+    ///   // pass a custom max. size (in bytes) or None to use the default
+    ///   let bytes = client.download_bytes(&node, Some(1024 * 1024)).await.unwrap();
+    /// }
+    /// ```
+    async fn download_bytes(
+        &self,
+        node: &Node,
+        max_size: Option<u64>,
+    ) -> Result<Vec<u8>, DracoonClientError>;
+
+    /// Downloads a file to the given path (the file is created or replaced)
+    /// The content is written to a temporary file next to the target, which is flushed, synced
+    /// and renamed to the target on completion - an existing file is left untouched if the download fails.
+    /// The modification time is set from the node (if present).
+    /// Example
+    /// ```no_run
+    /// use dco3::{Dracoon, OAuth2Flow, Download, Nodes};
+    /// #[cfg(not(doctest))]
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = Dracoon::builder()
+    ///      .with_base_url("https://dracoon.team")
+    ///      .with_client_id("client_id")
+    ///      .with_client_secret("client_secret")
+    ///      .build()
+    ///      .unwrap()
+    ///      .connect(OAuth2Flow::password_flow("username", "password"))
+    ///      .await
+    ///      .unwrap();
+    ///
+    ///   let node = client.nodes().get_node(123).await.unwrap();
+    ///
+    ///   client.download_to_path(&node, "/tmp/test.txt", None, None).await.unwrap();
+    /// }
+    /// ```
+    async fn download_to_path(
+        &self,
+        node: &Node,
+        path: impl AsRef<Path> + Send,
+        mut callback: Option<DownloadProgressCallback>,
+        chunksize: Option<usize>,
+    ) -> Result<(), DracoonClientError>;
+
+    /// Generates a short-lived download url for a file (node) without downloading the content.
+    /// The url can be passed to a browser or another process.
+    /// Encrypted nodes are rejected with `DracoonClientError::EncryptedDownloadUrl` because
+    /// the url serves the ciphertext - use `download` to get the decrypted content instead.
+    /// Example
+    /// ```no_run
+    /// use dco3::{Dracoon, OAuth2Flow, Download};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = Dracoon::builder()
+    ///      .with_base_url("https://dracoon.team")
+    ///      .with_client_id("client_id")
+    ///      .with_client_secret("client_secret")
+    ///      .build()
+    ///      .unwrap()
+    ///      .connect(OAuth2Flow::password_flow("username", "password"))
+    ///      .await
+    ///      .unwrap();
+    ///
+    ///   let url = client.generate_download_url(123).await.unwrap();
+    /// }
+    /// ```
+    async fn generate_download_url(&self, node_id: u64) -> Result<String, DracoonClientError>;
+}
+
+/// This trait represents the upload functionality and provides
+/// a single method to upload a stream of bytes by passing a buffered reader.
+/// This rquires a mutable reference to the client because the upload method
+/// needs to be able to check for the secret and set it for the client if encryption is used.
+#[async_trait]
+pub trait Upload<R: AsyncRead> {
+    /// Uploads a stream (buffered reader) with given file meta info to the given parent node
+    /// # Example
+    /// ```no_run
+    /// use dco3::{Dracoon, OAuth2Flow, Upload, Nodes, nodes::{FileMeta, UploadOptions, ResolutionStrategy}};
+    /// #[cfg(not(doctest))]
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = Dracoon::builder()
+    ///      .with_base_url("https://dracoon.team")
+    ///      .with_client_id("client_id")
+    ///      .with_client_secret("client_secret")
+    ///      // if you do not pass a password, encrypted uploads will fail with an error
+    ///      .with_encryption_password("encryption_password")
+    ///      .build()
+    ///      .unwrap()
+    ///      .connect(OAuth2Flow::password_flow("username", "password"))
+    ///      .await
+    ///      .unwrap();
+    ///
     /// let file = tokio::fs::File::open("test.txt").await.unwrap();
     /// let file_meta = FileMeta::builder("test.txt", 123456)
     /// .with_timestamp_modification("2020-01-01T00:00:00.000Z".parse().unwrap())
@@ -786,4 +1849,230 @@ pub trait Upload<R: AsyncRead> {
         mut callback: Option<UploadProgressCallback>,
         chunk_size: Option<usize>,
     ) -> Result<Node, DracoonClientError>;
+
+    /// Uploads a stream (buffered reader) like `upload` and returns the uploaded node
+    /// together with the resolved upload plan (part size and part count) for diagnostics.
+    /// The requested chunk size is clamped to the max. S3 part size (5 GB) for S3 uploads.
+    /// ```no_run
+    /// use dco3::{Dracoon, OAuth2Flow, Upload, Nodes, nodes::{FileMeta, UploadOptions}};
+    /// #[cfg(not(doctest))]
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = Dracoon::builder()
+    ///      .with_base_url("https://dracoon.team")
+    ///      .with_client_id("client_id")
+    ///      .with_client_secret("client_secret")
+    ///      .build()
+    ///      .unwrap()
+    ///      .connect(OAuth2Flow::password_flow("username", "password"))
+    ///      .await
+    ///      .unwrap();
+    ///
+    /// let file = tokio::fs::File::open("test.txt").await.unwrap();
+    /// let file_meta = FileMeta::builder("test.txt", 123456).build();
+    /// let parent_node = client.nodes().get_node(123).await.unwrap();
+    /// let reader = tokio::io::BufReader::new(file);
+    /// let options = UploadOptions::builder(file_meta).build();
+    ///
+    /// let result = client.upload_with_result(&parent_node, options, reader, None, None).await.unwrap();
+    ///
+    /// println!("Uploaded node {} (part size: {} bytes)", result.node.id, result.plan.part_size);
+    /// }
+    /// ```
+    async fn upload_with_result<'r>(
+        &'r self,
+        parent_node: &Node,
+        upload_options: UploadOptions,
+        mut reader: BufReader<R>,
+        mut callback: Option<UploadProgressCallback>,
+        chunk_size: Option<usize>,
+    ) -> Result<UploadResult, DracoonClientError>;
+
+    /// Uploads a seekable stream (buffered reader) like `upload`.
+    /// If the S3 finalization of an unencrypted upload fails, the reader is rewound to the start
+    /// and the upload is retried (see `UploadOptions::with_upload_retries`).
+    /// Use this for files or in-memory cursors - non-seekable readers can only be uploaded via `upload`.
+    /// ```no_run
+    /// use dco3::{Dracoon, OAuth2Flow, Upload, Nodes, nodes::{FileMeta, UploadOptions}};
+    /// #[cfg(not(doctest))]
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = Dracoon::builder()
+    ///      .with_base_url("https://dracoon.team")
+    ///      .with_client_id("client_id")
+    ///      .with_client_secret("client_secret")
+    ///      .build()
+    ///      .unwrap()
+    ///      .connect(OAuth2Flow::password_flow("username", "password"))
+    ///      .await
+    ///      .unwrap();
+    ///
+    /// let file = tokio::fs::File::open("test.txt").await.unwrap();
+    /// let file_meta = FileMeta::builder("test.txt", 123456).build();
+    /// let parent_node = client.nodes().get_node(123).await.unwrap();
+    /// let reader = tokio::io::BufReader::new(file);
+    /// let options = UploadOptions::builder(file_meta)
+    ///               .with_upload_retries(3)
+    ///               .build();
+    ///
+    /// client.upload_seekable(&parent_node, options, reader, None, None).await.unwrap();
+    /// }
+    /// ```
+    async fn upload_seekable<'r>(
+        &'r self,
+        parent_node: &Node,
+        upload_options: UploadOptions,
+        mut reader: BufReader<R>,
+        mut callback: Option<UploadProgressCallback>,
+        chunk_size: Option<usize>,
+    ) -> Result<Node, DracoonClientError>
+    where
+        R: AsyncSeek;
+
+    /// Computes the upload plan (part count and part sizes) for a file of the given size
+    /// without starting the transfer (e.g. to render progress bars).
+    /// Consults the system info to check if direct S3 upload is used - the S3 part size is
+    /// clamped to the max. part size (5 GB) and increased if the file would exceed 10,000 parts.
+    /// The reader type is not used and needs to be specified for the call.
+    /// ```no_run
+    /// use dco3::{Dracoon, OAuth2Flow, Upload, auth::Connected};
+    /// #[cfg(not(doctest))]
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = Dracoon::builder()
+    ///      .with_base_url("https://dracoon.team")
+    ///      .with_client_id("client_id")
+    ///      .with_client_secret("client_secret")
+    ///      .build()
+    ///      .unwrap()
+    ///      .connect(OAuth2Flow::password_flow("username", "password"))
+    ///      .await
+    ///      .unwrap();
+    ///
+    /// let plan = <Dracoon<Connected> as Upload<tokio::fs::File>>::plan_upload(&client, 123456, None)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// println!("Uploading {} parts of {} bytes", plan.part_count, plan.part_size);
+    /// }
+    /// ```
+    async fn plan_upload(
+        &self,
+        file_size: u64,
+        chunk_size: Option<usize>,
+    ) -> Result<UploadPlan, DracoonClientError>;
+}
+
+/// This trait provides a method to upload a local directory tree.
+#[async_trait]
+pub trait UploadDirectory {
+    /// Uploads a local directory (including all subfolders and files) to the given parent node.
+    /// The directory itself is created as folder in the parent node - existing folders are reused,
+    /// existing files are handled with the resolution strategy of the options.
+    /// Symbolic links are skipped unless `with_follow_symlinks(true)` is set.
+    /// Returns the executed operations - in dry run mode, the planned operations are returned
+    /// without creating folders or uploading files.
+    /// ```no_run
+    /// use dco3::{Dracoon, OAuth2Flow, UploadDirectory, Nodes, nodes::{ResolutionStrategy, UploadDirectoryOptions}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = Dracoon::builder()
+    ///      .with_base_url("https://dracoon.team")
+    ///      .with_client_id("client_id")
+    ///      .with_client_secret("client_secret")
+    ///      .build()
+    ///      .unwrap()
+    ///      .connect(OAuth2Flow::password_flow("username", "password"))
+    ///      .await
+    ///      .unwrap();
+    ///
+    /// let parent_node = client.nodes().get_node(123).await.unwrap();
+    ///
+    /// // preview the operations
+    /// let options = UploadDirectoryOptions::builder()
+    ///               .with_dry_run(true)
+    ///               .build();
+    /// let planned = client.upload_directory("./docs", &parent_node, options, None).await.unwrap();
+    ///
+    /// for operation in planned {
+    ///    println!("{:?}", operation);
+    /// }
+    ///
+    /// // upload with progress callback
+    /// let options = UploadDirectoryOptions::builder()
+    ///               .with_resolution_strategy(ResolutionStrategy::AutoRename)
+    ///               .build();
+    /// client.upload_directory("./docs", &parent_node, options, Some(Box::new(|progress| {
+    ///   println!("{}: {}/{}", progress.current_file.display(), progress.current_bytes, progress.current_total);
+    ///   println!("Overall: {}/{}", progress.overall_bytes, progress.overall_total);
+    /// }))).await.unwrap();
+    /// }
+    /// ```
+    async fn upload_directory(
+        &self,
+        local_dir: impl AsRef<Path> + Send,
+        parent_node: &Node,
+        options: UploadDirectoryOptions,
+        callback: Option<DirectoryUploadProgressCallback>,
+    ) -> Result<Vec<DirectoryUploadOperation>, DracoonClientError>;
+}
+
+/// This trait represents resumable uploads (S3 only, unencrypted parent nodes).
+/// The upload state is tracked in an `UploadSession` which can be persisted (serde)
+/// and used to resume an interrupted upload - only missing parts are uploaded.
+/// The persisted session contains the upload token in plain text - store it securely.
+#[async_trait]
+pub trait ResumableUpload {
+    /// Creates an upload session (upload channel) for a file in the given parent node
+    /// # Example
+    /// ```no_run
+    /// use dco3::{Dracoon, OAuth2Flow, ResumableUpload, Nodes, nodes::{FileMeta, UploadOptions}};
+    /// #[cfg(not(doctest))]
+    /// #[tokio::main]
+    /// async fn main() {
+    ///    let client = Dracoon::builder()
+    ///      .with_base_url("https://dracoon.team")
+    ///      .with_client_id("client_id")
+    ///      .with_client_secret("client_secret")
+    ///      .build()
+    ///      .unwrap()
+    ///      .connect(OAuth2Flow::password_flow("username", "password"))
+    ///      .await
+    ///      .unwrap();
+    ///
+    /// let parent_node = client.nodes().get_node(123).await.unwrap();
+    /// let file_meta = FileMeta::builder("test.txt", 123456).build();
+    /// let options = UploadOptions::builder(file_meta).build();
+    ///
+    /// let mut session = client.create_upload_session(&parent_node, &options, None).await.unwrap();
+    ///
+    /// let file = tokio::fs::File::open("test.txt").await.unwrap();
+    /// let reader = tokio::io::BufReader::new(file);
+    ///
+    /// if let Err(err) = client.resume_upload(&mut session, reader, None, None).await {
+    ///     // session contains all completed parts - persist it to resume later
+    ///     let state = serde_json::to_string(&session).unwrap();
+    /// }
+    /// }
+    /// ```
+    async fn create_upload_session(
+        &self,
+        parent_node: &Node,
+        upload_options: &UploadOptions,
+        chunk_size: Option<usize>,
+    ) -> Result<UploadSession, DracoonClientError>;
+
+    /// Uploads all missing parts of an upload session and completes the upload.
+    /// The reader is seeked to each missing part - it must contain the full file.
+    /// Completed parts are added to the session (also if the upload fails).
+    /// Fails if the parent node was deleted (not found) or if the passed chunk size
+    /// does not match the session (use `None` to use the chunk size of the session).
+    async fn resume_upload<R: AsyncRead + AsyncSeek + Sync + Send + Unpin + 'static>(
+        &self,
+        session: &mut UploadSession,
+        reader: BufReader<R>,
+        callback: Option<UploadProgressCallback>,
+        chunk_size: Option<usize>,
+    ) -> Result<Node, DracoonClientError>;
 }
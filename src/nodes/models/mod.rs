@@ -13,6 +13,7 @@ use tracing::error;
 use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
 
@@ -22,6 +23,7 @@ use crate::{
     models::{ObjectExpiration, Range, RangedItems},
     utils::parse_body,
     utils::FromResponse,
+    utils::WithTimestampTimezone,
 };
 
 use async_trait::async_trait;
@@ -29,6 +31,7 @@ use chrono::{DateTime, Utc};
 use dco3_crypto::FileKey;
 use dco3_crypto::PublicKeyContainer;
 use reqwest::{Response, StatusCode};
+use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
 
 use super::rooms::models::NodePermissionsBuilder;
@@ -123,6 +126,18 @@ impl FileMetaBuilder {
         self
     }
 
+    /// Sets creation and modification timestamps from local file metadata
+    /// Timestamps not supported by the platform / file system are not set.
+    pub fn with_timestamps_from_metadata(mut self, metadata: &std::fs::Metadata) -> Self {
+        if let Ok(created) = metadata.created() {
+            self.timestamp_creation = Some(created.into());
+        }
+        if let Ok(modified) = metadata.modified() {
+            self.timestamp_modification = Some(modified.into());
+        }
+        self
+    }
+
     pub fn build(self) -> FileMeta {
         FileMeta {
             name: self.name,
@@ -140,6 +155,10 @@ pub struct UploadOptions {
     pub classification: Option<u8>,
     pub keep_share_links: Option<bool>,
     pub resolution_strategy: Option<ResolutionStrategy>,
+    pub upload_retries: Option<u32>,
+    pub content_hash: Option<String>,
+    pub post_upload_verification: Option<bool>,
+    pub upload_concurrency: Option<usize>,
     pub file_meta: FileMeta,
 }
 
@@ -155,6 +174,11 @@ pub struct UploadOptionsBuilder {
     classification: Option<u8>,
     keep_share_links: Option<bool>,
     resolution_strategy: Option<ResolutionStrategy>,
+    upload_retries: Option<u32>,
+    content_hash: Option<String>,
+    post_upload_verification: Option<bool>,
+    upload_concurrency: Option<usize>,
+    default_timestamps: bool,
 }
 
 impl UploadOptionsBuilder {
@@ -164,6 +188,11 @@ impl UploadOptionsBuilder {
             classification: None,
             keep_share_links: None,
             resolution_strategy: None,
+            upload_retries: None,
+            content_hash: None,
+            post_upload_verification: None,
+            upload_concurrency: None,
+            default_timestamps: false,
             file_meta,
         }
     }
@@ -188,17 +217,196 @@ impl UploadOptionsBuilder {
         self
     }
 
+    /// Retries the full upload (new upload id) up to `upload_retries` times if the S3 finalization fails.
+    /// Encrypted uploads can always be retried, unencrypted uploads require a seekable reader
+    /// passed via `Upload::upload_seekable` - otherwise `DracoonClientError::UploadNotRetryable` is returned.
+    pub fn with_upload_retries(mut self, upload_retries: u32) -> Self {
+        self.upload_retries = Some(upload_retries);
+        self
+    }
+
+    /// Sets the expected (MD5) content hash of the file - it is not sent to DRACOON, but compared with
+    /// the node hash if post-upload verification is enabled (see `with_post_upload_verification`).
+    pub fn with_content_hash(mut self, content_hash: impl Into<String>) -> Self {
+        self.content_hash = Some(content_hash.into());
+        self
+    }
+
+    /// Re-fetches the node after a completed upload and verifies its size
+    /// (and hash, if a content hash is set) - fails with `DracoonClientError::UploadVerificationFailed` on mismatch.
+    /// The hash is compared case-insensitively and skipped for encrypted nodes.
+    pub fn with_post_upload_verification(mut self, post_upload_verification: bool) -> Self {
+        self.post_upload_verification = Some(post_upload_verification);
+        self
+    }
+
+    /// Sets how many parts of an S3 upload are uploaded concurrently (default: 4, min. 1)
+    /// Each part in flight keeps one chunk in memory. Progress is still reported per uploaded
+    /// granule, so the summed bytes increase monotonically even if parts complete out of order.
+    pub fn with_upload_concurrency(mut self, upload_concurrency: usize) -> Self {
+        self.upload_concurrency = Some(upload_concurrency);
+        self
+    }
+
+    /// Sets missing creation / modification timestamps of the file meta to the current time
+    pub fn with_default_timestamps(mut self, default_timestamps: bool) -> Self {
+        self.default_timestamps = default_timestamps;
+        self
+    }
+
     pub fn build(self) -> UploadOptions {
+        let mut file_meta = self.file_meta;
+
+        if self.default_timestamps {
+            let now = Utc::now();
+            file_meta.timestamp_creation.get_or_insert(now);
+            file_meta.timestamp_modification.get_or_insert(now);
+        }
+
         UploadOptions {
             expiration: self.expiration,
             classification: self.classification,
             keep_share_links: self.keep_share_links,
             resolution_strategy: self.resolution_strategy,
-            file_meta: self.file_meta,
+            upload_retries: self.upload_retries,
+            content_hash: self.content_hash,
+            post_upload_verification: self.post_upload_verification,
+            upload_concurrency: self.upload_concurrency,
+            file_meta,
         }
     }
 }
 
+/// upload plan (part count and part sizes) for a file - see `Upload::plan_upload`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadPlan {
+    pub file_size: u64,
+    pub part_count: u32,
+    /// size of all parts except the last one
+    pub part_size: u64,
+    pub last_part_size: u64,
+    pub use_s3_storage: bool,
+}
+
+/// result of an upload - the uploaded node and the resolved upload plan (e.g. part size for diagnostics)
+#[derive(Debug, Clone)]
+pub struct UploadResult {
+    pub node: Node,
+    pub plan: UploadPlan,
+}
+
+/// options for uploading a local directory tree (applied to all folders and files)
+#[derive(Debug, Clone, Default)]
+pub struct UploadDirectoryOptions {
+    pub classification: Option<u8>,
+    pub keep_share_links: Option<bool>,
+    pub resolution_strategy: Option<ResolutionStrategy>,
+    pub upload_retries: Option<u32>,
+    pub chunk_size: Option<usize>,
+    pub follow_symlinks: bool,
+    pub dry_run: bool,
+}
+
+impl UploadDirectoryOptions {
+    pub fn builder() -> UploadDirectoryOptionsBuilder {
+        UploadDirectoryOptionsBuilder::new()
+    }
+}
+
+#[derive(Default)]
+pub struct UploadDirectoryOptionsBuilder {
+    options: UploadDirectoryOptions,
+}
+
+impl UploadDirectoryOptionsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_classification(mut self, classification: u8) -> Self {
+        self.options.classification = Some(classification);
+        self
+    }
+
+    pub fn with_keep_share_links(mut self, keep_share_links: bool) -> Self {
+        self.options.keep_share_links = Some(keep_share_links);
+        self
+    }
+
+    /// Sets the resolution strategy for files that already exist (existing folders are always reused)
+    pub fn with_resolution_strategy(mut self, resolution_strategy: ResolutionStrategy) -> Self {
+        self.options.resolution_strategy = Some(resolution_strategy);
+        self
+    }
+
+    pub fn with_upload_retries(mut self, upload_retries: u32) -> Self {
+        self.options.upload_retries = Some(upload_retries);
+        self
+    }
+
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.options.chunk_size = Some(chunk_size);
+        self
+    }
+
+    /// Follows symbolic links (skipped by default)
+    pub fn with_follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.options.follow_symlinks = follow_symlinks;
+        self
+    }
+
+    /// Only plans the operations (existing folders are still looked up) - nothing is created or uploaded
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.options.dry_run = dry_run;
+        self
+    }
+
+    pub fn build(self) -> UploadDirectoryOptions {
+        self.options
+    }
+}
+
+/// An operation (planned or executed) of a directory upload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DirectoryUploadOperation {
+    /// create a missing folder (remote path of the folder)
+    CreateFolder { remote_path: String },
+    /// upload a local file (remote path of the file)
+    UploadFile {
+        local_path: PathBuf,
+        remote_path: String,
+        size: u64,
+    },
+}
+
+/// Progress of a directory upload (current file and overall bytes)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectoryUploadProgress {
+    pub current_file: PathBuf,
+    pub current_bytes: u64,
+    pub current_total: u64,
+    pub overall_bytes: u64,
+    pub overall_total: u64,
+}
+
+/// A callback function that is called after each chunk is processed (directory upload)
+pub type DirectoryUploadProgressCallback = Box<dyn FnMut(DirectoryUploadProgress) + Send + Sync>;
+
+/// Defines how the access token is passed when downloading from a download url
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DownloadAuthMode {
+    /// no token is sent (download urls are presigned)
+    #[default]
+    Presigned,
+    /// token is sent in the `Authorization` header to download urls on the base url origin
+    /// (not sent to other origins or on cross-origin redirects)
+    Header,
+    /// token is sent as `access_token` query parameter to download urls on the base url origin
+    /// (e.g. for CDN-fronted download urls) - the token ends up in proxy and server access logs,
+    /// prefer `Header` if the download url accepts it
+    QueryParam,
+}
+
 /// A list of nodes in DRACOON - GET /nodes
 pub type NodeList = RangedItems<Node>;
 
@@ -273,6 +481,30 @@ pub struct Node {
     pub auth_parent_id: Option<u64>,
 }
 
+/// Summary of a node's counters and favorite status (e.g. for a details panel)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NodeStats {
+    pub node_id: u64,
+    pub cnt_comments: u64,
+    pub cnt_download_shares: u64,
+    pub cnt_upload_shares: u64,
+    pub cnt_deleted_versions: u64,
+    pub is_favorite: bool,
+}
+
+impl From<&Node> for NodeStats {
+    fn from(node: &Node) -> Self {
+        Self {
+            node_id: node.id,
+            cnt_comments: node.cnt_comments.unwrap_or_default(),
+            cnt_download_shares: node.cnt_download_shares.unwrap_or_default(),
+            cnt_upload_shares: node.cnt_upload_shares.unwrap_or_default(),
+            cnt_deleted_versions: node.cnt_deleted_versions.unwrap_or_default(),
+            is_favorite: node.is_favorite.unwrap_or_default(),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub enum NodeType {
     #[serde(rename = "room")]
@@ -445,6 +677,193 @@ impl FromResponse for NodeList {
     }
 }
 
+/// A comment of a node - GET /nodes/{nodeId}/comments
+#[derive(Debug, Deserialize, Clone, FromResponse)]
+#[serde(rename_all = "camelCase")]
+pub struct Comment {
+    pub id: u64,
+    pub text: String,
+    pub created_at: DateTime<Utc>,
+    pub created_by: UserInfo,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub updated_by: Option<UserInfo>,
+    pub is_changed: bool,
+    pub is_deleted: Option<bool>,
+}
+
+/// A list of node comments - GET /nodes/{nodeId}/comments
+pub type CommentList = RangedItems<Comment>;
+
+#[async_trait]
+impl FromResponse for CommentList {
+    async fn from_response(response: Response) -> Result<Self, DracoonClientError> {
+        parse_body::<Self, DracoonErrorResponse>(response).await
+    }
+}
+
+/// Request to create or update a comment - POST /nodes/{nodeId}/comments, PUT /nodes/comments/{commentId}
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentRequest {
+    text: String,
+}
+
+impl From<String> for CommentRequest {
+    fn from(text: String) -> Self {
+        Self { text }
+    }
+}
+
+/// Deleted nodes with the same name (all deleted versions) - GET /nodes/{nodeId}/deleted_nodes
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletedNodeSummary {
+    pub parent_id: u64,
+    pub parent_path: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub node_type: NodeType,
+    pub cnt_versions: u64,
+    pub first_deleted_at: DateTime<Utc>,
+    pub last_deleted_at: DateTime<Utc>,
+    pub timestamp_creation: Option<DateTime<Utc>>,
+    pub timestamp_modification: Option<DateTime<Utc>>,
+}
+
+/// A list of deleted nodes (recycle bin) - GET /nodes/{nodeId}/deleted_nodes
+pub type DeletedNodeSummaryList = RangedItems<DeletedNodeSummary>;
+
+#[async_trait]
+impl FromResponse for DeletedNodeSummaryList {
+    async fn from_response(response: Response) -> Result<Self, DracoonClientError> {
+        parse_body::<Self, DracoonErrorResponse>(response).await
+    }
+}
+
+/// A deleted version of a node - GET /nodes/{nodeId}/deleted_nodes/versions
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletedNode {
+    pub id: u64,
+    pub parent_id: u64,
+    pub parent_path: String,
+    #[serde(rename = "type")]
+    pub node_type: NodeType,
+    pub name: String,
+    pub expire_at: Option<DateTime<Utc>>,
+    pub accessed_at: Option<DateTime<Utc>>,
+    pub is_encrypted: Option<bool>,
+    pub notes: Option<String>,
+    pub size: Option<u64>,
+    pub classification: Option<u8>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub created_by: Option<UserInfo>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub updated_by: Option<UserInfo>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub deleted_by: Option<UserInfo>,
+    pub timestamp_creation: Option<DateTime<Utc>>,
+    pub timestamp_modification: Option<DateTime<Utc>>,
+}
+
+/// A list of deleted versions of a node - GET /nodes/{nodeId}/deleted_nodes/versions
+pub type DeletedNodeVersionsList = RangedItems<DeletedNode>;
+
+#[async_trait]
+impl FromResponse for DeletedNodeVersionsList {
+    async fn from_response(response: Response) -> Result<Self, DracoonClientError> {
+        parse_body::<Self, DracoonErrorResponse>(response).await
+    }
+}
+
+/// Request to restore deleted nodes - POST /nodes/deleted_nodes/actions/restore
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreDeletedNodesRequest {
+    deleted_node_ids: Vec<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resolution_strategy: Option<ResolutionStrategy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keep_share_links: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_id: Option<u64>,
+}
+
+impl RestoreDeletedNodesRequest {
+    pub fn builder(deleted_node_ids: Vec<u64>) -> RestoreDeletedNodesRequestBuilder {
+        RestoreDeletedNodesRequestBuilder {
+            deleted_node_ids,
+            resolution_strategy: None,
+            keep_share_links: None,
+            parent_id: None,
+        }
+    }
+}
+
+impl From<Vec<u64>> for RestoreDeletedNodesRequest {
+    fn from(deleted_node_ids: Vec<u64>) -> Self {
+        RestoreDeletedNodesRequest::builder(deleted_node_ids).build()
+    }
+}
+
+pub struct RestoreDeletedNodesRequestBuilder {
+    deleted_node_ids: Vec<u64>,
+    resolution_strategy: Option<ResolutionStrategy>,
+    keep_share_links: Option<bool>,
+    parent_id: Option<u64>,
+}
+
+impl RestoreDeletedNodesRequestBuilder {
+    /// Defines how name conflicts with existing nodes are resolved (default: autorename)
+    pub fn with_resolution_strategy(mut self, resolution_strategy: ResolutionStrategy) -> Self {
+        self.resolution_strategy = Some(resolution_strategy);
+        self
+    }
+
+    pub fn with_keep_share_links(mut self, keep_share_links: bool) -> Self {
+        self.keep_share_links = Some(keep_share_links);
+        self
+    }
+
+    /// Restores the nodes into another parent (default: the original parent)
+    pub fn with_parent_id(mut self, parent_id: u64) -> Self {
+        self.parent_id = Some(parent_id);
+        self
+    }
+
+    pub fn build(self) -> RestoreDeletedNodesRequest {
+        RestoreDeletedNodesRequest {
+            deleted_node_ids: self.deleted_node_ids,
+            resolution_strategy: self.resolution_strategy,
+            keep_share_links: self.keep_share_links,
+            parent_id: self.parent_id,
+        }
+    }
+}
+
+/// A pending (in-progress) upload - GET /nodes/files/uploads
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingUpload {
+    pub upload_id: String,
+    pub parent_id: u64,
+    pub parent_path: Option<String>,
+    pub file_name: String,
+    pub user_id: Option<u64>,
+    pub created_at: DateTime<Utc>,
+    pub status: Option<String>,
+}
+
+/// A list of pending uploads - GET /nodes/files/uploads
+pub type PendingUploadList = RangedItems<PendingUpload>;
+
+#[async_trait]
+impl FromResponse for PendingUploadList {
+    async fn from_response(response: Response) -> Result<Self, DracoonClientError> {
+        parse_body::<Self, DracoonErrorResponse>(response).await
+    }
+}
+
 /// Response for download url of a node - POST /nodes/files/{nodeId}/download
 #[derive(Serialize, Deserialize, Debug, FromResponse)]
 #[serde(rename_all = "camelCase")]
@@ -453,7 +872,7 @@ pub struct DownloadUrlResponse {
 }
 
 /// Error response for S3 requests (XML)
-#[derive(Debug, Deserialize, PartialEq, Clone)]
+#[derive(Debug, Deserialize, PartialEq, Clone, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct S3XmlError {
     code: Option<String>,
@@ -463,6 +882,21 @@ pub struct S3XmlError {
     argument_name: Option<String>,
 }
 
+impl S3XmlError {
+    /// S3 error code (e.g. `SlowDown`)
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+}
+
 /// Error response for S3 requests
 #[derive(Debug, PartialEq, Clone)]
 pub struct S3ErrorResponse {
@@ -560,6 +994,15 @@ pub struct CreateFileUploadRequest {
     timestamp_modification: Option<String>,
 }
 
+impl WithTimestampTimezone for CreateFileUploadRequest {
+    fn timestamps_mut(&mut self) -> [&mut Option<String>; 2] {
+        [
+            &mut self.timestamp_creation,
+            &mut self.timestamp_modification,
+        ]
+    }
+}
+
 impl CreateFileUploadRequest {
     pub fn builder(parent_id: u64, name: String) -> CreateFileUploadRequestBuilder {
         CreateFileUploadRequestBuilder {
@@ -579,8 +1022,8 @@ impl CreateFileUploadRequest {
         upload_options: &UploadOptions,
         is_s3_upload: Option<bool>,
     ) -> Self {
-        let req = Self::builder(parent_id, upload_options.file_meta.name.clone())
-            .with_size(upload_options.file_meta.size)
+        let mut req = Self::builder(parent_id, upload_options.file_meta.name.clone())
+            .with_file_meta(&upload_options.file_meta)
             .with_classification(upload_options.classification.unwrap_or(1))
             .with_expiration(
                 upload_options
@@ -589,18 +1032,6 @@ impl CreateFileUploadRequest {
                     .unwrap_or_default()
                     .clone(),
             );
-        let req = if let Some(timestamp_creation) = upload_options.file_meta.timestamp_creation {
-            req.with_timestamp_creation(timestamp_creation)
-        } else {
-            req
-        };
-
-        let mut req =
-            if let Some(timestamp_modification) = upload_options.file_meta.timestamp_modification {
-                req.with_timestamp_modification(timestamp_modification)
-            } else {
-                req
-            };
 
         req.direct_s3_upload = is_s3_upload;
 
@@ -643,6 +1074,18 @@ impl CreateFileUploadRequestBuilder {
         self
     }
 
+    /// Sets size and (if set) creation / modification timestamps from the file meta
+    pub fn with_file_meta(mut self, file_meta: &FileMeta) -> Self {
+        self.size = Some(file_meta.size);
+        if let Some(timestamp_creation) = file_meta.timestamp_creation {
+            self = self.with_timestamp_creation(timestamp_creation);
+        }
+        if let Some(timestamp_modification) = file_meta.timestamp_modification {
+            self = self.with_timestamp_modification(timestamp_modification);
+        }
+        self
+    }
+
     pub fn with_direct_s3_upload(mut self, direct_s3_upload: bool) -> Self {
         if !direct_s3_upload {
             self.direct_s3_upload = None;
@@ -812,7 +1255,7 @@ impl CompleteUploadRequestBuilder {
     }
 }
 
-#[derive(Debug, Serialize, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub enum ResolutionStrategy {
     #[default]
     #[serde(rename = "autorename")]
@@ -823,7 +1266,7 @@ pub enum ResolutionStrategy {
     Fail,
 }
 
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct S3FileUploadPart {
     part_number: u32,
@@ -837,6 +1280,77 @@ impl S3FileUploadPart {
             part_etag,
         }
     }
+
+    pub fn part_number(&self) -> u32 {
+        self.part_number
+    }
+
+    pub fn part_etag(&self) -> &str {
+        &self.part_etag
+    }
+}
+
+/// State of a resumable (unencrypted) S3 upload
+/// Can be persisted (serde) and passed to `resume_upload` to upload only missing parts
+/// **Warning**: the upload token is serialized as plain text - store a persisted session securely.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadSession {
+    pub parent_id: u64,
+    pub upload_id: String,
+    pub upload_url: String,
+    /// upload token (redacted in debug output, but serialized with the session)
+    #[serde(serialize_with = "serialize_secret")]
+    pub token: SecretString,
+    pub file_size: u64,
+    pub chunk_size: usize,
+    pub resolution_strategy: Option<ResolutionStrategy>,
+    pub keep_share_links: Option<bool>,
+    pub parts: Vec<S3FileUploadPart>,
+}
+
+impl UploadSession {
+    pub fn new(
+        parent_id: u64,
+        upload_channel: CreateFileUploadResponse,
+        upload_options: &UploadOptions,
+        chunk_size: usize,
+    ) -> Self {
+        Self {
+            parent_id,
+            upload_id: upload_channel.upload_id,
+            upload_url: upload_channel.upload_url,
+            token: SecretString::from(upload_channel.token),
+            file_size: upload_options.file_meta.size,
+            chunk_size,
+            resolution_strategy: upload_options.resolution_strategy.clone(),
+            keep_share_links: upload_options.keep_share_links,
+            parts: Vec::new(),
+        }
+    }
+
+    /// returns true if the part with the given number was already uploaded
+    pub fn is_part_completed(&self, part_number: u32) -> bool {
+        self.parts
+            .iter()
+            .any(|part| part.part_number == part_number)
+    }
+
+    pub(crate) fn upload_channel(&self) -> CreateFileUploadResponse {
+        CreateFileUploadResponse {
+            upload_url: self.upload_url.clone(),
+            upload_id: self.upload_id.clone(),
+            token: self.token.expose_secret().to_string(),
+        }
+    }
+}
+
+/// helper to serialize a secret (e.g. to persist an upload session)
+fn serialize_secret<S: serde::Serializer>(
+    secret: &SecretString,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(secret.expose_secret())
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -881,6 +1395,63 @@ impl From<u64> for TransferNode {
     }
 }
 
+impl TransferNodesRequest {
+    /// Returns the ids of all nodes to transfer
+    pub fn node_ids(&self) -> Vec<u64> {
+        self.items.iter().map(|item| item.id).collect()
+    }
+
+    /// Splits the request into one request per node (same resolution strategy and share link handling)
+    pub(crate) fn split_items(&self) -> Vec<(u64, TransferNodesRequest)> {
+        self.items
+            .iter()
+            .map(|item| {
+                let req = TransferNodesRequest {
+                    items: vec![item.clone()],
+                    resolution_strategy: self.resolution_strategy.clone(),
+                    keep_share_links: self.keep_share_links,
+                };
+                (item.id, req)
+            })
+            .collect()
+    }
+}
+
+/// Result of copying nodes with file key distribution (see `copy_nodes_with_keys`)
+#[derive(Debug)]
+pub struct CopyWithKeysResult {
+    /// the target parent node
+    pub target: Node,
+    /// the copied nodes in the target parent
+    pub nodes: Vec<Node>,
+    /// the amount of distributed file keys - or the first distribution error (the nodes are still copied)
+    pub key_distribution: Result<u64, DracoonClientError>,
+}
+
+/// Result of transferring a single node (see `copy_nodes_detailed`)
+#[derive(Debug)]
+pub struct TransferResult {
+    pub node_id: u64,
+    /// the target parent node on success
+    pub result: Result<Node, DracoonClientError>,
+}
+
+impl TransferResult {
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// Returns true if the node already exists at the target
+    pub fn is_conflict(&self) -> bool {
+        self.result
+            .as_ref()
+            .is_err_and(DracoonClientError::is_node_conflict)
+    }
+}
+
+/// A callback function that is called after each node is transferred (transferred, total)
+pub type TransferProgressCallback = Box<dyn FnMut(u64, u64) + Send + Sync>;
+
 impl From<Vec<u64>> for TransferNodesRequest {
     fn from(node_ids: Vec<u64>) -> Self {
         Self {
@@ -933,6 +1504,26 @@ impl TransferNodesRequest {
     }
 }
 
+impl TransferNodesRequestBuilder {
+    pub fn with_resolution_strategy(mut self, resolution_strategy: ResolutionStrategy) -> Self {
+        self.resolution_strategy = Some(resolution_strategy);
+        self
+    }
+
+    pub fn with_keep_share_links(mut self, keep_share_links: bool) -> Self {
+        self.keep_share_links = Some(keep_share_links);
+        self
+    }
+
+    pub fn build(self) -> TransferNodesRequest {
+        TransferNodesRequest {
+            items: self.items,
+            resolution_strategy: self.resolution_strategy,
+            keep_share_links: self.keep_share_links,
+        }
+    }
+}
+
 pub struct TransferNodeBuilder {
     id: u64,
     name: Option<String>,
@@ -990,15 +1581,64 @@ pub struct CreateFolderRequest {
     classification: Option<u8>,
 }
 
+impl WithTimestampTimezone for CreateFolderRequest {
+    fn timestamps_mut(&mut self) -> [&mut Option<String>; 2] {
+        [
+            &mut self.timestamp_creation,
+            &mut self.timestamp_modification,
+        ]
+    }
+}
+
 pub struct CreateFolderRequestBuilder {
     name: String,
     parent_id: u64,
     notes: Option<String>,
-    timestamp_creation: Option<String>,
-    timestamp_modification: Option<String>,
+    timestamp_creation: Option<NodeTimestamp>,
+    timestamp_modification: Option<NodeTimestamp>,
     classification: Option<u8>,
 }
 
+/// Timestamp of a node passed to a request - either typed or as (RFC 3339) string
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeTimestamp {
+    DateTime(DateTime<Utc>),
+    Raw(String),
+}
+
+impl NodeTimestamp {
+    /// Formats the timestamp as RFC 3339 string - raw strings are validated
+    pub(crate) fn to_rfc3339(&self) -> Result<String, DracoonClientError> {
+        match self {
+            NodeTimestamp::DateTime(timestamp) => Ok(timestamp.to_rfc3339()),
+            NodeTimestamp::Raw(timestamp) => DateTime::parse_from_rfc3339(timestamp)
+                .map(|_| timestamp.clone())
+                .map_err(|_| {
+                    error!("Invalid timestamp (RFC 3339 expected): {}", timestamp);
+                    DracoonClientError::InvalidTimestamp(timestamp.clone())
+                }),
+        }
+    }
+}
+
+impl From<DateTime<Utc>> for NodeTimestamp {
+    fn from(value: DateTime<Utc>) -> Self {
+        NodeTimestamp::DateTime(value)
+    }
+}
+
+impl From<String> for NodeTimestamp {
+    fn from(value: String) -> Self {
+        NodeTimestamp::Raw(value)
+    }
+}
+
+impl From<&str> for NodeTimestamp {
+    fn from(value: &str) -> Self {
+        NodeTimestamp::Raw(value.to_string())
+    }
+}
+
 impl CreateFolderRequest {
     pub fn builder(name: impl Into<String>, parent_id: u64) -> CreateFolderRequestBuilder {
         CreateFolderRequestBuilder {
@@ -1018,14 +1658,16 @@ impl CreateFolderRequestBuilder {
         self
     }
 
-    pub fn with_timestamp_creation(mut self, timestamp_creation: impl Into<String>) -> Self {
+    /// Accepts a `DateTime<Utc>` or a RFC 3339 string (validated on `build`)
+    pub fn with_timestamp_creation(mut self, timestamp_creation: impl Into<NodeTimestamp>) -> Self {
         self.timestamp_creation = Some(timestamp_creation.into());
         self
     }
 
+    /// Accepts a `DateTime<Utc>` or a RFC 3339 string (validated on `build`)
     pub fn with_timestamp_modification(
         mut self,
-        timestamp_modification: impl Into<String>,
+        timestamp_modification: impl Into<NodeTimestamp>,
     ) -> Self {
         self.timestamp_modification = Some(timestamp_modification.into());
         self
@@ -1036,15 +1678,23 @@ impl CreateFolderRequestBuilder {
         self
     }
 
-    pub fn build(self) -> CreateFolderRequest {
-        CreateFolderRequest {
+    /// Builds the request - fails with `DracoonClientError::InvalidTimestamp` if a timestamp
+    /// string is not a valid RFC 3339 timestamp
+    pub fn build(self) -> Result<CreateFolderRequest, DracoonClientError> {
+        Ok(CreateFolderRequest {
             name: self.name,
             parent_id: self.parent_id,
             notes: self.notes,
-            timestamp_creation: self.timestamp_creation,
-            timestamp_modification: self.timestamp_modification,
+            timestamp_creation: self
+                .timestamp_creation
+                .map(|timestamp| timestamp.to_rfc3339())
+                .transpose()?,
+            timestamp_modification: self
+                .timestamp_modification
+                .map(|timestamp| timestamp.to_rfc3339())
+                .transpose()?,
             classification: self.classification,
-        }
+        })
     }
 }
 
@@ -1063,6 +1713,15 @@ pub struct UpdateFolderRequest {
     classification: Option<u8>,
 }
 
+impl WithTimestampTimezone for UpdateFolderRequest {
+    fn timestamps_mut(&mut self) -> [&mut Option<String>; 2] {
+        [
+            &mut self.timestamp_creation,
+            &mut self.timestamp_modification,
+        ]
+    }
+}
+
 pub struct UpdateFolderRequestBuilder {
     name: Option<String>,
     notes: Option<String>,
@@ -1173,6 +1832,10 @@ impl UserFileKeySetBatchRequest {
         self.items.is_empty()
     }
 
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
     pub fn try_new_from_missing_keys(
         missing_keys: MissingKeysResponse,
         keypair: &PlainUserKeyPairContainer,
@@ -1,14 +1,28 @@
 #![allow(clippy::module_inception)]
 
+use std::{
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    time::Duration,
+};
+
+use async_stream::try_stream;
 use async_trait::async_trait;
+use chrono::format::{Item, StrftimeItems};
+use futures_util::{
+    stream::{self, BoxStream},
+    StreamExt, TryStreamExt,
+};
 use reqwest::header;
+use tokio::sync::mpsc;
 use tracing::{debug, error};
 
 use crate::{
     client::{errors::DracoonClientError, Connected},
     constants::{
-        DRACOON_API_PREFIX, FILES_BASE, FILES_KEYS, MISSING_FILE_KEYS, NODES_BASE, NODES_COPY,
-        NODES_MOVE, NODES_SEARCH,
+        DRACOON_API_PREFIX, FILES_BASE, FILES_KEYS, MISSING_FILE_KEYS,
+        MISSING_KEYS_MAX_STABLE_ITERATIONS, NODES_BASE, NODES_COPY, NODES_FAVORITE,
+        NODES_LOOKUP_CONCURRENCY, NODES_MOVE, NODES_PAGINATION_CONCURRENCY, NODES_SEARCH,
+        NODES_TRANSFER_CONCURRENCY, NODE_CLASSIFICATIONS, POLLING_START_DELAY,
     },
     models::ListAllParams,
     utils::FromResponse,
@@ -16,8 +30,13 @@ use crate::{
 };
 
 use super::{
-    models::{DeleteNodesRequest, Node, NodeList, TransferNodesRequest},
-    MissingFileKeys, MissingKeysResponse, Nodes, NodesEndpoint, UserFileKeySetBatchRequest,
+    models::{
+        CopyWithKeysResult, CreateFolderRequest, DeleteNodesRequest, Node, NodeList, NodeStats,
+        NodeType, NodesFilter, NodesSearchFilter, TransferNodesRequest, TransferProgressCallback,
+        TransferResult,
+    },
+    Folders, MissingFileKeys, MissingKeysResponse, Nodes, NodesEndpoint,
+    UserFileKeySetBatchRequest,
 };
 
 #[async_trait]
@@ -29,36 +48,97 @@ impl Nodes for NodesEndpoint<Connected> {
         params: Option<ListAllParams>,
     ) -> Result<NodeList, DracoonClientError> {
         let params = params.unwrap_or_default();
-        let url_part = format!("/{DRACOON_API_PREFIX}/{NODES_BASE}");
 
-        let mut api_url = self.client().build_api_url(&url_part);
+        self.get_nodes_page(parent_id, room_manager, &params, params.offset)
+            .await
+    }
 
-        let filters = params.filter_to_string();
-        let sorts = params.sort_to_string();
+    async fn get_all_nodes(
+        &self,
+        parent_id: Option<u64>,
+        room_manager: Option<bool>,
+        params: Option<ListAllParams>,
+    ) -> Result<NodeList, DracoonClientError> {
+        let params = params.unwrap_or_default();
+        let start = params.offset.unwrap_or(0);
 
-        api_url
-            .query_pairs_mut()
-            .extend_pairs(params.limit.map(|v| ("limit", v.to_string())))
-            .extend_pairs(params.offset.map(|v| ("offset", v.to_string())))
-            .extend_pairs(params.sort.map(|_| ("sort", sorts)))
-            .extend_pairs(params.filter.map(|_| ("filter", filters)))
-            .extend_pairs(room_manager.map(|v| ("room_manager", v.to_string())))
-            .extend_pairs(parent_id.map(|v| ("parent_id", v.to_string())))
-            .finish();
+        let mut nodes = self
+            .get_nodes_page(parent_id, room_manager, &params, Some(start))
+            .await?;
 
-        let response = self
-            .client()
-            .http
-            .get(api_url)
-            .header(
-                header::AUTHORIZATION,
-                self.client().get_auth_header().await?,
-            )
-            .header(header::CONTENT_TYPE, "application/json")
-            .send()
+        let page_size = nodes.items.len() as u64;
+
+        if page_size == 0 {
+            return Ok(nodes);
+        }
+
+        let offsets = (start + page_size..nodes.range.total).step_by(page_size as usize);
+
+        // pages are returned in order - the first failing request fails the whole listing
+        let pages = stream::iter(offsets)
+            .map(|offset| self.get_nodes_page(parent_id, room_manager, &params, Some(offset)))
+            .buffered(NODES_PAGINATION_CONCURRENCY)
+            .try_collect::<Vec<_>>()
             .await?;
 
-        NodeList::from_response(response).await
+        for page in pages {
+            nodes.items.extend(page.items);
+        }
+
+        nodes.range.limit = nodes.items.len() as u64;
+
+        Ok(nodes)
+    }
+
+    fn get_nodes_stream(
+        &self,
+        parent_id: Option<u64>,
+        room_manager: Option<bool>,
+        params: Option<ListAllParams>,
+    ) -> BoxStream<'_, Result<Node, DracoonClientError>> {
+        Box::pin(try_stream! {
+            let params = params.unwrap_or_default();
+            let mut offset = params.offset.unwrap_or(0);
+
+            loop {
+                let nodes = self
+                    .get_nodes_page(parent_id, room_manager, &params, Some(offset))
+                    .await?;
+                let total = nodes.range.total;
+                let is_empty = nodes.items.is_empty();
+                offset += nodes.items.len() as u64;
+
+                for node in nodes.items {
+                    yield node;
+                }
+
+                if is_empty || offset >= total {
+                    break;
+                }
+            }
+        })
+    }
+
+    async fn get_nodes_to_channel(
+        &self,
+        parent_id: Option<u64>,
+        room_manager: Option<bool>,
+        params: Option<ListAllParams>,
+        tx: mpsc::Sender<Node>,
+    ) -> Result<u64, DracoonClientError> {
+        let mut nodes = self.get_nodes_stream(parent_id, room_manager, params);
+        let mut sent = 0;
+
+        // dropping the stream stops fetching further pages
+        while let Some(node) = nodes.try_next().await? {
+            if tx.send(node).await.is_err() {
+                debug!("Node channel closed after {} nodes", sent);
+                break;
+            }
+            sent += 1;
+        }
+
+        Ok(sent)
     }
 
     async fn get_node_from_path(&self, path: &str) -> Result<Option<Node>, DracoonClientError> {
@@ -121,6 +201,154 @@ impl Nodes for NodesEndpoint<Connected> {
         Node::from_response(response).await
     }
 
+    async fn wait_for_node(
+        &self,
+        node_id: u64,
+        timeout: Duration,
+    ) -> Result<Node, DracoonClientError> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut sleep_duration = Duration::from_millis(POLLING_START_DELAY);
+
+        loop {
+            match self.get_node(node_id).await {
+                Ok(node) => return Ok(node),
+                Err(err) if err.is_not_found() => {
+                    debug!("Node {} not available yet", node_id);
+                }
+                Err(err) => return Err(err),
+            }
+
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                error!("Node {} not available after {:?}", node_id, timeout);
+                return Err(DracoonClientError::NodeNotAvailable { node_id, timeout });
+            }
+
+            // never sleep past the deadline - poll a last time instead
+            tokio::time::sleep(sleep_duration.min(deadline - now)).await;
+            sleep_duration *= 2;
+        }
+    }
+
+    async fn get_node_path(&self, node_id: u64) -> Result<String, DracoonClientError> {
+        let node = self.get_node(node_id).await?;
+
+        Ok(build_node_path(&node))
+    }
+
+    async fn get_node_ancestors(&self, node_id: u64) -> Result<Vec<Node>, DracoonClientError> {
+        let mut node = self.get_node(node_id).await?;
+        let mut ancestors = Vec::new();
+
+        while let Some(parent_id) = node.parent_id {
+            let parent = self.get_node(parent_id).await.map_err(|err| {
+                error!(
+                    "Error getting parent {} of node {}: {}",
+                    parent_id, node.id, err
+                );
+                err
+            })?;
+            ancestors.push(node);
+            node = parent;
+        }
+
+        ancestors.push(node);
+        ancestors.reverse();
+
+        Ok(ancestors)
+    }
+
+    async fn get_nodes_by_ids(&self, ids: &[u64]) -> Result<Vec<Node>, DracoonClientError> {
+        let results = stream::iter(ids.iter().copied())
+            .map(|node_id| async move { (node_id, self.get_node(node_id).await) })
+            .buffer_unordered(NODES_LOOKUP_CONCURRENCY)
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut nodes = Vec::with_capacity(results.len());
+
+        for (node_id, result) in results {
+            match result {
+                Ok(node) => nodes.push(node),
+                Err(err) if err.is_not_found() => debug!("Node {} not found", node_id),
+                Err(err) => {
+                    error!("Error getting node {}: {}", node_id, err);
+                    return Err(err);
+                }
+            }
+        }
+
+        Ok(nodes)
+    }
+
+    async fn get_node_stats(&self, node_id: u64) -> Result<NodeStats, DracoonClientError> {
+        let node = self.get_node(node_id).await?;
+
+        Ok(NodeStats::from(&node))
+    }
+
+    async fn classification_breakdown(
+        &self,
+        room_id: u64,
+    ) -> Result<HashMap<u8, u64>, DracoonClientError> {
+        let mut breakdown = HashMap::new();
+
+        for classification in NODE_CLASSIFICATIONS {
+            // only the total is needed - the range contains the count of all matching files
+            let params = ListAllParams::builder()
+                .with_filter(NodesSearchFilter::is_file())
+                .with_filter(NodesSearchFilter::classification_equals(classification))
+                .with_limit(1)
+                .build();
+
+            let files = self
+                .search_nodes("*", Some(room_id), Some(-1), Some(params))
+                .await?;
+
+            breakdown.insert(classification, files.range.total);
+        }
+
+        Ok(breakdown)
+    }
+
+    async fn get_recursive_size(
+        &self,
+        node_id: u64,
+        max_depth: Option<i8>,
+    ) -> Result<u64, DracoonClientError> {
+        let depth_level = max_depth.unwrap_or(-1);
+        let mut size = 0;
+        let mut offset = 0;
+
+        loop {
+            let params = ListAllParams::builder()
+                .with_filter(NodesSearchFilter::is_file())
+                .with_offset(offset)
+                .build();
+
+            let files = self
+                .search_nodes("*", Some(node_id), Some(depth_level), Some(params))
+                .await?;
+
+            if files.items.is_empty() {
+                break;
+            }
+
+            offset += files.items.len() as u64;
+            size += files
+                .items
+                .iter()
+                .map(|file| file.size.unwrap_or(0))
+                .sum::<u64>();
+
+            if offset >= files.range.total {
+                break;
+            }
+        }
+
+        Ok(size)
+    }
+
     async fn search_nodes(
         &self,
         search_string: &str,
@@ -220,6 +448,9 @@ impl Nodes for NodesEndpoint<Connected> {
         req: TransferNodesRequest,
         target_parent_id: u64,
     ) -> Result<Node, DracoonClientError> {
+        self.check_encryption_boundary(&req, target_parent_id)
+            .await?;
+
         let url_part =
             format!("/{DRACOON_API_PREFIX}/{NODES_BASE}/{target_parent_id}/{NODES_MOVE}");
 
@@ -266,6 +497,337 @@ impl Nodes for NodesEndpoint<Connected> {
 
         Node::from_response(response).await
     }
+
+    async fn copy_nodes_detailed(
+        &self,
+        req: TransferNodesRequest,
+        target_parent_id: u64,
+        mut callback: Option<TransferProgressCallback>,
+    ) -> Vec<TransferResult> {
+        let items = req.split_items();
+        let total = items.len() as u64;
+
+        let mut transfers = stream::iter(items)
+            .map(|(node_id, req)| async move {
+                let result = self
+                    .copy_nodes(req, target_parent_id)
+                    .await
+                    .map_err(map_node_conflict);
+                if let Err(err) = &result {
+                    error!(
+                        "Error copying node {} to {}: {}",
+                        node_id, target_parent_id, err
+                    );
+                }
+                TransferResult { node_id, result }
+            })
+            .buffered(NODES_TRANSFER_CONCURRENCY);
+
+        let mut results = Vec::with_capacity(total as usize);
+
+        while let Some(result) = transfers.next().await {
+            results.push(result);
+            if let Some(cb) = callback.as_mut() {
+                cb(results.len() as u64, total);
+            }
+        }
+
+        results
+    }
+
+    async fn mark_favorite(&self, node_id: u64) -> Result<(), DracoonClientError> {
+        let url_part = format!("/{DRACOON_API_PREFIX}/{NODES_BASE}/{node_id}/{NODES_FAVORITE}");
+
+        let api_url = self.client().build_api_url(&url_part);
+
+        let response = self
+            .client()
+            .http
+            .post(api_url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .header(header::CONTENT_TYPE, "application/json")
+            .send()
+            .await?;
+
+        if response.status().is_server_error() || response.status().is_client_error() {
+            return Err(DracoonClientError::from_response(response)
+                .await
+                .expect("Could not parse error response"));
+        }
+
+        Ok(())
+    }
+
+    async fn unmark_favorite(&self, node_id: u64) -> Result<(), DracoonClientError> {
+        let url_part = format!("/{DRACOON_API_PREFIX}/{NODES_BASE}/{node_id}/{NODES_FAVORITE}");
+
+        let api_url = self.client().build_api_url(&url_part);
+
+        let response = self
+            .client()
+            .http
+            .delete(api_url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .header(header::CONTENT_TYPE, "application/json")
+            .send()
+            .await?;
+
+        if response.status().is_server_error() || response.status().is_client_error() {
+            return Err(DracoonClientError::from_response(response)
+                .await
+                .expect("Could not parse error response"));
+        }
+
+        Ok(())
+    }
+
+    async fn get_favorites(
+        &self,
+        params: Option<ListAllParams>,
+    ) -> Result<NodeList, DracoonClientError> {
+        let mut params = params.unwrap_or_default();
+
+        let mut filters = params.filter.take().unwrap_or_default();
+        filters.push(NodesSearchFilter::is_favorite(true).into());
+        params.filter = Some(filters);
+
+        self.search_nodes("*", None, Some(-1), Some(params)).await
+    }
+
+    async fn organize_by_date(
+        &self,
+        source_room_id: u64,
+        target_room_id: u64,
+        pattern: &str,
+    ) -> Result<Vec<Node>, DracoonClientError> {
+        if pattern.is_empty() || StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error))
+        {
+            error!("Invalid date pattern: {}", pattern);
+            return Err(DracoonClientError::InvalidDatePattern(pattern.to_string()));
+        }
+
+        let params = ListAllParams::builder()
+            .with_filter(NodesFilter::is_file())
+            .build();
+
+        let files = self
+            .get_all_nodes(Some(source_room_id), None, Some(params))
+            .await?;
+
+        // files grouped by formatted folder path (sorted to create folders in a stable order)
+        let mut groups: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+
+        for file in files.items {
+            let Some(timestamp) = file.timestamp_creation.or(file.created_at) else {
+                debug!("Skipping file {} without creation timestamp", file.id);
+                continue;
+            };
+
+            groups
+                .entry(timestamp.format(pattern).to_string())
+                .or_default()
+                .push(file.id);
+        }
+
+        let mut folder_ids: HashMap<String, u64> = HashMap::new();
+        let mut folders = Vec::with_capacity(groups.len());
+
+        for (folder_path, file_ids) in groups {
+            let mut parent_id = target_room_id;
+            let mut current_path = String::new();
+
+            // a pattern like "%Y/%m" results in nested folders
+            for name in folder_path.split('/').filter(|name| !name.is_empty()) {
+                current_path.push('/');
+                current_path.push_str(name);
+
+                parent_id = match folder_ids.get(&current_path) {
+                    Some(folder_id) => *folder_id,
+                    None => {
+                        let folder = match self.find_folder(parent_id, name).await? {
+                            Some(folder) => folder,
+                            None => {
+                                debug!("Creating folder {}", current_path);
+                                let req = CreateFolderRequest::builder(name, parent_id).build()?;
+                                self.create_folder(req).await?
+                            }
+                        };
+
+                        folder_ids.insert(current_path.clone(), folder.id);
+                        folder.id
+                    }
+                };
+            }
+
+            folders.push(self.move_nodes(file_ids.into(), parent_id).await?);
+        }
+
+        Ok(folders)
+    }
+
+    async fn list_all_paths(
+        &self,
+        root_id: Option<u64>,
+        include_files: bool,
+    ) -> Result<Vec<(u64, String)>, DracoonClientError> {
+        let root_path = match root_id {
+            Some(root_id) => self.get_node_path(root_id).await?,
+            None => String::new(),
+        };
+
+        let mut paths = Vec::new();
+        // rooms and folders to list (breadth first)
+        let mut pending = VecDeque::from([(root_id, root_path)]);
+
+        while let Some((parent_id, parent_path)) = pending.pop_front() {
+            let params = (!include_files).then(|| {
+                ListAllParams::builder()
+                    .with_filter(NodesFilter::is_types(vec![
+                        NodeType::Room,
+                        NodeType::Folder,
+                    ]))
+                    .build()
+            });
+
+            let mut nodes = self.get_nodes_stream(parent_id, None, params);
+
+            while let Some(node) = nodes.try_next().await? {
+                let path = format!("{}/{}", parent_path.trim_end_matches('/'), node.name);
+
+                if node.node_type != NodeType::File {
+                    pending.push_back((Some(node.id), path.clone()));
+                }
+
+                paths.push((node.id, path));
+            }
+        }
+
+        debug!("Listed {} paths", paths.len());
+
+        Ok(paths)
+    }
+}
+
+/// helper to detect a node that already exists at the target of a transfer
+/// maps 409 Conflict to `DracoonClientError::NodeConflict`
+pub(crate) fn map_node_conflict(err: DracoonClientError) -> DracoonClientError {
+    match err {
+        DracoonClientError::Http(response) if response.is_conflict() => {
+            DracoonClientError::NodeConflict(response)
+        }
+        err => err,
+    }
+}
+
+impl NodesEndpoint<Connected> {
+    /// Looks up a folder by name in the given parent node
+    pub(crate) async fn find_folder(
+        &self,
+        parent_id: u64,
+        name: &str,
+    ) -> Result<Option<Node>, DracoonClientError> {
+        let params = ListAllParams::builder()
+            .with_filter(NodesFilter::name_equals(name))
+            .with_filter(NodesFilter::is_folder())
+            .build();
+
+        let nodes = self.get_nodes(Some(parent_id), None, Some(params)).await?;
+
+        Ok(nodes.items.into_iter().find(|node| node.name == name))
+    }
+
+    /// Fetches a single page of nodes - the passed offset overrides the offset of the params
+    async fn get_nodes_page(
+        &self,
+        parent_id: Option<u64>,
+        room_manager: Option<bool>,
+        params: &ListAllParams,
+        offset: Option<u64>,
+    ) -> Result<NodeList, DracoonClientError> {
+        let url_part = format!("/{DRACOON_API_PREFIX}/{NODES_BASE}");
+
+        let mut api_url = self.client().build_api_url(&url_part);
+
+        let filters = params.filter_to_string();
+        let sorts = params.sort_to_string();
+
+        api_url
+            .query_pairs_mut()
+            .extend_pairs(params.limit.map(|v| ("limit", v.to_string())))
+            .extend_pairs(offset.map(|v| ("offset", v.to_string())))
+            .extend_pairs(params.sort.as_ref().map(|_| ("sort", sorts)))
+            .extend_pairs(params.filter.as_ref().map(|_| ("filter", filters)))
+            .extend_pairs(room_manager.map(|v| ("room_manager", v.to_string())))
+            .extend_pairs(parent_id.map(|v| ("parent_id", v.to_string())))
+            .finish();
+
+        let response = self
+            .client()
+            .http
+            .get(api_url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .header(header::CONTENT_TYPE, "application/json")
+            .send()
+            .await?;
+
+        NodeList::from_response(response).await
+    }
+
+    /// Checks that all nodes to transfer share the encryption state of the target parent.
+    /// Moving nodes between encrypted and unencrypted parents requires key handling
+    /// which is not supported by the API.
+    /// The source nodes are fetched concurrently (capped by `NODES_LOOKUP_CONCURRENCY`),
+    /// duplicate node ids are only fetched once.
+    async fn check_encryption_boundary(
+        &self,
+        req: &TransferNodesRequest,
+        target_parent_id: u64,
+    ) -> Result<(), DracoonClientError> {
+        let target_encrypted = self
+            .get_node(target_parent_id)
+            .await?
+            .is_encrypted
+            .unwrap_or(false);
+
+        let mut seen = HashSet::new();
+        let node_ids = req
+            .node_ids()
+            .into_iter()
+            .filter(|node_id| seen.insert(*node_id))
+            .collect::<Vec<_>>();
+
+        let mut source_nodes = stream::iter(node_ids)
+            .map(|node_id| async move { (node_id, self.get_node(node_id).await) })
+            .buffered(NODES_LOOKUP_CONCURRENCY);
+
+        while let Some((node_id, source_node)) = source_nodes.next().await {
+            let source_encrypted = source_node?.is_encrypted.unwrap_or(false);
+
+            if source_encrypted != target_encrypted {
+                error!(
+                    "Cannot move node {} to {}: encryption mismatch",
+                    node_id, target_parent_id
+                );
+                return Err(DracoonClientError::EncryptionBoundaryCrossed {
+                    node_id,
+                    target_parent_id,
+                    source_encrypted,
+                    target_encrypted,
+                });
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -276,26 +838,111 @@ impl MissingFileKeys for Dracoon<Connected> {
         file_id: Option<u64>,
         user_id: Option<u64>,
     ) -> Result<u64, DracoonClientError> {
-        let keypair = self.get_keypair(None).await?;
-
-        let missing_keys = self
-            .get_missing_file_keys(room_id, file_id, user_id, None)
+        let (remaining_keys, _) = self
+            .distribute_missing_keys_batch(room_id, file_id, user_id)
             .await?;
 
-        let remaining_keys = if missing_keys.range.is_none() {
-            0
-        } else {
-            missing_keys.range.as_ref().unwrap().total
-        };
+        Ok(remaining_keys)
+    }
 
-        let key_reqs =
-            UserFileKeySetBatchRequest::try_new_from_missing_keys(missing_keys, &keypair)?;
+    async fn distribute_all_missing_keys(
+        &self,
+        room_id: Option<u64>,
+        file_id: Option<u64>,
+        user_id: Option<u64>,
+    ) -> Result<u64, DracoonClientError> {
+        let mut distributed_keys = 0;
+        let mut last_remaining_keys = None;
+        let mut stable_iterations = 0;
+
+        loop {
+            let (remaining_keys, distributed) = self
+                .distribute_missing_keys_batch(room_id, file_id, user_id)
+                .await?;
+
+            distributed_keys += distributed;
+
+            if remaining_keys == 0 {
+                break;
+            }
+
+            // keys that cannot be distributed are returned again - stop after a few attempts
+            match last_remaining_keys {
+                Some(last) if remaining_keys >= last => {
+                    stable_iterations += 1;
+                    debug!(
+                        "No progress distributing keys ({} remaining, attempt {}/{})",
+                        remaining_keys, stable_iterations, MISSING_KEYS_MAX_STABLE_ITERATIONS
+                    );
+                }
+                _ => stable_iterations = 0,
+            }
+
+            if stable_iterations >= MISSING_KEYS_MAX_STABLE_ITERATIONS {
+                error!(
+                    "Missing keys not distributable ({} remaining after {} keys distributed)",
+                    remaining_keys, distributed_keys
+                );
+                return Err(DracoonClientError::MissingKeysNotDistributable {
+                    remaining: remaining_keys,
+                    distributed: distributed_keys,
+                });
+            }
+
+            last_remaining_keys = Some(remaining_keys);
+        }
 
-        if !key_reqs.is_empty() {
-            self.set_file_keys(key_reqs).await?;
+        Ok(distributed_keys)
+    }
+
+    async fn copy_nodes_with_keys(
+        &self,
+        req: TransferNodesRequest,
+        target_parent_id: u64,
+    ) -> Result<CopyWithKeysResult, DracoonClientError> {
+        // the copy only returns the target parent - copied nodes are the new children
+        let existing_ids = self
+            .nodes()
+            .get_all_nodes(Some(target_parent_id), None, None)
+            .await?
+            .items
+            .into_iter()
+            .map(|node| node.id)
+            .collect::<HashSet<_>>();
+
+        let target = self.nodes().copy_nodes(req, target_parent_id).await?;
+
+        let nodes = self
+            .nodes()
+            .get_all_nodes(Some(target_parent_id), None, None)
+            .await?
+            .items
+            .into_iter()
+            .filter(|node| !existing_ids.contains(&node.id))
+            .collect::<Vec<_>>();
+
+        if !target.is_encrypted.unwrap_or(false) {
+            return Ok(CopyWithKeysResult {
+                target,
+                nodes,
+                key_distribution: Ok(0),
+            });
         }
 
-        Ok(remaining_keys)
+        let key_distribution = self.distribute_copied_file_keys(&nodes).await;
+
+        if let Err(err) = &key_distribution {
+            error!(
+                "Error distributing file keys of nodes copied to {}: {}",
+                target_parent_id, err
+            );
+        }
+
+        Ok(CopyWithKeysResult {
+            target,
+            nodes,
+            key_distribution,
+        })
     }
 }
 
@@ -313,6 +960,19 @@ trait MissingFileKeysInternal {
         &self,
         req: UserFileKeySetBatchRequest,
     ) -> Result<(), DracoonClientError>;
+
+    /// Distributes a single batch of missing keys.
+    /// Returns the total amount of missing keys (before distribution) and the amount of distributed keys.
+    async fn distribute_missing_keys_batch(
+        &self,
+        room_id: Option<u64>,
+        file_id: Option<u64>,
+        user_id: Option<u64>,
+    ) -> Result<(u64, u64), DracoonClientError>;
+
+    /// Distributes all missing keys of the given (copied) files and of the files within the given folders.
+    /// Keys of all files are distributed - the first error is returned.
+    async fn distribute_copied_file_keys(&self, nodes: &[Node]) -> Result<u64, DracoonClientError>;
 }
 
 #[async_trait]
@@ -379,10 +1039,106 @@ impl MissingFileKeysInternal for Dracoon<Connected> {
 
         Ok(())
     }
+
+    async fn distribute_missing_keys_batch(
+        &self,
+        room_id: Option<u64>,
+        file_id: Option<u64>,
+        user_id: Option<u64>,
+    ) -> Result<(u64, u64), DracoonClientError> {
+        let keypair = self.get_keypair(None).await?;
+
+        let missing_keys = self
+            .get_missing_file_keys(room_id, file_id, user_id, None)
+            .await?;
+
+        let remaining_keys = if missing_keys.range.is_none() {
+            0
+        } else {
+            missing_keys.range.as_ref().unwrap().total
+        };
+
+        let key_reqs =
+            UserFileKeySetBatchRequest::try_new_from_missing_keys(missing_keys, &keypair)?;
+
+        let distributed_keys = key_reqs.len() as u64;
+
+        if !key_reqs.is_empty() {
+            self.set_file_keys(key_reqs).await?;
+        }
+
+        Ok((remaining_keys, distributed_keys))
+    }
+
+    async fn distribute_copied_file_keys(&self, nodes: &[Node]) -> Result<u64, DracoonClientError> {
+        let mut file_ids = Vec::new();
+
+        for node in nodes {
+            if node.node_type == NodeType::File {
+                file_ids.push(node.id);
+                continue;
+            }
+
+            // files of a copied folder are nested at any depth
+            let mut offset = 0;
+
+            loop {
+                let params = ListAllParams::builder()
+                    .with_filter(NodesSearchFilter::is_file())
+                    .with_offset(offset)
+                    .build();
+
+                let files = self
+                    .nodes()
+                    .search_nodes("*", Some(node.id), Some(-1), Some(params))
+                    .await?;
+
+                if files.items.is_empty() {
+                    break;
+                }
+
+                offset += files.items.len() as u64;
+                file_ids.extend(files.items.iter().map(|file| file.id));
+
+                if offset >= files.range.total {
+                    break;
+                }
+            }
+        }
+
+        let mut distributed_keys = 0;
+        let mut distribution_error = None;
+
+        for file_id in file_ids {
+            match self
+                .distribute_all_missing_keys(None, Some(file_id), None)
+                .await
+            {
+                Ok(distributed) => distributed_keys += distributed,
+                Err(err) => {
+                    error!("Error distributing file keys of file {}: {}", file_id, err);
+                    distribution_error.get_or_insert(err);
+                }
+            }
+        }
+
+        match distribution_error {
+            Some(err) => Err(err),
+            None => Ok(distributed_keys),
+        }
+    }
 }
 
 type ParsedPath = (String, String, u64);
 
+/// Builds the full path of a node from its parent path and name
+pub fn build_node_path(node: &Node) -> String {
+    let parent_path = node.parent_path.as_deref().unwrap_or("/");
+    let parent_path = parent_path.trim_end_matches('/');
+
+    format!("{parent_path}/{}", node.name)
+}
+
 pub fn parse_node_path(path: &str) -> Result<ParsedPath, DracoonClientError> {
     if path == "/" {
         return Ok((String::from("/"), String::new(), 0));
@@ -0,0 +1,171 @@
+use async_trait::async_trait;
+use reqwest::header;
+use tracing::error;
+
+use crate::{
+    client::{errors::DracoonClientError, Connected},
+    constants::{DRACOON_API_PREFIX, FILES_BASE, FILES_UPLOAD, NODES_BASE},
+    models::ListAllParams,
+    utils::FromResponse,
+};
+
+use super::{models::PendingUploadList, NodesEndpoint, PendingUploads};
+
+#[async_trait]
+impl PendingUploads for NodesEndpoint<Connected> {
+    async fn get_pending_uploads(
+        &self,
+        params: Option<ListAllParams>,
+    ) -> Result<PendingUploadList, DracoonClientError> {
+        let url_part = format!("/{DRACOON_API_PREFIX}/{NODES_BASE}/{FILES_BASE}/{FILES_UPLOAD}");
+        let mut api_url = self.client().build_api_url(&url_part);
+
+        let params = params.unwrap_or_default();
+
+        let filters = params.filter_to_string();
+        let sorts = params.sort_to_string();
+
+        api_url
+            .query_pairs_mut()
+            .extend_pairs(params.limit.map(|limit| ("limit", limit.to_string())))
+            .extend_pairs(params.offset.map(|offset| ("offset", offset.to_string())))
+            .extend_pairs(params.filter.map(|filter| ("filter", filters)))
+            .extend_pairs(params.sort.map(|sort| ("sort", sorts)))
+            .finish();
+
+        let response = self
+            .client()
+            .http
+            .get(api_url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .header(header::CONTENT_TYPE, "application/json")
+            .send()
+            .await?;
+
+        PendingUploadList::from_response(response).await
+    }
+
+    async fn cancel_pending_uploads(
+        &self,
+        upload_ids: Vec<String>,
+    ) -> Result<(), DracoonClientError> {
+        for upload_id in upload_ids {
+            let url_part = format!(
+                "/{DRACOON_API_PREFIX}/{NODES_BASE}/{FILES_BASE}/{FILES_UPLOAD}/{upload_id}"
+            );
+
+            let api_url = self.client().build_api_url(&url_part);
+
+            let response = self
+                .client()
+                .http
+                .delete(api_url)
+                .header(
+                    header::AUTHORIZATION,
+                    self.client().get_auth_header().await?,
+                )
+                .send()
+                .await?;
+
+            if response.status().is_server_error() || response.status().is_client_error() {
+                error!("Error cancelling upload {}", upload_id);
+                return Err(DracoonClientError::from_response(response)
+                    .await
+                    .expect("Could not parse error response"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::dracoon::get_connected_client;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_pending_uploads() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let pending_uploads_res = include_str!("../tests/responses/upload/pending_uploads_ok.json");
+
+        let pending_uploads_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads?offset=0")
+            .with_status(200)
+            .with_body(pending_uploads_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let pending_uploads = client.nodes().get_pending_uploads(None).await.unwrap();
+
+        pending_uploads_mock.assert();
+
+        assert_eq!(pending_uploads.range.total, 3);
+        assert_eq!(pending_uploads.items.len(), 3);
+
+        let pending_upload = pending_uploads.items.first().unwrap();
+        assert_eq!(pending_upload.upload_id, "upload1");
+        assert_eq!(pending_upload.parent_id, 1);
+        assert_eq!(pending_upload.parent_path, Some("/room".to_string()));
+        assert_eq!(pending_upload.file_name, "file1.txt");
+        assert_eq!(pending_upload.user_id, Some(2));
+        assert_eq!(pending_upload.status, Some("transfer".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_pending_uploads() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let pending_uploads_res = include_str!("../tests/responses/upload/pending_uploads_ok.json");
+
+        let pending_uploads_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads?offset=0")
+            .with_status(200)
+            .with_body(pending_uploads_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let cancel_mocks: Vec<_> = ["upload1", "upload2"]
+            .iter()
+            .map(|upload_id| {
+                mock_server
+                    .mock(
+                        "DELETE",
+                        format!("/api/v4/nodes/files/uploads/{upload_id}").as_str(),
+                    )
+                    .with_status(204)
+                    .create()
+            })
+            .collect();
+
+        let not_cancelled_mock = mock_server
+            .mock("DELETE", "/api/v4/nodes/files/uploads/upload3")
+            .with_status(204)
+            .expect(0)
+            .create();
+
+        let pending_uploads = client.nodes().get_pending_uploads(None).await.unwrap();
+
+        let upload_ids = pending_uploads
+            .items
+            .into_iter()
+            .take(2)
+            .map(|upload| upload.upload_id)
+            .collect();
+
+        client
+            .nodes()
+            .cancel_pending_uploads(upload_ids)
+            .await
+            .unwrap();
+
+        pending_uploads_mock.assert();
+        cancel_mocks.iter().for_each(|mock| mock.assert());
+        not_cancelled_mock.assert();
+    }
+}
@@ -0,0 +1,298 @@
+use async_trait::async_trait;
+use reqwest::header;
+
+use crate::{
+    client::{errors::DracoonClientError, Connected},
+    constants::{
+        DRACOON_API_PREFIX, NODES_BASE, NODES_DELETED, NODES_DELETED_RESTORE,
+        NODES_DELETED_VERSIONS,
+    },
+    models::ListAllParams,
+    utils::FromResponse,
+};
+
+use super::{
+    models::{
+        DeletedNodeSummaryList, DeletedNodeVersionsList, NodeType, RestoreDeletedNodesRequest,
+    },
+    nodes::map_node_conflict,
+    NodesEndpoint, RecycleBin,
+};
+
+#[async_trait]
+impl RecycleBin for NodesEndpoint<Connected> {
+    async fn get_deleted_nodes(
+        &self,
+        parent_id: u64,
+        params: Option<ListAllParams>,
+    ) -> Result<DeletedNodeSummaryList, DracoonClientError> {
+        let url_part = format!("/{DRACOON_API_PREFIX}/{NODES_BASE}/{parent_id}/{NODES_DELETED}");
+        let mut api_url = self.client().build_api_url(&url_part);
+
+        let params = params.unwrap_or_default();
+        let filters = params.filter_to_string();
+        let sorts = params.sort_to_string();
+
+        api_url
+            .query_pairs_mut()
+            .extend_pairs(params.limit.map(|v| ("limit", v.to_string())))
+            .extend_pairs(params.offset.map(|v| ("offset", v.to_string())))
+            .extend_pairs(params.sort.as_ref().map(|_| ("sort", sorts)))
+            .extend_pairs(params.filter.as_ref().map(|_| ("filter", filters)))
+            .finish();
+
+        let response = self
+            .client()
+            .http
+            .get(api_url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .header(header::CONTENT_TYPE, "application/json")
+            .send()
+            .await?;
+
+        DeletedNodeSummaryList::from_response(response).await
+    }
+
+    async fn get_deleted_node_versions(
+        &self,
+        parent_id: u64,
+        node_type: NodeType,
+        name: &str,
+        params: Option<ListAllParams>,
+    ) -> Result<DeletedNodeVersionsList, DracoonClientError> {
+        let url_part = format!(
+            "/{DRACOON_API_PREFIX}/{NODES_BASE}/{parent_id}/{NODES_DELETED}/{NODES_DELETED_VERSIONS}"
+        );
+        let mut api_url = self.client().build_api_url(&url_part);
+
+        let node_type = match node_type {
+            NodeType::Room => "room",
+            NodeType::Folder => "folder",
+            NodeType::File => "file",
+        };
+
+        let params = params.unwrap_or_default();
+
+        api_url
+            .query_pairs_mut()
+            .append_pair("type", node_type)
+            .append_pair("name", name)
+            .extend_pairs(params.limit.map(|v| ("limit", v.to_string())))
+            .extend_pairs(params.offset.map(|v| ("offset", v.to_string())))
+            .finish();
+
+        let response = self
+            .client()
+            .http
+            .get(api_url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .header(header::CONTENT_TYPE, "application/json")
+            .send()
+            .await?;
+
+        DeletedNodeVersionsList::from_response(response).await
+    }
+
+    async fn restore_nodes(
+        &self,
+        req: RestoreDeletedNodesRequest,
+    ) -> Result<(), DracoonClientError> {
+        let url_part =
+            format!("/{DRACOON_API_PREFIX}/{NODES_BASE}/{NODES_DELETED}/{NODES_DELETED_RESTORE}");
+        let api_url = self.client().build_api_url(&url_part);
+
+        let response = self
+            .client()
+            .http
+            .post(api_url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&req)
+            .send()
+            .await?;
+
+        if response.status().is_server_error() || response.status().is_client_error() {
+            let err = DracoonClientError::from_response(response)
+                .await
+                .expect("Could not parse error response");
+            return Err(map_node_conflict(err));
+        }
+
+        Ok(())
+    }
+
+    async fn empty_recycle_bin(&self, parent_id: u64) -> Result<(), DracoonClientError> {
+        let url_part = format!("/{DRACOON_API_PREFIX}/{NODES_BASE}/{parent_id}/{NODES_DELETED}");
+        let api_url = self.client().build_api_url(&url_part);
+
+        let response = self
+            .client()
+            .http
+            .delete(api_url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .send()
+            .await?;
+
+        if response.status().is_server_error() || response.status().is_client_error() {
+            return Err(DracoonClientError::from_response(response)
+                .await
+                .expect("Could not parse error response"));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::{nodes::ResolutionStrategy, tests::dracoon::get_connected_client};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_deleted_nodes() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let deleted_nodes_res = include_str!("../tests/responses/nodes/deleted_nodes_ok.json");
+
+        let deleted_nodes_mock = mock_server
+            .mock("GET", "/api/v4/nodes/123/deleted_nodes?offset=0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(deleted_nodes_res)
+            .create();
+
+        let deleted_nodes = client.nodes().get_deleted_nodes(123, None).await.unwrap();
+
+        deleted_nodes_mock.assert();
+
+        assert_eq!(deleted_nodes.range.total, 2);
+        assert_eq!(deleted_nodes.items.len(), 2);
+
+        let file = deleted_nodes.items.first().unwrap();
+        assert_eq!(file.name, "report.pdf");
+        assert_eq!(file.node_type, NodeType::File);
+        assert_eq!(file.cnt_versions, 3);
+        assert_eq!(file.parent_id, 123);
+        assert!(file.timestamp_creation.is_some());
+
+        let folder = deleted_nodes.items.last().unwrap();
+        assert_eq!(folder.node_type, NodeType::Folder);
+        assert_eq!(folder.cnt_versions, 1);
+        assert!(folder.timestamp_creation.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_deleted_node_versions() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let versions_res = include_str!("../tests/responses/nodes/deleted_node_versions_ok.json");
+
+        let versions_mock = mock_server
+            .mock("GET", "/api/v4/nodes/123/deleted_nodes/versions")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".into(), "file".into()),
+                Matcher::UrlEncoded("name".into(), "report.pdf".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(versions_res)
+            .create();
+
+        let versions = client
+            .nodes()
+            .get_deleted_node_versions(123, NodeType::File, "report.pdf", None)
+            .await
+            .unwrap();
+
+        versions_mock.assert();
+
+        assert_eq!(versions.items.len(), 2);
+
+        let first = versions.items.first().unwrap();
+        assert_eq!(first.id, 11);
+        assert_eq!(first.size, Some(1024));
+        assert_eq!(first.deleted_by.as_ref().unwrap().id, 2);
+
+        let second = versions.items.last().unwrap();
+        assert_eq!(second.id, 12);
+        assert!(second.deleted_by.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_restore_nodes() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let restore_mock = mock_server
+            .mock("POST", "/api/v4/nodes/deleted_nodes/actions/restore")
+            .match_body(Matcher::Json(serde_json::json!({
+                "deletedNodeIds": [11, 12],
+                "resolutionStrategy": "overwrite",
+                "parentId": 456
+            })))
+            .with_status(204)
+            .create();
+
+        let req = RestoreDeletedNodesRequest::builder(vec![11, 12])
+            .with_resolution_strategy(ResolutionStrategy::Overwrite)
+            .with_parent_id(456)
+            .build();
+
+        client.nodes().restore_nodes(req).await.unwrap();
+
+        restore_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_restore_nodes_conflict() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let restore_mock = mock_server
+            .mock("POST", "/api/v4/nodes/deleted_nodes/actions/restore")
+            .match_body(Matcher::PartialJsonString(
+                r#"{"resolutionStrategy": "fail"}"#.into(),
+            ))
+            .with_status(409)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/error_conflict.json"))
+            .create();
+
+        let req = RestoreDeletedNodesRequest::builder(vec![11])
+            .with_resolution_strategy(ResolutionStrategy::Fail)
+            .build();
+
+        let err = client.nodes().restore_nodes(req).await.unwrap_err();
+
+        restore_mock.assert();
+
+        assert!(err.is_node_conflict());
+    }
+
+    #[tokio::test]
+    async fn test_empty_recycle_bin() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let empty_mock = mock_server
+            .mock("DELETE", "/api/v4/nodes/123/deleted_nodes")
+            .with_status(204)
+            .create();
+
+        client.nodes().empty_recycle_bin(123).await.unwrap();
+
+        empty_mock.assert();
+    }
+}
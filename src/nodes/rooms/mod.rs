@@ -1,23 +1,30 @@
 use async_trait::async_trait;
+use futures_util::{stream, StreamExt};
 use reqwest::header;
+use tracing::error;
 
 use crate::{
-    client::{errors::DracoonClientError, Connected},
+    client::{errors::DracoonClientError, models::DracoonErrorResponse, Connected},
     constants::{
-        DRACOON_API_PREFIX, NODES_BASE, ROOMS_BASE, ROOMS_CONFIG, ROOMS_ENCRYPT, ROOMS_GROUPS,
-        ROOMS_GUEST_USERS, ROOMS_POLICIES, ROOMS_USERS,
+        DRACOON_API_PREFIX, NODES_BASE, ROOMS_BASE, ROOMS_BATCH_CONCURRENCY, ROOMS_CONFIG,
+        ROOMS_ENCRYPT, ROOMS_GROUPS, ROOMS_GUEST_USERS, ROOMS_POLICIES, ROOMS_USERS,
     },
     models::ListAllParams,
-    utils::FromResponse,
+    user::{User, UserEndpoint},
+    utils::{FromResponse, WithTimestampTimezone},
 };
 
 use self::models::{
     ConfigRoomRequest, CreateRoomRequest, EncryptRoomRequest, RoomGroupList,
     RoomGroupsAddBatchRequest, RoomGroupsDeleteBatchRequest, RoomPolicies, RoomPoliciesRequest,
-    RoomUserList, RoomUsersAddBatchRequest, RoomUsersDeleteBatchRequest, UpdateRoomRequest,
+    RoomQuota, RoomUserItem, RoomUserList, RoomUsersAddBatchRequest, RoomUsersDeleteBatchRequest,
+    RoomUsersFilter, UpdateRoomRequest,
 };
 
-use super::{models::Node, NodesEndpoint, RoomGuestUserAddRequest, Rooms};
+use super::{
+    models::{Node, NodesFilter},
+    Nodes, NodesEndpoint, RoomGuestUserAddRequest, Rooms,
+};
 
 pub mod models;
 
@@ -27,6 +34,9 @@ impl Rooms for NodesEndpoint<Connected> {
         &self,
         create_room_req: CreateRoomRequest,
     ) -> Result<Node, DracoonClientError> {
+        let create_room_req =
+            create_room_req.with_timestamp_timezone(self.client().get_timestamp_timezone());
+
         let url_part = format!("/{DRACOON_API_PREFIX}/{NODES_BASE}/{ROOMS_BASE}");
         let api_url = self.client().build_api_url(&url_part);
 
@@ -50,6 +60,9 @@ impl Rooms for NodesEndpoint<Connected> {
         room_id: u64,
         update_room_req: UpdateRoomRequest,
     ) -> Result<Node, DracoonClientError> {
+        let update_room_req =
+            update_room_req.with_timestamp_timezone(self.client().get_timestamp_timezone());
+
         let url_part = format!("/{DRACOON_API_PREFIX}/{NODES_BASE}/{ROOMS_BASE}/{room_id}");
         let api_url = self.client().build_api_url(&url_part);
 
@@ -110,6 +123,11 @@ impl Rooms for NodesEndpoint<Connected> {
 
         RoomPolicies::from_response(response).await
     }
+    async fn get_room_quota(&self, room_id: u64) -> Result<RoomQuota, DracoonClientError> {
+        let room = self.get_node(room_id).await?;
+
+        Ok(RoomQuota::from(&room))
+    }
     async fn update_room_policies(
         &self,
         room_id: u64,
@@ -138,11 +156,47 @@ impl Rooms for NodesEndpoint<Connected> {
 
         Ok(())
     }
+
+    async fn update_rooms_policies(
+        &self,
+        room_ids: Vec<u64>,
+        policy_room_req: RoomPoliciesRequest,
+    ) -> Vec<(u64, Result<(), DracoonClientError>)> {
+        stream::iter(room_ids)
+            .map(|room_id| {
+                let policy_room_req = policy_room_req.clone();
+                async move {
+                    let result = self.update_room_policies(room_id, policy_room_req).await;
+                    if let Err(ref err) = result {
+                        error!("Error updating policies of room {}: {}", room_id, err);
+                    }
+                    (room_id, result)
+                }
+            })
+            .buffered(ROOMS_BATCH_CONCURRENCY)
+            .collect()
+            .await
+    }
     async fn encrypt_room(
         &self,
         room_id: u64,
         encrypt_room_req: EncryptRoomRequest,
     ) -> Result<Node, DracoonClientError> {
+        // without a rescue key, the user keypair is the only way to access file keys
+        if encrypt_room_req.is_encrypted() && !encrypt_room_req.has_rescue_key() {
+            let account = UserEndpoint::new(self.client().clone())
+                .get_user_account()
+                .await?;
+
+            if account.is_encryption_enabled != Some(true) {
+                error!(
+                    "Cannot encrypt room {}: no rescue key and no user keypair",
+                    room_id
+                );
+                return Err(DracoonClientError::MissingRoomEncryptionKey { room_id });
+            }
+        }
+
         let url_part =
             format!("/{DRACOON_API_PREFIX}/{NODES_BASE}/{ROOMS_BASE}/{room_id}/{ROOMS_ENCRYPT}");
         let api_url = self.client().build_api_url(&url_part);
@@ -287,6 +341,41 @@ impl Rooms for NodesEndpoint<Connected> {
 
         RoomUserList::from_response(response).await
     }
+    async fn get_room_user(
+        &self,
+        room_id: u64,
+        user_id: i64,
+    ) -> Result<RoomUserItem, DracoonClientError> {
+        let explicit_params = ListAllParams::builder()
+            .with_filter(RoomUsersFilter::user_id_equals(user_id))
+            .with_filter(RoomUsersFilter::is_granted_any())
+            .build();
+
+        let effective_params = ListAllParams::builder()
+            .with_filter(RoomUsersFilter::user_id_equals(user_id))
+            .with_filter(RoomUsersFilter::is_granted_any())
+            .with_filter(RoomUsersFilter::effective_perm(true))
+            .build();
+
+        let explicit = self
+            .get_room_users(room_id, Some(explicit_params))
+            .await?
+            .items
+            .into_iter()
+            .find(|user| user.user_info.id == user_id)
+            .ok_or_else(|| {
+                DracoonClientError::Http(DracoonErrorResponse::new(404, "User not found in room"))
+            })?;
+
+        let effective = self
+            .get_room_users(room_id, Some(effective_params))
+            .await?
+            .items
+            .into_iter()
+            .find(|user| user.user_info.id == user_id);
+
+        Ok(RoomUserItem::new(explicit, effective))
+    }
     async fn update_room_users(
         &self,
         room_id: u64,
@@ -373,4 +462,19 @@ impl Rooms for NodesEndpoint<Connected> {
 
         Ok(())
     }
+
+    async fn room_name_available(
+        &self,
+        parent_id: Option<u64>,
+        name: &str,
+    ) -> Result<bool, DracoonClientError> {
+        let params = ListAllParams::builder()
+            .with_filter(NodesFilter::name_equals(name))
+            .with_filter(NodesFilter::is_room())
+            .build();
+
+        let rooms = self.get_nodes(parent_id, None, Some(params)).await?;
+
+        Ok(!rooms.items.iter().any(|room| room.name == name))
+    }
 }
@@ -9,9 +9,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     client::{errors::DracoonClientError, models::DracoonErrorResponse},
-    models::RangedItems,
-    nodes::models::{NodePermissions, UserInfo},
-    utils::{parse_body, FromResponse},
+    models::{FilterOperator, FilterQuery, RangedItems},
+    nodes::models::{Node, NodePermissions, UserInfo},
+    utils::{parse_body, FromResponse, WithTimestampTimezone},
 };
 
 #[derive(Debug, Serialize, Clone)]
@@ -44,6 +44,15 @@ pub struct CreateRoomRequest {
     timestamp_modification: Option<String>,
 }
 
+impl WithTimestampTimezone for CreateRoomRequest {
+    fn timestamps_mut(&mut self) -> [&mut Option<String>; 2] {
+        [
+            &mut self.timestamp_creation,
+            &mut self.timestamp_modification,
+        ]
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub enum GroupMemberAcceptance {
     #[serde(rename = "autoallow")]
@@ -186,6 +195,15 @@ pub struct UpdateRoomRequest {
     timestamp_modification: Option<String>,
 }
 
+impl WithTimestampTimezone for UpdateRoomRequest {
+    fn timestamps_mut(&mut self) -> [&mut Option<String>; 2] {
+        [
+            &mut self.timestamp_creation,
+            &mut self.timestamp_modification,
+        ]
+    }
+}
+
 impl UpdateRoomRequest {
     pub fn builder() -> UpdateRoomRequestBuilder {
         UpdateRoomRequestBuilder {
@@ -418,6 +436,15 @@ impl EncryptRoomRequest {
             data_room_rescue_key: None,
         }
     }
+
+    pub fn is_encrypted(&self) -> bool {
+        self.is_encrypted
+    }
+
+    /// Returns true if either a data room or the data space rescue key is set
+    pub fn has_rescue_key(&self) -> bool {
+        self.data_room_rescue_key.is_some() || self.use_data_space_rescue_key == Some(true)
+    }
 }
 
 pub struct EncryptRoomRequestBuilder {
@@ -451,6 +478,32 @@ impl EncryptRoomRequestBuilder {
     }
 }
 
+/// Quota of a room (all values in bytes)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoomQuota {
+    pub room_id: u64,
+    /// `None` if no quota is set
+    pub quota: Option<u64>,
+    pub size: u64,
+    /// remaining space (`None` if no quota is set)
+    pub available: Option<u64>,
+}
+
+impl From<&Node> for RoomQuota {
+    fn from(node: &Node) -> Self {
+        // a quota of 0 means no quota is set
+        let quota = node.quota.filter(|quota| *quota > 0);
+        let size = node.size.unwrap_or_default();
+
+        Self {
+            room_id: node.id,
+            quota,
+            size,
+            available: quota.map(|quota| quota.saturating_sub(size)),
+        }
+    }
+}
+
 pub type RoomGroupList = RangedItems<RoomGroup>;
 
 #[async_trait]
@@ -619,6 +672,101 @@ pub struct RoomUser {
     pub public_key_container: Option<PublicKeyContainer>,
 }
 
+/// A single room user with explicit and effective permissions
+/// - **explicit**: permissions granted directly to the user
+/// - **effective**: permissions including the ones inherited from groups
+#[derive(Debug, Clone)]
+pub struct RoomUserItem {
+    pub user_info: UserInfo,
+    pub is_granted: bool,
+    pub permissions: Option<NodePermissions>,
+    pub effective_permissions: Option<NodePermissions>,
+    pub is_inherited: bool,
+    pub public_key_container: Option<PublicKeyContainer>,
+}
+
+impl RoomUserItem {
+    pub fn new(explicit: RoomUser, effective: Option<RoomUser>) -> Self {
+        let effective_permissions = effective.and_then(|user| user.permissions);
+        let is_inherited = !explicit.is_granted && effective_permissions.is_some();
+        let permissions = if explicit.is_granted {
+            explicit.permissions
+        } else {
+            None
+        };
+
+        Self {
+            user_info: explicit.user_info,
+            is_granted: explicit.is_granted,
+            permissions,
+            effective_permissions,
+            is_inherited,
+            public_key_container: explicit.public_key_container,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum RoomUsersFilter {
+    UserId(FilterOperator, i64),
+    User(FilterOperator, String),
+    IsGranted(FilterOperator, String),
+    EffectivePerm(FilterOperator, bool),
+}
+
+impl FilterQuery for RoomUsersFilter {
+    fn to_filter_string(&self) -> String {
+        match self {
+            RoomUsersFilter::UserId(op, val) => {
+                let op: String = op.into();
+                format!("userId:{}:{}", op, val)
+            }
+            RoomUsersFilter::User(op, val) => {
+                let op: String = op.into();
+                format!("user:{}:{}", op, val)
+            }
+            RoomUsersFilter::IsGranted(op, val) => {
+                let op: String = op.into();
+                format!("isGranted:{}:{}", op, val)
+            }
+            RoomUsersFilter::EffectivePerm(op, val) => {
+                let op: String = op.into();
+                format!("effectivePerm:{}:{}", op, val)
+            }
+        }
+    }
+}
+
+impl RoomUsersFilter {
+    pub fn user_id_equals(val: i64) -> Self {
+        Self::UserId(FilterOperator::Eq, val)
+    }
+
+    pub fn user_contains(val: impl Into<String>) -> Self {
+        Self::User(FilterOperator::Cn, val.into())
+    }
+
+    pub fn is_granted(val: bool) -> Self {
+        Self::IsGranted(FilterOperator::Eq, val.to_string())
+    }
+
+    /// Includes users with and without granted permissions
+    pub fn is_granted_any() -> Self {
+        Self::IsGranted(FilterOperator::Eq, "any".into())
+    }
+
+    /// Returns effective permissions (including group permissions) instead of explicit ones
+    pub fn effective_perm(val: bool) -> Self {
+        Self::EffectivePerm(FilterOperator::Eq, val)
+    }
+}
+
+impl From<RoomUsersFilter> for Box<dyn FilterQuery> {
+    fn from(filter: RoomUsersFilter) -> Self {
+        Box::new(filter)
+    }
+}
+
 #[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct RoomUsersAddBatchRequest {
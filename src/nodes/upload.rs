@@ -1,32 +1,34 @@
-use std::time::Duration;
+use std::{io::SeekFrom, time::Duration};
 
 use super::{
     models::{
         CloneableUploadProgressCallback, CompleteS3FileUploadRequest, CreateFileUploadRequest,
         CreateFileUploadResponse, GeneratePresignedUrlsRequest, MissingKeysResponse, Node,
         PresignedUrl, PresignedUrlList, S3FileUploadStatus, S3UploadStatus, UploadOptions,
-        UploadProgressCallback, UserFileKeySetBatchRequest,
+        UploadPlan, UploadProgressCallback, UploadResult, UploadSession,
+        UserFileKeySetBatchRequest,
     },
-    CompleteUploadRequest, Upload,
+    CompleteUploadRequest, Nodes, ResumableUpload, Upload,
 };
 use crate::{
-    client::{errors::DracoonClientError, Connected, GetClient},
+    client::{errors::DracoonClientError, models::DracoonErrorResponse, Connected, GetClient},
     constants::{
-        DEFAULT_UPLOAD_CHUNK_SIZE, DEFAULT_UPLOAD_GRANULARITY, DRACOON_API_PREFIX, FILES_BASE,
-        FILES_KEYS, FILES_S3_COMPLETE, FILES_S3_URLS, FILES_UPLOAD, MISSING_FILE_KEYS,
-        MISSING_KEYS_BATCH, NODES_BASE, POLLING_START_DELAY, UPLOADS_BASE,
+        DEFAULT_UPLOAD_CHUNK_SIZE, DEFAULT_UPLOAD_CONCURRENCY, DEFAULT_UPLOAD_GRANULARITY,
+        DRACOON_API_PREFIX, FILES_BASE, FILES_KEYS, FILES_S3_COMPLETE, FILES_S3_URLS, FILES_UPLOAD,
+        MISSING_FILE_KEYS, MISSING_KEYS_BATCH, NODES_BASE, POLLING_START_DELAY,
+        S3_MAX_UPLOAD_PARTS, S3_MAX_UPLOAD_PART_SIZE, UPLOADS_BASE, UPLOAD_STATUS_TIMEOUT,
     },
-    nodes::models::{S3FileUploadPart, UserFileKeySetRequest},
-    utils::{build_s3_error, FromResponse},
+    nodes::models::{S3FileUploadPart, S3XmlError, UserFileKeySetRequest},
+    utils::{build_s3_error, FromResponse, WithTimestampTimezone},
     Dracoon,
 };
 use async_trait::async_trait;
 
 use dco3_crypto::{ChunkedEncryption, DracoonCrypto, DracoonRSACrypto, Encrypter};
-use futures_util::Stream;
+use futures_util::{future::BoxFuture, stream::FuturesUnordered, Stream, StreamExt};
 use reqwest::{header, Body};
-use tokio::io::{AsyncRead, AsyncReadExt, BufReader};
-use tracing::error;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, BufReader};
+use tracing::{debug, error};
 
 #[async_trait]
 impl<R: AsyncRead + Sync + Send + Unpin + 'static> Upload<R> for Dracoon<Connected> {
@@ -38,25 +40,452 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> Upload<R> for Dracoon<Connect
         callback: Option<UploadProgressCallback>,
         chunk_size: Option<usize>,
     ) -> Result<Node, DracoonClientError> {
-        let is_s3_upload = self.get_system_info().await?.use_s3_storage;
-        let is_encrypted = parent_node.is_encrypted.unwrap_or(false);
+        <Dracoon<Connected> as Upload<R>>::upload_with_result(
+            self,
+            parent_node,
+            upload_options,
+            reader,
+            callback,
+            chunk_size,
+        )
+        .await
+        .map(|upload_result| upload_result.node)
+    }
 
-        let upload_fn = match (is_encrypted, is_s3_upload) {
-            (true, true) => Self::upload_to_s3_encrypted,
-            (true, false) => Self::upload_to_nfs_encrypted,
-            (false, true) => Self::upload_to_s3_unencrypted,
-            (false, false) => Self::upload_to_nfs_unencrypted,
-        };
+    async fn upload_with_result<'r>(
+        &'r self,
+        parent_node: &Node,
+        upload_options: UploadOptions,
+        reader: BufReader<R>,
+        callback: Option<UploadProgressCallback>,
+        chunk_size: Option<usize>,
+    ) -> Result<UploadResult, DracoonClientError> {
+        self.upload_with_rewind(
+            parent_node,
+            upload_options,
+            reader,
+            callback,
+            chunk_size,
+            None,
+        )
+        .await
+    }
 
-        upload_fn(
-            self,
+    async fn upload_seekable<'r>(
+        &'r self,
+        parent_node: &Node,
+        upload_options: UploadOptions,
+        reader: BufReader<R>,
+        callback: Option<UploadProgressCallback>,
+        chunk_size: Option<usize>,
+    ) -> Result<Node, DracoonClientError>
+    where
+        R: AsyncSeek,
+    {
+        self.upload_with_rewind(
             parent_node,
             upload_options,
             reader,
             callback,
             chunk_size,
+            Some(rewind_seekable::<R>),
+        )
+        .await
+        .map(|upload_result| upload_result.node)
+    }
+
+    async fn plan_upload(
+        &self,
+        file_size: u64,
+        chunk_size: Option<usize>,
+    ) -> Result<UploadPlan, DracoonClientError> {
+        let use_s3_storage = self.get_system_info().await?.use_s3_storage;
+
+        let part_size = if use_s3_storage {
+            resolve_s3_chunk_size(file_size, chunk_size.unwrap_or(DEFAULT_UPLOAD_CHUNK_SIZE))?
+                as u64
+        } else {
+            chunk_size.unwrap_or(DEFAULT_UPLOAD_CHUNK_SIZE) as u64
+        };
+
+        let (part_count, last_part_size) = if use_s3_storage {
+            calculate_s3_upload_parts(file_size, part_size)?
+        } else {
+            calculate_upload_chunks(file_size, part_size)?
+        };
+
+        Ok(UploadPlan {
+            file_size,
+            part_count,
+            part_size,
+            last_part_size,
+            use_s3_storage,
+        })
+    }
+}
+
+impl Dracoon<Connected> {
+    /// Uploads a stream to the given parent node - the rewind function (if passed) is used to
+    /// retry unencrypted S3 uploads (encrypted uploads retry from the encrypted buffer).
+    async fn upload_with_rewind<R: AsyncRead + Sync + Send + Unpin + 'static>(
+        &self,
+        parent_node: &Node,
+        upload_options: UploadOptions,
+        reader: BufReader<R>,
+        callback: Option<UploadProgressCallback>,
+        chunk_size: Option<usize>,
+        rewind: Option<ReaderRewind<R>>,
+    ) -> Result<UploadResult, DracoonClientError> {
+        // resolves the part size (S3 part size is clamped to the max. part size)
+        let plan = <Dracoon<Connected> as Upload<R>>::plan_upload(
+            self,
+            upload_options.file_meta.size,
+            chunk_size,
+        )
+        .await?;
+        let is_encrypted = parent_node.is_encrypted.unwrap_or(false);
+
+        // the part size is below the chunk size (usize) or the S3 max. part size
+        let part_size = Some(usize::try_from(plan.part_size).unwrap_or(usize::MAX));
+
+        let node = match (is_encrypted, plan.use_s3_storage) {
+            (true, true) => {
+                <Self as UploadInternal<R>>::upload_to_s3_encrypted(
+                    self,
+                    parent_node,
+                    upload_options,
+                    reader,
+                    callback,
+                    part_size,
+                )
+                .await
+            }
+            (true, false) => {
+                <Self as UploadInternalNfs<R, Connected>>::upload_to_nfs_encrypted(
+                    self,
+                    parent_node,
+                    upload_options,
+                    reader,
+                    callback,
+                    part_size,
+                )
+                .await
+            }
+            (false, true) => {
+                <Self as UploadInternal<R>>::upload_to_s3_unencrypted_with_rewind(
+                    self,
+                    parent_node,
+                    upload_options,
+                    reader,
+                    callback,
+                    part_size,
+                    rewind,
+                )
+                .await
+            }
+            (false, false) => {
+                <Self as UploadInternalNfs<R, Connected>>::upload_to_nfs_unencrypted(
+                    self,
+                    parent_node,
+                    upload_options,
+                    reader,
+                    callback,
+                    part_size,
+                )
+                .await
+            }
+        }?;
+
+        Ok(UploadResult { node, plan })
+    }
+}
+
+impl Dracoon<Connected> {
+    /// Uploads all parts of an S3 upload - up to `concurrency` parts (default: 4) are uploaded concurrently.
+    /// Chunks are read in order and only once an upload slot is free (max. `concurrency` chunks in memory).
+    /// The returned parts are sorted by part number.
+    async fn upload_s3_parts<R, T>(
+        &self,
+        upload_channel: &CreateFileUploadResponse,
+        reader: &mut T,
+        upload_options: &UploadOptions,
+        chunk_size: usize,
+        callback: Option<CloneableUploadProgressCallback>,
+    ) -> Result<Vec<S3FileUploadPart>, DracoonClientError>
+    where
+        R: AsyncRead + Sync + Send + Unpin + 'static,
+        T: AsyncRead + Send + Unpin,
+    {
+        let (count_urls, last_chunk_size) =
+            calculate_s3_upload_parts(upload_options.file_meta.size, chunk_size as u64)?;
+        let concurrency = upload_options
+            .upload_concurrency
+            .unwrap_or(DEFAULT_UPLOAD_CONCURRENCY)
+            .max(1);
+
+        let mut uploads = FuturesUnordered::new();
+        let mut s3_parts = Vec::with_capacity(count_urls as usize);
+
+        for url_part in 1..=count_urls {
+            // wait for a free upload slot before reading the next chunk
+            if uploads.len() >= concurrency {
+                if let Some(s3_part) = uploads.next().await {
+                    s3_parts.push(s3_part?);
+                }
+            }
+
+            let chunk_len = if url_part == count_urls {
+                last_chunk_size
+            } else {
+                chunk_size as u64
+            };
+
+            let chunk = read_chunk(reader, chunk_len).await?;
+
+            uploads.push(<Dracoon<Connected> as UploadInternal<R>>::upload_chunk(
+                self,
+                upload_channel,
+                chunk,
+                url_part,
+                callback.clone(),
+            ));
+        }
+
+        while let Some(s3_part) = uploads.next().await {
+            s3_parts.push(s3_part?);
+        }
+
+        // parts complete out of order
+        s3_parts.sort_by_key(S3FileUploadPart::part_number);
+
+        Ok(s3_parts)
+    }
+
+    /// Polls the S3 upload status (exponential backoff) until the upload is done or failed.
+    /// Fails with `DracoonClientError::UploadStatusTimeout` if the upload is not completed within the timeout.
+    async fn wait_for_upload_status<R: AsyncRead + Sync + Send + Unpin + 'static>(
+        &self,
+        upload_id: &str,
+        timeout: Duration,
+    ) -> Result<S3UploadOutcome, DracoonClientError> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut sleep_duration = Duration::from_millis(POLLING_START_DELAY);
+
+        loop {
+            let status_response = <Dracoon<Connected> as UploadInternal<R>>::get_upload_status(
+                self,
+                upload_id.to_string(),
+            )
+            .await
+            .map_err(|err| {
+                error!("Error getting upload status: {}", err);
+                err
+            })?;
+
+            match status_response.status {
+                S3UploadStatus::Done => {
+                    let Some(node) = status_response.node else {
+                        error!("Upload {} is done, but no node is returned", upload_id);
+                        return Err(DracoonClientError::Unknown);
+                    };
+                    return Ok(S3UploadOutcome::Done(Box::new(node)));
+                }
+                S3UploadStatus::Error => {
+                    let Some(response) = status_response.error_details else {
+                        error!("Upload {} failed without error details", upload_id);
+                        return Err(DracoonClientError::Unknown);
+                    };
+                    error!("Error uploading file: {}", response);
+                    return Ok(S3UploadOutcome::Failed(response));
+                }
+                _ => debug!("Upload {} not completed yet", upload_id),
+            }
+
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                error!("Upload {} not completed after {:?}", upload_id, timeout);
+                return Err(DracoonClientError::UploadStatusTimeout {
+                    upload_id: upload_id.to_string(),
+                    timeout,
+                });
+            }
+
+            // never sleep past the deadline - poll a last time instead
+            tokio::time::sleep(sleep_duration.min(deadline - now)).await;
+            sleep_duration *= 2;
+        }
+    }
+}
+
+#[async_trait]
+impl ResumableUpload for Dracoon<Connected> {
+    async fn create_upload_session(
+        &self,
+        parent_node: &Node,
+        upload_options: &UploadOptions,
+        chunk_size: Option<usize>,
+    ) -> Result<UploadSession, DracoonClientError> {
+        if parent_node.is_encrypted.unwrap_or(false) {
+            error!(
+                "Cannot create upload session in encrypted node {}",
+                parent_node.id
+            );
+            return Err(DracoonClientError::UploadNotResumable(
+                "encrypted uploads are not supported".to_string(),
+            ));
+        }
+
+        if !self.get_system_info().await?.use_s3_storage {
+            error!("Cannot create upload session without S3 storage");
+            return Err(DracoonClientError::UploadNotResumable(
+                "S3 storage required".to_string(),
+            ));
+        }
+
+        let chunk_size = resolve_s3_chunk_size(
+            upload_options.file_meta.size,
+            chunk_size.unwrap_or(DEFAULT_UPLOAD_CHUNK_SIZE),
+        )?;
+
+        let file_upload_req = CreateFileUploadRequest::from_upload_options(
+            parent_node.id,
+            upload_options,
+            Some(true),
+        );
+
+        // the reader type is irrelevant for creating the upload channel
+        let upload_channel =
+            <Dracoon<Connected> as UploadInternal<tokio::fs::File>>::create_upload_channel(
+                self,
+                file_upload_req,
+            )
+            .await
+            .map_err(|err| {
+                error!("Error creating upload channel: {}", err);
+                err
+            })?;
+
+        Ok(UploadSession::new(
+            parent_node.id,
+            upload_channel,
+            upload_options,
+            chunk_size,
+        ))
+    }
+
+    async fn resume_upload<R: AsyncRead + AsyncSeek + Sync + Send + Unpin + 'static>(
+        &self,
+        session: &mut UploadSession,
+        mut reader: BufReader<R>,
+        callback: Option<UploadProgressCallback>,
+        chunk_size: Option<usize>,
+    ) -> Result<Node, DracoonClientError> {
+        if let Some(chunk_size) = chunk_size {
+            if chunk_size != session.chunk_size {
+                error!(
+                    "Chunk size mismatch (session: {}, passed: {})",
+                    session.chunk_size, chunk_size
+                );
+                return Err(DracoonClientError::UploadNotResumable(format!(
+                    "chunk size mismatch (session: {}, passed: {chunk_size})",
+                    session.chunk_size
+                )));
+            }
+        }
+
+        let (count_urls, last_chunk_size) =
+            calculate_s3_upload_parts(session.file_size, session.chunk_size as u64)?;
+
+        if let Some(part) = session
+            .parts
+            .iter()
+            .find(|part| part.part_number() == 0 || part.part_number() > count_urls)
+        {
+            error!(
+                "Invalid part number in upload session: {}",
+                part.part_number()
+            );
+            return Err(DracoonClientError::UploadNotResumable(format!(
+                "invalid part number {} (max. {count_urls})",
+                part.part_number()
+            )));
+        }
+
+        // fails with not found if the parent node was deleted
+        self.nodes().get_node(session.parent_id).await?;
+
+        let upload_channel = session.upload_channel();
+        let cloneable_callback = callback.map(CloneableUploadProgressCallback::new);
+
+        for url_part in 1..=count_urls {
+            if session.is_part_completed(url_part) {
+                continue;
+            }
+
+            let part_size = if url_part == count_urls {
+                last_chunk_size
+            } else {
+                session.chunk_size as u64
+            };
+
+            let offset = calculate_chunk_offset(url_part, session.chunk_size);
+            reader.seek(SeekFrom::Start(offset)).await.map_err(|err| {
+                error!("Error seeking reader: {}", err);
+                DracoonClientError::IoError
+            })?;
+
+            let mut buffer = vec![
+                0;
+                part_size
+                    .try_into()
+                    .map_err(|_| DracoonClientError::IoError)?
+            ];
+            reader.read_exact(&mut buffer).await.map_err(|err| {
+                error!("Error reading file: {}", err);
+                DracoonClientError::IoError
+            })?;
+
+            let s3_part = <Dracoon<Connected> as UploadInternal<R>>::upload_chunk(
+                self,
+                &upload_channel,
+                bytes::Bytes::from(buffer),
+                url_part,
+                cloneable_callback.clone(),
+            )
+            .await?;
+
+            session.parts.push(s3_part);
+        }
+
+        let mut s3_parts = session.parts.clone();
+        s3_parts.sort_by_key(S3FileUploadPart::part_number);
+
+        let complete_upload_req = CompleteS3FileUploadRequest::builder(s3_parts)
+            .with_resolution_strategy(session.resolution_strategy.clone().unwrap_or_default())
+            .with_keep_share_links(session.keep_share_links.unwrap_or(false))
+            .build();
+
+        <Dracoon<Connected> as UploadInternal<R>>::finalize_upload::<'_, '_>(
+            self,
+            session.upload_id.clone(),
+            complete_upload_req,
         )
         .await
+        .map_err(|err| {
+            error!("Error finalizing upload: {}", err);
+            err
+        })?;
+
+        match self
+            .wait_for_upload_status::<R>(
+                &session.upload_id,
+                Duration::from_millis(UPLOAD_STATUS_TIMEOUT),
+            )
+            .await?
+        {
+            S3UploadOutcome::Done(node) => Ok(*node),
+            S3UploadOutcome::Failed(response) => Err(DracoonClientError::Http(response)),
+        }
     }
 }
 
@@ -81,6 +510,15 @@ trait UploadInternal<R: AsyncRead> {
         mut callback: Option<UploadProgressCallback>,
         chunk_size: Option<usize>,
     ) -> Result<Node, DracoonClientError>;
+    async fn upload_to_s3_unencrypted_with_rewind(
+        &self,
+        parent_node: &Node,
+        upload_options: UploadOptions,
+        reader: BufReader<R>,
+        mut callback: Option<UploadProgressCallback>,
+        chunk_size: Option<usize>,
+        rewind: Option<ReaderRewind<R>>,
+    ) -> Result<Node, DracoonClientError>;
     async fn upload_to_s3_encrypted(
         &self,
         parent_node: &Node,
@@ -152,8 +590,22 @@ pub(crate) trait StreamUploadInternal<S>: GetClient<S> {
                 "Error uploading file to S3: {:?}",
                 res.error_for_status_ref().unwrap_err()
             );
-            let error = build_s3_error(res).await;
-            return Err(error);
+            let status = res.status();
+            // keep the failing part number - an unparsable body results in an empty error
+            let error = match build_s3_error(res).await {
+                DracoonClientError::S3Error(err) => err.error,
+                _ => S3XmlError::default(),
+            };
+            error!(
+                "S3 upload of part {} failed: {:?}",
+                url.part_number,
+                error.code()
+            );
+            return Err(DracoonClientError::S3Upload {
+                part_number: url.part_number,
+                status,
+                error,
+            });
         }
 
         let e_tag_header = res
@@ -214,9 +666,11 @@ pub(crate) trait StreamUploadInternal<S>: GetClient<S> {
                 "Error uploading file to NFS: {:?}",
                 res.error_for_status_ref().unwrap_err()
             );
-            return Err(DracoonClientError::from_response(res)
-                .await
-                .unwrap_or(DracoonClientError::Unknown));
+            return Err(map_upload_session_expired(
+                DracoonClientError::from_response(res)
+                    .await
+                    .unwrap_or(DracoonClientError::Unknown),
+            ));
         }
         Ok(())
     }
@@ -282,6 +736,9 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternal<R> for Dracoon
 
         let api_url = self.build_api_url(&url_part);
 
+        let create_file_upload_req =
+            create_file_upload_req.with_timestamp_timezone(self.client.get_timestamp_timezone());
+
         let res = self
             .client
             .http
@@ -314,7 +771,9 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternal<R> for Dracoon
             .send()
             .await?;
 
-        PresignedUrlList::from_response(res).await
+        PresignedUrlList::from_response(res)
+            .await
+            .map_err(map_upload_session_expired)
     }
 
     async fn finalize_upload(
@@ -337,9 +796,24 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternal<R> for Dracoon
             .await?;
 
         if res.status().is_success() {
-            Ok(())
-        } else {
-            Err(DracoonClientError::from_response(res).await?)
+            return Ok(());
+        }
+
+        let err = DracoonClientError::from_response(res).await?;
+
+        // a completion retried after a 5xx may be rejected because the first request
+        // already went through - the upload status (same upload id) tells if the upload is finalized
+        match <Dracoon<Connected> as UploadInternal<R>>::get_upload_status(self, upload_id).await {
+            Ok(status)
+                if matches!(
+                    status.status,
+                    S3UploadStatus::Finishing | S3UploadStatus::Done
+                ) =>
+            {
+                debug!("Upload already completed - ignoring error: {}", err);
+                Ok(())
+            }
+            _ => Err(err),
         }
     }
 
@@ -363,155 +837,124 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternal<R> for Dracoon
         S3FileUploadStatus::from_response(res).await
     }
 
+    async fn upload_to_s3_unencrypted(
+        &self,
+        parent_node: &Node,
+        upload_options: UploadOptions,
+        reader: BufReader<R>,
+        callback: Option<UploadProgressCallback>,
+        chunk_size: Option<usize>,
+    ) -> Result<Node, DracoonClientError> {
+        <Dracoon<Connected> as UploadInternal<R>>::upload_to_s3_unencrypted_with_rewind(
+            self,
+            parent_node,
+            upload_options,
+            reader,
+            callback,
+            chunk_size,
+            None,
+        )
+        .await
+    }
+
     #[allow(clippy::single_match_else)]
     #[allow(clippy::too_many_lines)]
-    async fn upload_to_s3_unencrypted(
+    async fn upload_to_s3_unencrypted_with_rewind(
         &self,
         parent_node: &Node,
         upload_options: UploadOptions,
         mut reader: BufReader<R>,
         callback: Option<UploadProgressCallback>,
         chunk_size: Option<usize>,
+        rewind: Option<ReaderRewind<R>>,
     ) -> Result<Node, DracoonClientError> {
         let fm = upload_options.file_meta.clone();
 
-        let chunk_size = chunk_size.unwrap_or(DEFAULT_UPLOAD_CHUNK_SIZE);
-
-        // create upload channel
-        let file_upload_req = CreateFileUploadRequest::from_upload_options(
-            parent_node.id,
-            &upload_options,
-            Some(true),
-        );
-
-        let upload_channel = <Dracoon<Connected> as UploadInternal<R>>::create_upload_channel::<
-            '_,
-            '_,
-        >(self, file_upload_req)
-        .await
-        .map_err(|err| {
-            error!("Error creating upload channel: {}", err);
-            err
-        })?;
-
-        let mut s3_parts = Vec::new();
-
-        let (count_urls, last_chunk_size) = calculate_s3_url_count(fm.size, chunk_size as u64);
-        let mut url_part: u32 = 1;
+        let chunk_size =
+            resolve_s3_chunk_size(fm.size, chunk_size.unwrap_or(DEFAULT_UPLOAD_CHUNK_SIZE))?;
 
         let cloneable_callback = callback.map(CloneableUploadProgressCallback::new);
+        let mut attempt: u32 = 0;
+
+        'upload: loop {
+            // create upload channel
+            let file_upload_req = CreateFileUploadRequest::from_upload_options(
+                parent_node.id,
+                &upload_options,
+                Some(true),
+            );
 
-        if count_urls > 1 {
-            while url_part < count_urls {
-                let mut buffer = vec![0; chunk_size];
-                let cb = cloneable_callback.clone();
-                let fm = fm.clone();
-
-                match reader.read_exact(&mut buffer).await {
-                    Ok(0) => break,
-                    Ok(n) => {
-                        buffer.truncate(n);
-                        let chunk = bytes::Bytes::from(buffer);
-
-                        let s3_part = <Dracoon<Connected> as UploadInternal<R>>::upload_chunk(
-                            self,
-                            &upload_channel,
-                            chunk,
-                            url_part,
-                            cb,
-                        )
-                        .await?;
-
-                        s3_parts.push(s3_part);
-                        url_part += 1;
-                    }
-                    Err(err) => {
-                        error!("Error reading file: {}", err);
-                        return Err(DracoonClientError::IoError);
-                    }
-                }
-            }
-        }
-
-        // upload last chunk
-        let mut buffer = vec![
-            0;
-            last_chunk_size
-                .try_into()
-                .map_err(|_| DracoonClientError::IoError)?
-        ];
-        let cb = cloneable_callback.clone();
-        match reader.read_exact(&mut buffer).await {
-            Ok(n) => {
-                buffer.truncate(n);
-                let chunk = bytes::Bytes::from(buffer);
-
-                let s3_part = <Dracoon<Connected> as UploadInternal<R>>::upload_chunk(
+            let upload_channel =
+                <Dracoon<Connected> as UploadInternal<R>>::create_upload_channel::<'_, '_>(
                     self,
+                    file_upload_req,
+                )
+                .await
+                .map_err(|err| {
+                    error!("Error creating upload channel: {}", err);
+                    err
+                })?;
+
+            let s3_parts = self
+                .upload_s3_parts::<R, _>(
                     &upload_channel,
-                    chunk,
-                    url_part,
-                    cb,
+                    &mut reader,
+                    &upload_options,
+                    chunk_size,
+                    cloneable_callback.clone(),
                 )
                 .await?;
 
-                s3_parts.push(s3_part);
-            }
-            Err(err) => {
-                error!("Error reading file: {}", err);
-                return Err(DracoonClientError::IoError);
-            }
-        }
-
-        // finalize upload
-        let complete_upload_req = CompleteS3FileUploadRequest::builder(s3_parts)
-            .with_resolution_strategy(upload_options.resolution_strategy.unwrap_or_default())
-            .with_keep_share_links(upload_options.keep_share_links.unwrap_or(false))
-            .build();
-
-        <Dracoon<Connected> as UploadInternal<R>>::finalize_upload::<'_, '_>(
-            self,
-            upload_channel.upload_id.clone(),
-            complete_upload_req,
-        )
-        .await
-        .map_err(|err| {
-            error!("Error finalizing upload: {}", err);
-            err
-        })?;
+            // finalize upload
+            let complete_upload_req = CompleteS3FileUploadRequest::builder(s3_parts)
+                .with_resolution_strategy(
+                    upload_options
+                        .resolution_strategy
+                        .clone()
+                        .unwrap_or_default(),
+                )
+                .with_keep_share_links(upload_options.keep_share_links.unwrap_or(false))
+                .build();
 
-        // get upload status
-        // return node if upload is done
-        // return error if upload failed
-        // polling with exponential backoff
-        let mut sleep_duration = POLLING_START_DELAY;
-        loop {
-            let status_response = <Dracoon<Connected> as UploadInternal<R>>::get_upload_status(
+            <Dracoon<Connected> as UploadInternal<R>>::finalize_upload::<'_, '_>(
                 self,
                 upload_channel.upload_id.clone(),
+                complete_upload_req,
             )
             .await
             .map_err(|err| {
-                error!("Error getting upload status: {}", err);
+                error!("Error finalizing upload: {}", err);
                 err
             })?;
 
-            match status_response.status {
-                S3UploadStatus::Done => {
-                    return Ok(status_response
-                        .node
-                        .expect("Node must be set if status is done"));
-                }
-                S3UploadStatus::Error => {
-                    let response = status_response
-                        .error_details
-                        .expect("Error message must be set if status is error");
-                    error!("Error uploading file: {}", response);
-                    return Err(DracoonClientError::Http(response));
+            // poll the upload status until the upload is done or failed
+            match self
+                .wait_for_upload_status::<R>(
+                    &upload_channel.upload_id,
+                    Duration::from_millis(UPLOAD_STATUS_TIMEOUT),
+                )
+                .await?
+            {
+                S3UploadOutcome::Done(node) => {
+                    return verify_uploaded_node(self, *node, &upload_options).await;
                 }
-                _ => {
-                    tokio::time::sleep(Duration::from_millis(sleep_duration)).await;
-                    sleep_duration *= 2;
+                S3UploadOutcome::Failed(response) => {
+                    if attempt >= upload_options.upload_retries.unwrap_or(0) {
+                        return Err(DracoonClientError::Http(response));
+                    }
+
+                    // the upload can only be retried if the reader can be rewound
+                    let Some(rewind) = rewind else {
+                        return Err(DracoonClientError::UploadNotRetryable(response));
+                    };
+
+                    rewind(&mut reader).await.map_err(|err| {
+                        error!("Error rewinding reader: {}", err);
+                        DracoonClientError::IoError
+                    })?;
+
+                    attempt += 1;
+                    continue 'upload;
                 }
             }
         }
@@ -528,7 +971,10 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternal<R> for Dracoon
     ) -> Result<Node, DracoonClientError> {
         let keypair = self.get_keypair(None).await?;
 
-        let chunk_size = chunk_size.unwrap_or(DEFAULT_UPLOAD_CHUNK_SIZE);
+        let chunk_size = resolve_s3_chunk_size(
+            upload_options.file_meta.size,
+            chunk_size.unwrap_or(DEFAULT_UPLOAD_CHUNK_SIZE),
+        )?;
 
         let mut crypto_buff = vec![
             0u8;
@@ -563,203 +1009,126 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternal<R> for Dracoon
 
         assert_eq!(enc_bytes.len() as u64, upload_options.file_meta.size);
 
-        let mut crypto_reader = BufReader::new(enc_bytes.as_slice());
         let plain_file_key = crypter.get_plain_file_key();
         let file_key = DracoonCrypto::encrypt_file_key(plain_file_key.clone(), keypair)?;
-        // drop the crypto buffer (enc bytes are still in the reader)
+        // drop the crypto buffer (enc bytes are kept for the reader)
         drop(crypto_buff);
 
         let fm = upload_options.file_meta.clone();
 
-        // create upload channel
-        let file_upload_req = CreateFileUploadRequest::from_upload_options(
-            parent_node.id,
-            &upload_options,
-            Some(true),
-        );
-
-        let upload_channel = <Dracoon<Connected> as UploadInternal<R>>::create_upload_channel::<
-            '_,
-            '_,
-        >(self, file_upload_req)
-        .await
-        .map_err(|err| {
-            error!("Error creating upload channel: {}", err);
-            err
-        })?;
-
-        let mut s3_parts = Vec::new();
-
-        let (count_urls, last_chunk_size) = calculate_s3_url_count(fm.size, chunk_size as u64);
-        let mut url_part: u32 = 1;
-
         let cloneable_callback = callback.map(CloneableUploadProgressCallback::new);
+        let mut attempt: u32 = 0;
 
-        if count_urls > 1 {
-            while url_part < count_urls {
-                let mut buffer = vec![0; chunk_size];
-                let cb = cloneable_callback.clone();
-                let fm = fm.clone();
-
-                match crypto_reader.read_exact(&mut buffer).await {
-                    Ok(0) => break,
-                    Ok(n) => {
-                        let chunk_len = n;
-                        buffer.truncate(chunk_len);
-                        let chunk = bytes::Bytes::from(buffer);
-
-                        let s3_part = <Dracoon<Connected> as UploadInternal<R>>::upload_chunk(
-                            self,
-                            &upload_channel,
-                            chunk,
-                            url_part,
-                            cb,
-                        )
-                        .await?;
-
-                        s3_parts.push(s3_part);
-                        url_part += 1;
-                    }
-                    Err(err) => return Err(DracoonClientError::IoError),
-                }
-            }
-        }
+        'upload: loop {
+            // encrypted bytes are kept in memory - every attempt starts with a fresh reader
+            let mut crypto_reader = BufReader::new(enc_bytes.as_slice());
 
-        // upload last chunk
-        let mut buffer = vec![
-            0;
-            last_chunk_size
-                .try_into()
-                .map_err(|_| DracoonClientError::IoError)?
-        ];
-        let cb = cloneable_callback.clone();
-        match crypto_reader.read_exact(&mut buffer).await {
-            Ok(n) => {
-                buffer.truncate(n);
-                let chunk = bytes::Bytes::from(buffer);
+            // create upload channel
+            let file_upload_req = CreateFileUploadRequest::from_upload_options(
+                parent_node.id,
+                &upload_options,
+                Some(true),
+            );
 
-                let s3_part = <Dracoon<Connected> as UploadInternal<R>>::upload_chunk(
+            let upload_channel =
+                <Dracoon<Connected> as UploadInternal<R>>::create_upload_channel::<'_, '_>(
                     self,
+                    file_upload_req,
+                )
+                .await
+                .map_err(|err| {
+                    error!("Error creating upload channel: {}", err);
+                    err
+                })?;
+
+            let s3_parts = self
+                .upload_s3_parts::<R, _>(
                     &upload_channel,
-                    chunk,
-                    url_part,
-                    cb,
+                    &mut crypto_reader,
+                    &upload_options,
+                    chunk_size,
+                    cloneable_callback.clone(),
                 )
                 .await?;
 
-                s3_parts.push(s3_part);
-            }
-
-            Err(err) => {
-                error!("Error reading file: {}", err);
-                return Err(DracoonClientError::IoError);
-            }
-        }
-
-        // finalize upload
-        let complete_upload_req = CompleteS3FileUploadRequest::builder(s3_parts)
-            .with_resolution_strategy(upload_options.resolution_strategy.unwrap_or_default())
-            .with_keep_share_links(upload_options.keep_share_links.unwrap_or(false))
-            .with_file_key(file_key)
-            .build();
-
-        <Dracoon<Connected> as UploadInternal<R>>::finalize_upload::<'_, '_>(
-            self,
-            upload_channel.upload_id.clone(),
-            complete_upload_req,
-        )
-        .await
-        .map_err(|err| {
-            error!("Error finalizing upload: {}", err);
-            err
-        })?;
+            // finalize upload
+            let complete_upload_req = CompleteS3FileUploadRequest::builder(s3_parts)
+                .with_resolution_strategy(
+                    upload_options
+                        .resolution_strategy
+                        .clone()
+                        .unwrap_or_default(),
+                )
+                .with_keep_share_links(upload_options.keep_share_links.unwrap_or(false))
+                .with_file_key(file_key.clone())
+                .build();
 
-        // get upload status
-        // return node if upload is done
-        // return error if upload failed
-        // polling with exponential backoff
-        let mut sleep_duration = POLLING_START_DELAY;
-        loop {
-            let status_response = <Dracoon<Connected> as UploadInternal<R>>::get_upload_status(
+            <Dracoon<Connected> as UploadInternal<R>>::finalize_upload::<'_, '_>(
                 self,
                 upload_channel.upload_id.clone(),
+                complete_upload_req,
             )
             .await
             .map_err(|err| {
-                error!("Error getting upload status: {}", err);
+                error!("Error finalizing upload: {}", err);
                 err
             })?;
 
-            match status_response.status {
-                S3UploadStatus::Done => {
-                    // fetch missing keys (limit 50)
-                    let missing_keys =
-                        <Dracoon<Connected> as UploadInternal<R>>::get_missing_file_keys(
-                            self,
-                            status_response
-                                .node
-                                .as_ref()
-                                .expect("Node must be set if status is done")
-                                .id,
-                        )
-                        .await
-                        .map_err(|err| {
-                            error!("Error getting missing file keys: {}", err);
-                            err
-                        })?;
-
-                    // encrypt plain file key for each user
-                    let key_reqs = missing_keys
-                        .users
-                        .into_iter()
-                        .flat_map::<Result<UserFileKeySetRequest, DracoonClientError>, _>(|user| {
-                            let user_id = user.id;
-                            let file_id = status_response
-                                .node
-                                .as_ref()
-                                .expect("Node must be set if status is done")
-                                .id;
-                            let public_key = user.public_key_container;
-                            let file_key = DracoonCrypto::encrypt_file_key(
-                                plain_file_key.clone(),
-                                public_key,
-                            )?;
-                            let set_key_req =
-                                UserFileKeySetRequest::new(user_id, file_id, file_key);
-                            Ok(set_key_req)
-                        })
-                        .collect::<Vec<_>>();
-
-                    drop(plain_file_key);
-                    // set file keys
-                    if !key_reqs.is_empty() {
-                        <Dracoon<Connected> as UploadInternal<R>>::set_file_keys(
-                            self,
-                            key_reqs.into(),
-                        )
-                        .await
-                        .map_err(|err| {
-                            error!("Error setting file keys: {}", err);
-                            err
-                        })?;
+            // poll the upload status until the upload is done or failed
+            let node = match self
+                .wait_for_upload_status::<R>(
+                    &upload_channel.upload_id,
+                    Duration::from_millis(UPLOAD_STATUS_TIMEOUT),
+                )
+                .await?
+            {
+                S3UploadOutcome::Done(node) => *node,
+                S3UploadOutcome::Failed(response) => {
+                    if attempt >= upload_options.upload_retries.unwrap_or(0) {
+                        return Err(DracoonClientError::Http(response));
                     }
 
-                    return Ok(status_response
-                        .node
-                        .expect("Node must be set if status is done"));
-                }
-                S3UploadStatus::Error => {
-                    return Err(DracoonClientError::Http(
-                        status_response
-                            .error_details
-                            .expect("Error message must be set if status is error"),
-                    ));
-                }
-                _ => {
-                    tokio::time::sleep(Duration::from_millis(sleep_duration)).await;
-                    sleep_duration *= 2;
+                    attempt += 1;
+                    continue 'upload;
                 }
+            };
+
+            // fetch missing keys (limit 50)
+            let missing_keys =
+                <Dracoon<Connected> as UploadInternal<R>>::get_missing_file_keys(self, node.id)
+                    .await
+                    .map_err(|err| {
+                        error!("Error getting missing file keys: {}", err);
+                        err
+                    })?;
+
+            // encrypt plain file key for each user
+            let key_reqs = missing_keys
+                .users
+                .into_iter()
+                .flat_map::<Result<UserFileKeySetRequest, DracoonClientError>, _>(|user| {
+                    let user_id = user.id;
+                    let file_id = node.id;
+                    let public_key = user.public_key_container;
+                    let file_key =
+                        DracoonCrypto::encrypt_file_key(plain_file_key.clone(), public_key)?;
+                    let set_key_req = UserFileKeySetRequest::new(user_id, file_id, file_key);
+                    Ok(set_key_req)
+                })
+                .collect::<Vec<_>>();
+
+            drop(plain_file_key);
+            // set file keys
+            if !key_reqs.is_empty() {
+                <Dracoon<Connected> as UploadInternal<R>>::set_file_keys(self, key_reqs.into())
+                    .await
+                    .map_err(|err| {
+                        error!("Error setting file keys: {}", err);
+                        err
+                    })?;
             }
+
+            return verify_uploaded_node(self, node, &upload_options).await;
         }
     }
 
@@ -851,10 +1220,26 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternal<R> for Dracoon
 }
 
 /// helper to calculate the number of S3 urls and the size of the last chunk
+/// panics if the chunk size is 0 or the chunk count exceeds the max. part number (u32)
+#[deprecated = "panics on invalid input - use calculate_upload_chunks instead"]
 pub fn calculate_s3_url_count(total_size: u64, chunk_size: u64) -> (u32, u64) {
+    calculate_upload_chunks(total_size, chunk_size).expect("overflow size to chunk")
+}
+
+/// helper to calculate the number of chunks and the size of the last chunk
+/// fails if the chunk size is 0 or the chunk count exceeds the max. part number (u32)
+pub fn calculate_upload_chunks(
+    total_size: u64,
+    chunk_size: u64,
+) -> Result<(u32, u64), DracoonClientError> {
+    if chunk_size == 0 {
+        error!("Invalid chunk size: 0");
+        return Err(DracoonClientError::InvalidChunkSize);
+    }
+
     // handle empty file first
     if total_size == 0 {
-        return (1, 0);
+        return Ok((1, 0));
     }
 
     let full_chunks = total_size / chunk_size;
@@ -864,16 +1249,216 @@ pub fn calculate_s3_url_count(total_size: u64, chunk_size: u64) -> (u32, u64) {
         total_size % chunk_size
     };
 
-    let url_count = if last_chunk_size == chunk_size {
+    let chunk_count = if last_chunk_size == chunk_size {
         full_chunks
     } else {
         full_chunks + 1
     };
 
-    (
-        url_count.try_into().expect("overflow size to chunk"),
-        last_chunk_size,
-    )
+    let chunk_count = chunk_count.try_into().map_err(|_| {
+        error!(
+            "Too many chunks for file of {} bytes (chunk size: {} bytes)",
+            total_size, chunk_size
+        );
+        DracoonClientError::FileTooLarge {
+            size: total_size,
+            max_size: u64::from(u32::MAX).saturating_mul(chunk_size),
+        }
+    })?;
+
+    Ok((chunk_count, last_chunk_size))
+}
+
+/// helper to calculate the number of S3 parts and the size of the last part
+/// fails if the part count exceeds the S3 limit (10,000 parts)
+pub(crate) fn calculate_s3_upload_parts(
+    total_size: u64,
+    chunk_size: u64,
+) -> Result<(u32, u64), DracoonClientError> {
+    let (part_count, last_part_size) = calculate_upload_chunks(total_size, chunk_size)?;
+
+    if u64::from(part_count) > S3_MAX_UPLOAD_PARTS {
+        error!(
+            "Too many S3 parts for file of {} bytes: {} (max. {})",
+            total_size, part_count, S3_MAX_UPLOAD_PARTS
+        );
+        return Err(DracoonClientError::FileTooLarge {
+            size: total_size,
+            max_size: S3_MAX_UPLOAD_PARTS.saturating_mul(chunk_size),
+        });
+    }
+
+    Ok((part_count, last_part_size))
+}
+
+/// helper to calculate the offset of a chunk (part numbers start at 1)
+/// computed in u64 - the offset of any chunk is below the file size
+pub(crate) fn calculate_chunk_offset(chunk_part: u32, chunk_size: usize) -> u64 {
+    u64::from(chunk_part.saturating_sub(1)) * chunk_size as u64
+}
+
+/// helper to resolve the chunk size of an S3 upload (max. 10,000 parts)
+/// increases the chunk size (rounded up to full MB) if the file would exceed the part limit
+/// and fails if the file is too large even at the max. part size
+pub(crate) fn resolve_s3_chunk_size(
+    total_size: u64,
+    chunk_size: usize,
+) -> Result<usize, DracoonClientError> {
+    resolve_s3_chunk_size_with_max(total_size, chunk_size, S3_MAX_UPLOAD_PART_SIZE)
+}
+
+/// helper to resolve the chunk size of an S3 upload with a max. part size (e.g. instance limit)
+/// the requested chunk size is clamped to the max. part size - fails if the chunk size is 0
+pub(crate) fn resolve_s3_chunk_size_with_max(
+    total_size: u64,
+    chunk_size: usize,
+    max_part_size: u64,
+) -> Result<usize, DracoonClientError> {
+    const MB: u64 = 1024 * 1024;
+
+    if chunk_size == 0 {
+        error!("Invalid chunk size: 0");
+        return Err(DracoonClientError::InvalidChunkSize);
+    }
+
+    let max_part_size = max_part_size.clamp(1, S3_MAX_UPLOAD_PART_SIZE);
+
+    let chunk_size = if chunk_size as u64 > max_part_size {
+        debug!(
+            "Clamped chunk size from {} to {} bytes (max. part size)",
+            chunk_size, max_part_size
+        );
+        // max. 5 GB - only fails on 32-bit targets
+        max_part_size.try_into().unwrap_or(usize::MAX)
+    } else {
+        chunk_size
+    };
+
+    if total_size.div_ceil(chunk_size as u64) <= S3_MAX_UPLOAD_PARTS {
+        return Ok(chunk_size);
+    }
+
+    let max_size = S3_MAX_UPLOAD_PARTS * max_part_size;
+    let resolved_chunk_size = total_size.div_ceil(S3_MAX_UPLOAD_PARTS).div_ceil(MB) * MB;
+
+    if resolved_chunk_size > max_part_size {
+        error!(
+            "File too large for S3 upload: {} bytes (max. {} bytes)",
+            total_size, max_size
+        );
+        return Err(DracoonClientError::FileTooLarge {
+            size: total_size,
+            max_size,
+        });
+    }
+
+    debug!(
+        "Increased chunk size from {} to {} bytes (max. {} parts)",
+        chunk_size, resolved_chunk_size, S3_MAX_UPLOAD_PARTS
+    );
+
+    // max. 5 GB - only fails on 32-bit targets
+    resolved_chunk_size
+        .try_into()
+        .map_err(|_| DracoonClientError::FileTooLarge {
+            size: total_size,
+            max_size,
+        })
+}
+
+/// helper to detect an expired upload (upload id / token) on a part request
+/// maps 404 Not Found and 410 Gone to `DracoonClientError::UploadSessionExpired`
+pub(crate) fn map_upload_session_expired(err: DracoonClientError) -> DracoonClientError {
+    match err {
+        DracoonClientError::Http(response) if response.is_not_found() || response.is_gone() => {
+            error!("Upload session expired: {}", response);
+            DracoonClientError::UploadSessionExpired(response)
+        }
+        err => err,
+    }
+}
+
+/// helper to read a chunk of the given size
+async fn read_chunk<T: AsyncRead + Unpin>(
+    reader: &mut T,
+    chunk_len: u64,
+) -> Result<bytes::Bytes, DracoonClientError> {
+    let mut buffer = vec![
+        0;
+        chunk_len
+            .try_into()
+            .map_err(|_| DracoonClientError::IoError)?
+    ];
+
+    reader.read_exact(&mut buffer).await.map_err(|err| {
+        error!("Error reading file: {}", err);
+        DracoonClientError::IoError
+    })?;
+
+    Ok(bytes::Bytes::from(buffer))
+}
+
+/// final state of an S3 upload (see `wait_for_upload_status`)
+enum S3UploadOutcome {
+    Done(Box<Node>),
+    Failed(DracoonErrorResponse),
+}
+
+/// rewinds a reader to the start to retry an upload
+type ReaderRewind<R> = for<'a> fn(&'a mut BufReader<R>) -> BoxFuture<'a, std::io::Result<()>>;
+
+/// helper to rewind a seekable reader to the start for a retry
+fn rewind_seekable<R: AsyncRead + AsyncSeek + Send + Unpin>(
+    reader: &mut BufReader<R>,
+) -> BoxFuture<'_, std::io::Result<()>> {
+    Box::pin(async move { reader.rewind().await.map(|_| ()) })
+}
+
+/// helper to verify an uploaded node (if enabled via upload options)
+/// re-fetches the node and compares size and hash (if a content hash was passed)
+/// the hash is not compared for encrypted nodes (the node hash is not the hash of the plain content)
+async fn verify_uploaded_node(
+    dracoon: &Dracoon<Connected>,
+    node: Node,
+    upload_options: &UploadOptions,
+) -> Result<Node, DracoonClientError> {
+    if !upload_options.post_upload_verification.unwrap_or(false) {
+        return Ok(node);
+    }
+
+    let node = dracoon.nodes().get_node(node.id).await?;
+
+    let expected_size = upload_options.file_meta.size;
+    if node.size != Some(expected_size) {
+        error!(
+            "Upload verification failed: size mismatch (expected {}, got {:?})",
+            expected_size, node.size
+        );
+        return Err(DracoonClientError::UploadVerificationFailed {
+            node_id: node.id,
+            reason: format!(
+                "size mismatch (expected {expected_size}, got {})",
+                node.size
+                    .map_or_else(|| "none".to_string(), |size| size.to_string())
+            ),
+        });
+    }
+
+    if node.is_encrypted.unwrap_or(false) {
+        return Ok(node);
+    }
+
+    if let (Some(expected_hash), Some(hash)) = (&upload_options.content_hash, &node.hash) {
+        if !expected_hash.eq_ignore_ascii_case(hash) {
+            error!("Upload verification failed: hash mismatch");
+            return Err(DracoonClientError::UploadVerificationFailed {
+                node_id: node.id,
+                reason: "hash mismatch".to_string(),
+            });
+        }
+    }
+
+    Ok(node)
 }
 
 #[async_trait]
@@ -906,7 +1491,7 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternalNfs<R, Connecte
             err
         })?;
 
-        let (count_chunks, last_chunk_size) = calculate_s3_url_count(fm.size, chunk_size as u64);
+        let (count_chunks, last_chunk_size) = calculate_upload_chunks(fm.size, chunk_size as u64)?;
         let mut chunk_part: u32 = 1;
 
         let cloneable_callback = callback.map(CloneableUploadProgressCallback::new);
@@ -927,9 +1512,7 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternalNfs<R, Connecte
 
                         let url = upload_channel.upload_url.clone();
 
-                        // truncation is safe because chunk_size is 32 MB
-                        #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
-                        let curr_pos: u64 = ((chunk_part - 1) * (chunk_size as u32)) as u64;
+                        let curr_pos = calculate_chunk_offset(chunk_part, chunk_size);
 
                         self.upload_stream_to_nfs(
                             Box::pin(stream),
@@ -966,7 +1549,7 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternalNfs<R, Connecte
 
                 let url = upload_channel.upload_url.clone();
 
-                let curr_pos: u64 = (chunk_part - 1) as u64 * (DEFAULT_UPLOAD_CHUNK_SIZE as u64);
+                let curr_pos = calculate_chunk_offset(chunk_part, chunk_size);
 
                 self.upload_stream_to_nfs(
                     Box::pin(stream),
@@ -985,7 +1568,12 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternalNfs<R, Connecte
 
         // finalize upload
         let complete_upload_req = CompleteUploadRequest::builder()
-            .with_resolution_strategy(upload_options.resolution_strategy.unwrap_or_default())
+            .with_resolution_strategy(
+                upload_options
+                    .resolution_strategy
+                    .clone()
+                    .unwrap_or_default(),
+            )
             .with_keep_share_links(upload_options.keep_share_links.unwrap_or(false))
             .build();
 
@@ -999,7 +1587,7 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternalNfs<R, Connecte
             err
         })?;
 
-        Ok(node)
+        verify_uploaded_node(self, node, &upload_options).await
     }
 
     async fn upload_to_nfs_encrypted(
@@ -1069,7 +1657,7 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternalNfs<R, Connecte
             err
         })?;
 
-        let (count_chunks, last_chunk_size) = calculate_s3_url_count(fm.size, chunk_size as u64);
+        let (count_chunks, last_chunk_size) = calculate_upload_chunks(fm.size, chunk_size as u64)?;
         let mut chunk_part: u32 = 1;
 
         let cloneable_callback = callback.map(CloneableUploadProgressCallback::new);
@@ -1091,7 +1679,7 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternalNfs<R, Connecte
 
                         let url = upload_channel.upload_url.clone();
 
-                        let curr_pos: u64 = (chunk_part - 1) as u64 * (chunk_size as u64);
+                        let curr_pos = calculate_chunk_offset(chunk_part, chunk_size);
 
                         self.upload_stream_to_nfs(
                             Box::pin(stream),
@@ -1129,9 +1717,7 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternalNfs<R, Connecte
 
                 let url = upload_channel.upload_url.clone();
 
-                // truncation is safe because chunk_size is 32 MB
-                #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
-                let curr_pos: u64 = ((chunk_part - 1) * (DEFAULT_UPLOAD_CHUNK_SIZE as u32)) as u64;
+                let curr_pos = calculate_chunk_offset(chunk_part, chunk_size);
 
                 self.upload_stream_to_nfs(
                     Box::pin(stream),
@@ -1155,7 +1741,12 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternalNfs<R, Connecte
 
         // finalize upload
         let complete_upload_req = CompleteUploadRequest::builder()
-            .with_resolution_strategy(upload_options.resolution_strategy.unwrap_or_default())
+            .with_resolution_strategy(
+                upload_options
+                    .resolution_strategy
+                    .clone()
+                    .unwrap_or_default(),
+            )
             .with_keep_share_links(upload_options.keep_share_links.unwrap_or(false))
             .with_file_key(file_key)
             .build();
@@ -1204,7 +1795,7 @@ impl<R: AsyncRead + Sync + Send + Unpin + 'static> UploadInternalNfs<R, Connecte
                 })?;
         }
 
-        Ok(node)
+        verify_uploaded_node(self, node, &upload_options).await
     }
 
     async fn finalize_nfs_upload(
@@ -1235,6 +1826,7 @@ mod tests {
     use std::sync::Arc;
 
     use dco3_crypto::FileKeyVersion;
+    use secrecy::{ExposeSecret, SecretString};
 
     use crate::nodes::FileMeta;
     use crate::tests::dracoon::get_connected_client;
@@ -1272,20 +1864,89 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_create_s3_upload_urls() {
+    async fn test_create_upload_channel_with_default_timestamps() {
         let (client, mut mock_server) = get_connected_client().await;
 
-        let s3_urls_res = include_str!("../tests/responses/upload/s3_urls_ok.json");
+        let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
 
-        let s3_urls_mock = mock_server
-            .mock("POST", "/api/v4/nodes/files/uploads/123/s3_urls")
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex(r#""timestampCreation":"\d{4}-"#.into()),
+                mockito::Matcher::Regex(r#""timestampModification":"\d{4}-"#.into()),
+            ]))
             .with_status(200)
-            .with_body(s3_urls_res)
+            .with_body(channel_res)
             .with_header("content-type", "application/json")
             .create();
 
-        let s3_urls_req = GeneratePresignedUrlsRequest::new(123456, 1, 1);
-
+        let file_meta = FileMeta::builder("test", 16).build();
+        let upload_options = UploadOptions::builder(file_meta)
+            .with_default_timestamps(true)
+            .build();
+
+        assert!(upload_options.file_meta.timestamp_creation.is_some());
+        assert!(upload_options.file_meta.timestamp_modification.is_some());
+
+        let channel_req = CreateFileUploadRequest::from_upload_options(123, &upload_options, None);
+
+        <Dracoon<Connected> as UploadInternal<BufReader<&[u8]>>>::create_upload_channel(
+            &client,
+            channel_req,
+        )
+        .await
+        .unwrap();
+
+        upload_channel_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_create_s3_upload_urls_upload_session_expired() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let error_res = include_str!("../tests/responses/error_gone.json");
+
+        let s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads/123/s3_urls")
+            .with_status(410)
+            .with_body(error_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let s3_urls_req = GeneratePresignedUrlsRequest::new(123456, 2, 2);
+
+        let err = <Dracoon<Connected> as UploadInternal<BufReader<&[u8]>>>::create_s3_upload_urls(
+            &client,
+            "123".into(),
+            s3_urls_req,
+        )
+        .await
+        .unwrap_err();
+
+        s3_urls_mock.assert();
+
+        assert!(err.is_upload_session_expired());
+        let DracoonClientError::UploadSessionExpired(response) = err else {
+            panic!("expected upload session expired error");
+        };
+        assert!(response.is_gone());
+    }
+
+    #[tokio::test]
+    async fn test_create_s3_upload_urls() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let s3_urls_res = include_str!("../tests/responses/upload/s3_urls_ok.json");
+
+        let s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads/123/s3_urls")
+            .with_status(200)
+            .with_body(s3_urls_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let s3_urls_req = GeneratePresignedUrlsRequest::new(123456, 1, 1);
+
         let s3_urls =
             <Dracoon<Connected> as UploadInternal<BufReader<&[u8]>>>::create_s3_upload_urls(
                 &client,
@@ -1560,21 +2221,145 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_upload_to_s3_unencrypted_no_content() {
+    async fn test_upload_to_s3_unencrypted_concurrent_parts() {
         let (client, mut mock_server) = get_connected_client().await;
 
         let parent_node: Node =
             serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
 
-        // test 0KB file
-        let mock_bytes: Vec<u8> = vec![];
+        let mock_bytes: Vec<u8> = vec![
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
 
         let reader = Cursor::new(mock_bytes);
         let reader_clone = BufReader::new(reader);
 
-        let file_meta = FileMeta::builder("test", 0).build();
+        let file_meta = FileMeta::builder("test", 16).build();
 
-        let upload_options = UploadOptions::builder(file_meta).build();
+        let upload_options = UploadOptions::builder(file_meta)
+            .with_upload_concurrency(3)
+            .build();
+
+        // mock upload channel
+        let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
+
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads")
+            .with_status(201)
+            .with_body(channel_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        // mock S3 urls and uploads (one url and etag per part)
+        let mut part_mocks = Vec::new();
+        for part in 1..=4 {
+            let s3_urls_response = format!(
+                r#"{{"urls":[{{"url":"{}upload_url/{part}","partNumber":{part}}}]}}"#,
+                client.get_base_url()
+            );
+
+            let s3_urls_mock = mock_server
+                .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
+                .match_body(mockito::Matcher::PartialJsonString(format!(
+                    r#"{{"firstPartNumber":{part},"lastPartNumber":{part}}}"#
+                )))
+                .with_status(201)
+                .with_body(s3_urls_response)
+                .with_header("content-type", "application/json")
+                .create();
+
+            let upload_mock = mock_server
+                .mock("PUT", format!("/upload_url/{part}").as_str())
+                .with_status(202)
+                .with_header("etag", format!("etag-{part}").as_str())
+                .create();
+
+            part_mocks.push((s3_urls_mock, upload_mock));
+        }
+
+        // mock finalize upload (parts sorted by part number)
+        let finalize_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
+            .match_body(mockito::Matcher::Regex(
+                r#""parts":\[\{"partNumber":1,"partEtag":"etag-1"\},\{"partNumber":2,"partEtag":"etag-2"\},\{"partNumber":3,"partEtag":"etag-3"\},\{"partNumber":4,"partEtag":"etag-4"\}\]"#
+                    .into(),
+            ))
+            .with_status(202)
+            .create();
+
+        // mock upload status
+        let status_res = include_str!("../tests/responses/upload/upload_status_ok.json");
+        let status_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads/string")
+            .with_status(200)
+            .with_body(status_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let arc_processed = Arc::new(AtomicU64::new(0));
+        let cloned_processed = arc_processed.clone();
+
+        let callback = move |processed: u64, _total: u64| {
+            cloned_processed.fetch_add(processed, std::sync::atomic::Ordering::Relaxed);
+        };
+
+        let node =
+            <Dracoon<Connected> as UploadInternal<Cursor<Vec<u8>>>>::upload_to_s3_unencrypted(
+                &client,
+                &parent_node,
+                upload_options,
+                reader_clone,
+                Some(Box::new(callback)),
+                Some(4),
+            )
+            .await
+            .unwrap();
+
+        upload_channel_mock.assert();
+        for (s3_urls_mock, upload_mock) in part_mocks {
+            s3_urls_mock.assert();
+            upload_mock.assert();
+        }
+        finalize_mock.assert();
+        status_mock.assert();
+
+        assert_eq!(node.id, 2);
+        assert_eq!(arc_processed.load(std::sync::atomic::Ordering::Relaxed), 16);
+    }
+
+    // MD5 of "hello world"
+    const HELLO_WORLD_MD5: &str = "5eb63bbbe01eeed093cb22bb8f5acdc3";
+
+    #[test]
+    fn test_create_upload_request_without_content_hash() {
+        let file_meta = FileMeta::builder("test", 16).build();
+        let upload_options = UploadOptions::builder(file_meta)
+            .with_content_hash("d41d8cd98f00b204e9800998ecf8427e")
+            .build();
+
+        let channel_req = CreateFileUploadRequest::from_upload_options(123, &upload_options, None);
+        let channel_req = serde_json::to_value(channel_req).unwrap();
+
+        // the expected hash is only used for the post-upload verification
+        assert!(channel_req.get("contentHash").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_upload_to_s3_unencrypted_with_content_hash() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let parent_node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+
+        let reader = Cursor::new(b"hello world".to_vec());
+        let reader_clone = BufReader::new(reader);
+
+        let file_meta = FileMeta::builder("test", 11).build();
+
+        let upload_options = UploadOptions::builder(file_meta)
+            .with_content_hash(HELLO_WORLD_MD5)
+            .with_post_upload_verification(true)
+            .build();
 
         // mock upload channel
         let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
@@ -1595,18 +2380,116 @@ mod tests {
         let s3_urls_mock = mock_server
             .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
             .with_status(201)
-            .with_body(s3_urls_response.clone())
+            .with_body(s3_urls_response)
             .with_header("content-type", "application/json")
+            .expect(3)
             .create();
 
-        let upload_res =
-            serde_json::from_str::<PresignedUrlList>(s3_urls_response.as_str()).unwrap();
+        // mock upload to S3 (3 parts)
+        let upload_mock = mock_server
+            .mock("PUT", "/upload_url")
+            .with_status(202)
+            .with_header("etag", "string")
+            .expect(3)
+            .create();
 
-        // mock upload to S3
+        // mock finalize upload
+        let finalize_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
+            .with_status(202)
+            .create();
+
+        // mock upload status
+        let status_res = include_str!("../tests/responses/upload/upload_status_ok.json");
+        let status_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads/string")
+            .with_status(200)
+            .with_body(status_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        // the stored node matches the content hash (hex is compared case-insensitively)
+        let node_res = include_str!("../tests/responses/nodes/node_ok.json").replace(
+            r#""size": 16"#,
+            &format!(
+                r#""size": 11, "hash": "{}""#,
+                HELLO_WORLD_MD5.to_uppercase()
+            ),
+        );
+        let node_mock = mock_server
+            .mock("GET", "/api/v4/nodes/2")
+            .with_status(200)
+            .with_body(node_res)
+            .with_header("content-type", "application/json")
+            .expect(1)
+            .create();
+
+        <Dracoon<Connected> as UploadInternal<Cursor<Vec<u8>>>>::upload_to_s3_unencrypted(
+            &client,
+            &parent_node,
+            upload_options,
+            reader_clone,
+            None,
+            Some(4),
+        )
+        .await
+        .unwrap();
+
+        upload_channel_mock.assert();
+        s3_urls_mock.assert();
+        upload_mock.assert();
+        finalize_mock.assert();
+        status_mock.assert();
+        node_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_upload_to_s3_unencrypted_content_hash_mismatch() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let parent_node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+
+        let reader = Cursor::new(b"hello world".to_vec());
+        let reader_clone = BufReader::new(reader);
+
+        let file_meta = FileMeta::builder("test", 11).build();
+
+        let upload_options = UploadOptions::builder(file_meta)
+            .with_content_hash(HELLO_WORLD_MD5)
+            .with_post_upload_verification(true)
+            .build();
+
+        // mock upload channel
+        let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
+
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads")
+            .with_status(201)
+            .with_body(channel_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        // mock S3 urls
+        let s3_urls_response =
+            include_str!("../tests/responses/upload/s3_urls_ok_with_placeholder.json");
+        let s3_urls_response =
+            s3_urls_response.replace("$base_url/", client.get_base_url().as_str());
+
+        let s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
+            .with_status(201)
+            .with_body(s3_urls_response)
+            .with_header("content-type", "application/json")
+            .expect(3)
+            .create();
+
+        // mock upload to S3 (3 parts)
         let upload_mock = mock_server
             .mock("PUT", "/upload_url")
             .with_status(202)
             .with_header("etag", "string")
+            .expect(3)
             .create();
 
         // mock finalize upload
@@ -1624,41 +2507,57 @@ mod tests {
             .with_header("content-type", "application/json")
             .create();
 
-        let node =
+        // the stored node does not match the content hash
+        let node_res = include_str!("../tests/responses/nodes/node_ok.json")
+            .replace(r#""size": 16"#, r#""size": 11, "hash": "other""#);
+        let node_mock = mock_server
+            .mock("GET", "/api/v4/nodes/2")
+            .with_status(200)
+            .with_body(node_res)
+            .with_header("content-type", "application/json")
+            .expect(1)
+            .create();
+
+        let err =
             <Dracoon<Connected> as UploadInternal<Cursor<Vec<u8>>>>::upload_to_s3_unencrypted(
                 &client,
                 &parent_node,
                 upload_options,
                 reader_clone,
                 None,
-                None,
+                Some(4),
             )
             .await
-            .unwrap();
+            .unwrap_err();
 
         upload_channel_mock.assert();
         s3_urls_mock.assert();
         upload_mock.assert();
         finalize_mock.assert();
         status_mock.assert();
+        node_mock.assert();
+
+        assert_eq!(
+            err,
+            DracoonClientError::UploadVerificationFailed {
+                node_id: 2,
+                reason: "hash mismatch".to_string(),
+            }
+        );
     }
 
     #[tokio::test]
-    async fn test_upload_to_s3_encrypted() {
+    async fn test_upload_to_s3_encrypted_with_content_hash() {
         let (client, mut mock_server) = get_connected_client().await;
 
         let parent_node: Node =
             serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
 
-        let mock_bytes: Vec<u8> = vec![
-            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
-        ];
-
-        let reader = Cursor::new(mock_bytes);
+        let reader = Cursor::new(b"hello world".to_vec());
         let reader_clone = BufReader::new(reader);
 
         let keypair =
-            DracoonCrypto::create_plain_user_keypair(dco3_crypto::UserKeyPairVersion::RSA4096)
+            DracoonCrypto::create_plain_user_keypair(dco3_crypto::UserKeyPairVersion::RSA2048)
                 .unwrap();
         let enc_keypair =
             DracoonCrypto::encrypt_private_key("TopSecret1234!", keypair.clone()).unwrap();
@@ -1678,9 +2577,12 @@ mod tests {
 
         keypair_mock.assert();
 
-        let file_meta = FileMeta::builder("test", 16).build();
+        let file_meta = FileMeta::builder("test", 11).build();
 
-        let upload_options = UploadOptions::builder(file_meta).build();
+        let upload_options = UploadOptions::builder(file_meta)
+            .with_content_hash(HELLO_WORLD_MD5)
+            .with_post_upload_verification(true)
+            .build();
 
         // mock upload channel
         let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
@@ -1701,13 +2603,10 @@ mod tests {
         let s3_urls_mock = mock_server
             .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
             .with_status(201)
-            .with_body(s3_urls_response.clone())
+            .with_body(s3_urls_response)
             .with_header("content-type", "application/json")
             .create();
 
-        let upload_res =
-            serde_json::from_str::<PresignedUrlList>(s3_urls_response.as_str()).unwrap();
-
         // mock upload to S3
         let upload_mock = mock_server
             .mock("PUT", "/upload_url")
@@ -1730,6 +2629,18 @@ mod tests {
             .with_header("content-type", "application/json")
             .create();
 
+        // the hash of an encrypted node is not compared (it is not the hash of the plain content)
+        let node_res = include_str!("../tests/responses/nodes/node_ok.json")
+            .replace(r#""size": 16"#, r#""size": 11, "hash": "other""#)
+            .replace(r#""isEncrypted": false"#, r#""isEncrypted": true"#);
+        let node_mock = mock_server
+            .mock("GET", "/api/v4/nodes/2")
+            .with_status(200)
+            .with_body(node_res)
+            .with_header("content-type", "application/json")
+            .expect(1)
+            .create();
+
         // mock missing file keys
         let missing_keys = include_str!("../tests/responses/nodes/missing_file_keys_empty_ok.json");
         let keys_mock = mock_server
@@ -1739,7 +2650,7 @@ mod tests {
             .with_header("content-type", "application/json")
             .create();
 
-        let node = <Dracoon<Connected> as UploadInternal<Cursor<Vec<u8>>>>::upload_to_s3_encrypted(
+        <Dracoon<Connected> as UploadInternal<Cursor<Vec<u8>>>>::upload_to_s3_encrypted(
             &client,
             &parent_node,
             upload_options,
@@ -1755,45 +2666,1021 @@ mod tests {
         upload_mock.assert();
         finalize_mock.assert();
         status_mock.assert();
+        node_mock.assert();
         keys_mock.assert();
     }
 
     #[tokio::test]
-    async fn test_upload_to_s3_encrypted_no_content() {
+    async fn test_upload_to_s3_unencrypted_part_failure() {
         let (client, mut mock_server) = get_connected_client().await;
 
         let parent_node: Node =
             serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
 
-        // empty file
-        let mock_bytes: Vec<u8> = vec![];
+        let reader = BufReader::new(Cursor::new(vec![0u8; 16]));
 
-        let reader = Cursor::new(mock_bytes);
-        let reader_clone = BufReader::new(reader);
+        let file_meta = FileMeta::builder("test", 16).build();
+        let upload_options = UploadOptions::builder(file_meta).build();
 
-        let keypair =
-            DracoonCrypto::create_plain_user_keypair(dco3_crypto::UserKeyPairVersion::RSA4096)
-                .unwrap();
-        let enc_keypair =
-            DracoonCrypto::encrypt_private_key("TopSecret1234!", keypair.clone()).unwrap();
-        let enc_keypair_json = serde_json::to_string(&enc_keypair).unwrap();
+        let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads")
+            .with_status(201)
+            .with_body(channel_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let s3_urls_response =
+            include_str!("../tests/responses/upload/s3_urls_ok_with_placeholder.json");
+        let s3_urls_response =
+            s3_urls_response.replace("$base_url/", client.get_base_url().as_str());
+
+        let s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
+            .with_status(201)
+            .with_body(s3_urls_response)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let upload_mock = mock_server
+            .mock("PUT", "/upload_url")
+            .with_status(503)
+            .with_header("content-type", "application/xml")
+            .with_body(
+                "<Error><Code>SlowDown</Code><Message>Please reduce your request rate.</Message></Error>",
+            )
+            .create();
+
+        let finalize_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
+            .expect(0)
+            .create();
+
+        let err =
+            <Dracoon<Connected> as UploadInternal<Cursor<Vec<u8>>>>::upload_to_s3_unencrypted(
+                &client,
+                &parent_node,
+                upload_options,
+                reader,
+                None,
+                None,
+            )
+            .await
+            .unwrap_err();
+
+        assert!(err.is_s3_error());
+
+        let DracoonClientError::S3Upload {
+            part_number,
+            status,
+            error,
+        } = err
+        else {
+            panic!("Expected S3 upload error");
+        };
+
+        assert_eq!(part_number, 1);
+        assert_eq!(status, reqwest::StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(error.code(), Some("SlowDown"));
+
+        upload_channel_mock.assert();
+        s3_urls_mock.assert();
+        upload_mock.assert();
+        finalize_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_upload_to_s3_unencrypted_post_upload_verification_size_mismatch() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let parent_node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+
+        let mock_bytes: Vec<u8> = vec![
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+
+        let reader = BufReader::new(Cursor::new(mock_bytes));
+
+        let file_meta = FileMeta::builder("test", 16).build();
+
+        let upload_options = UploadOptions::builder(file_meta)
+            .with_post_upload_verification(true)
+            .build();
+
+        let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
+
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads")
+            .with_status(201)
+            .with_body(channel_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let s3_urls_response =
+            include_str!("../tests/responses/upload/s3_urls_ok_with_placeholder.json");
+        let s3_urls_response =
+            s3_urls_response.replace("$base_url/", client.get_base_url().as_str());
+
+        let s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
+            .with_status(201)
+            .with_body(s3_urls_response)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let upload_mock = mock_server
+            .mock("PUT", "/upload_url")
+            .with_status(202)
+            .with_header("etag", "string")
+            .create();
+
+        let finalize_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
+            .with_status(202)
+            .create();
+
+        let status_res = include_str!("../tests/responses/upload/upload_status_ok.json");
+        let status_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads/string")
+            .with_status(200)
+            .with_body(status_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        // stored node is smaller than the uploaded content
+        let node_res = include_str!("../tests/responses/nodes/node_ok.json")
+            .replace(r#""size": 16"#, r#""size": 15"#);
+        let node_mock = mock_server
+            .mock("GET", "/api/v4/nodes/2")
+            .with_status(200)
+            .with_body(node_res)
+            .with_header("content-type", "application/json")
+            .expect(1)
+            .create();
+
+        let res =
+            <Dracoon<Connected> as UploadInternal<Cursor<Vec<u8>>>>::upload_to_s3_unencrypted(
+                &client,
+                &parent_node,
+                upload_options,
+                reader,
+                None,
+                None,
+            )
+            .await;
+
+        upload_channel_mock.assert();
+        s3_urls_mock.assert();
+        upload_mock.assert();
+        finalize_mock.assert();
+        status_mock.assert();
+        node_mock.assert();
+
+        let err = res.unwrap_err();
+        assert!(err.is_upload_verification_failed());
+        assert_eq!(
+            err,
+            DracoonClientError::UploadVerificationFailed {
+                node_id: 2,
+                reason: "size mismatch (expected 16, got 15)".to_string(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upload_to_s3_unencrypted_no_content() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let parent_node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+
+        // test 0KB file
+        let mock_bytes: Vec<u8> = vec![];
+
+        let reader = Cursor::new(mock_bytes);
+        let reader_clone = BufReader::new(reader);
+
+        let file_meta = FileMeta::builder("test", 0).build();
+
+        let upload_options = UploadOptions::builder(file_meta).build();
+
+        // mock upload channel
+        let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
+
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads")
+            .with_status(201)
+            .with_body(channel_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        // mock S3 urls
+        let s3_urls_response =
+            include_str!("../tests/responses/upload/s3_urls_ok_with_placeholder.json");
+        let s3_urls_response =
+            s3_urls_response.replace("$base_url/", client.get_base_url().as_str());
+
+        let s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
+            .with_status(201)
+            .with_body(s3_urls_response.clone())
+            .with_header("content-type", "application/json")
+            .create();
+
+        let upload_res =
+            serde_json::from_str::<PresignedUrlList>(s3_urls_response.as_str()).unwrap();
+
+        // mock upload to S3
+        let upload_mock = mock_server
+            .mock("PUT", "/upload_url")
+            .with_status(202)
+            .with_header("etag", "string")
+            .create();
+
+        // mock finalize upload
+        let finalize_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
+            .with_status(202)
+            .create();
+
+        // mock upload status
+        let status_res = include_str!("../tests/responses/upload/upload_status_ok.json");
+        let status_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads/string")
+            .with_status(200)
+            .with_body(status_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let node =
+            <Dracoon<Connected> as UploadInternal<Cursor<Vec<u8>>>>::upload_to_s3_unencrypted(
+                &client,
+                &parent_node,
+                upload_options,
+                reader_clone,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        upload_channel_mock.assert();
+        s3_urls_mock.assert();
+        upload_mock.assert();
+        finalize_mock.assert();
+        status_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_upload_to_s3_unencrypted_retry_on_finalization_error() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let parent_node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+
+        let mock_bytes: Vec<u8> = vec![
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+
+        let reader = Cursor::new(mock_bytes);
+        let reader_clone = BufReader::new(reader);
+
+        let file_meta = FileMeta::builder("test", 16).build();
+
+        let upload_options = UploadOptions::builder(file_meta)
+            .with_upload_retries(1)
+            .build();
+
+        // mock upload channel
+        let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
+
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads")
+            .with_status(201)
+            .with_body(channel_res)
+            .with_header("content-type", "application/json")
+            .expect(2)
+            .create();
+
+        // mock S3 urls
+        let s3_urls_response =
+            include_str!("../tests/responses/upload/s3_urls_ok_with_placeholder.json");
+        let s3_urls_response =
+            s3_urls_response.replace("$base_url/", client.get_base_url().as_str());
+
+        let s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
+            .with_status(201)
+            .with_body(s3_urls_response.clone())
+            .with_header("content-type", "application/json")
+            .expect(2)
+            .create();
+
+        // mock upload to S3
+        let upload_mock = mock_server
+            .mock("PUT", "/upload_url")
+            .with_status(202)
+            .with_header("etag", "string")
+            .expect(2)
+            .create();
+
+        // mock finalize upload
+        let finalize_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
+            .with_status(202)
+            .expect(2)
+            .create();
+
+        // mock upload status (first attempt fails, second succeeds)
+        let status_error_res =
+            include_str!("../tests/responses/upload/upload_status_error_ok.json");
+        let status_error_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads/string")
+            .with_status(200)
+            .with_body(status_error_res)
+            .with_header("content-type", "application/json")
+            .expect(1)
+            .create();
+
+        let status_res = include_str!("../tests/responses/upload/upload_status_ok.json");
+        let status_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads/string")
+            .with_status(200)
+            .with_body(status_res)
+            .with_header("content-type", "application/json")
+            .expect(1)
+            .create();
+
+        let node = <Dracoon<Connected> as UploadInternal<Cursor<Vec<u8>>>>::upload_to_s3_unencrypted_with_rewind(
+            &client,
+            &parent_node,
+            upload_options,
+            reader_clone,
+            None,
+            None,
+            Some(rewind_seekable::<Cursor<Vec<u8>>>),
+        )
+        .await
+        .unwrap();
+
+        upload_channel_mock.assert();
+        s3_urls_mock.assert();
+        upload_mock.assert();
+        finalize_mock.assert();
+        status_error_mock.assert();
+        status_mock.assert();
+
+        assert_eq!(node.id, 2);
+    }
+
+    #[tokio::test]
+    async fn test_upload_to_s3_unencrypted_finalization_already_completed() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let parent_node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+
+        let mock_bytes: Vec<u8> = vec![
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+
+        let reader = Cursor::new(mock_bytes);
+        let reader_clone = BufReader::new(reader);
+
+        let file_meta = FileMeta::builder("test", 16).build();
+
+        let upload_options = UploadOptions::builder(file_meta).build();
+
+        let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads")
+            .with_status(201)
+            .with_body(channel_res)
+            .with_header("content-type", "application/json")
+            .expect(1)
+            .create();
+
+        let s3_urls_response =
+            include_str!("../tests/responses/upload/s3_urls_ok_with_placeholder.json");
+        let s3_urls_response =
+            s3_urls_response.replace("$base_url/", client.get_base_url().as_str());
+
+        let _s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
+            .with_status(201)
+            .with_body(s3_urls_response)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let _upload_mock = mock_server
+            .mock("PUT", "/upload_url")
+            .with_status(202)
+            .with_header("etag", "string")
+            .create();
+
+        // first completion fails with 500 (retried), the retry is rejected as already completed
+        let finalize_error_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
+            .with_status(500)
+            .expect(1)
+            .create();
+
+        let finalize_completed_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"code": 400, "message": "Upload already completed", "debugInfo": "Upload already completed"}"#,
+            )
+            .expect(1)
+            .create();
+
+        // status is checked once after the rejected completion and once when polling
+        let status_res = include_str!("../tests/responses/upload/upload_status_ok.json");
+        let status_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads/string")
+            .with_status(200)
+            .with_body(status_res)
+            .with_header("content-type", "application/json")
+            .expect(2)
+            .create();
+
+        let node =
+            <Dracoon<Connected> as UploadInternal<Cursor<Vec<u8>>>>::upload_to_s3_unencrypted(
+                &client,
+                &parent_node,
+                upload_options,
+                reader_clone,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        upload_channel_mock.assert();
+        finalize_error_mock.assert();
+        finalize_completed_mock.assert();
+        status_mock.assert();
+
+        assert_eq!(node.id, 2);
+    }
+
+    #[tokio::test]
+    async fn test_finalize_upload_error_not_completed() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let error_res = include_str!("../tests/responses/error_conflict.json");
+
+        let finalize_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
+            .with_status(409)
+            .with_header("content-type", "application/json")
+            .with_body(error_res)
+            .create();
+
+        // upload was not completed (still in transfer)
+        let status_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads/string")
+            .with_status(200)
+            .with_body(r#"{"status": "transfer"}"#)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let complete_upload_req =
+            CompleteS3FileUploadRequest::builder(vec![S3FileUploadPart::new(1, "123".into())])
+                .build();
+
+        let err = <Dracoon<Connected> as UploadInternal<Cursor<Vec<u8>>>>::finalize_upload(
+            &client,
+            "string".into(),
+            complete_upload_req,
+        )
+        .await
+        .unwrap_err();
+
+        finalize_mock.assert();
+        status_mock.assert();
+
+        assert!(err.is_conflict());
+    }
+
+    #[tokio::test]
+    async fn test_upload_to_s3_unencrypted_finalization_error_without_retries() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let parent_node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+
+        let mock_bytes: Vec<u8> = vec![
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+
+        let reader = Cursor::new(mock_bytes);
+        let reader_clone = BufReader::new(reader);
+
+        let file_meta = FileMeta::builder("test", 16).build();
+
+        let upload_options = UploadOptions::builder(file_meta).build();
+
+        let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads")
+            .with_status(201)
+            .with_body(channel_res)
+            .with_header("content-type", "application/json")
+            .expect(1)
+            .create();
+
+        let s3_urls_response =
+            include_str!("../tests/responses/upload/s3_urls_ok_with_placeholder.json");
+        let s3_urls_response =
+            s3_urls_response.replace("$base_url/", client.get_base_url().as_str());
+
+        let _s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
+            .with_status(201)
+            .with_body(s3_urls_response)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let _upload_mock = mock_server
+            .mock("PUT", "/upload_url")
+            .with_status(202)
+            .with_header("etag", "string")
+            .create();
+
+        let _finalize_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
+            .with_status(202)
+            .create();
+
+        let status_error_res =
+            include_str!("../tests/responses/upload/upload_status_error_ok.json");
+        let _status_error_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads/string")
+            .with_status(200)
+            .with_body(status_error_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let err =
+            <Dracoon<Connected> as UploadInternal<Cursor<Vec<u8>>>>::upload_to_s3_unencrypted(
+                &client,
+                &parent_node,
+                upload_options,
+                reader_clone,
+                None,
+                None,
+            )
+            .await
+            .unwrap_err();
+
+        upload_channel_mock.assert();
+        assert!(err.is_http_error());
+    }
+
+    #[tokio::test]
+    async fn test_upload_to_s3_unencrypted_not_retryable_without_rewind() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let parent_node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+
+        let mock_bytes: Vec<u8> = vec![
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+
+        let reader = BufReader::new(Cursor::new(mock_bytes));
+
+        let file_meta = FileMeta::builder("test", 16).build();
+
+        let upload_options = UploadOptions::builder(file_meta)
+            .with_upload_retries(1)
+            .build();
+
+        let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads")
+            .with_status(201)
+            .with_body(channel_res)
+            .with_header("content-type", "application/json")
+            .expect(1)
+            .create();
+
+        let s3_urls_response =
+            include_str!("../tests/responses/upload/s3_urls_ok_with_placeholder.json");
+        let s3_urls_response =
+            s3_urls_response.replace("$base_url/", client.get_base_url().as_str());
+        let s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
+            .with_status(201)
+            .with_body(s3_urls_response)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let upload_mock = mock_server
+            .mock("PUT", "/upload_url")
+            .with_status(202)
+            .with_header("etag", "string")
+            .create();
+
+        let finalize_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
+            .with_status(202)
+            .create();
+
+        let status_error_res =
+            include_str!("../tests/responses/upload/upload_status_error_ok.json");
+        let status_error_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads/string")
+            .with_status(200)
+            .with_body(status_error_res)
+            .with_header("content-type", "application/json")
+            .expect(1)
+            .create();
+
+        // upload without rewind (plain upload) cannot be retried
+        let err =
+            <Dracoon<Connected> as UploadInternal<Cursor<Vec<u8>>>>::upload_to_s3_unencrypted(
+                &client,
+                &parent_node,
+                upload_options,
+                reader,
+                None,
+                None,
+            )
+            .await
+            .unwrap_err();
+
+        upload_channel_mock.assert();
+        s3_urls_mock.assert();
+        upload_mock.assert();
+        finalize_mock.assert();
+        status_error_mock.assert();
+
+        assert!(err.is_upload_not_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_rewind_seekable() {
+        let mut cursor = BufReader::new(Cursor::new(vec![1u8, 2, 3]));
+        let mut buf = [0u8; 3];
+        cursor.read_exact(&mut buf).await.unwrap();
+
+        rewind_seekable(&mut cursor).await.unwrap();
+        cursor.read_exact(&mut buf).await.unwrap();
+        assert_eq!(buf, [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_upload_to_s3_encrypted() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let parent_node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+
+        let mock_bytes: Vec<u8> = vec![
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+
+        let reader = Cursor::new(mock_bytes);
+        let reader_clone = BufReader::new(reader);
+
+        let keypair =
+            DracoonCrypto::create_plain_user_keypair(dco3_crypto::UserKeyPairVersion::RSA4096)
+                .unwrap();
+        let enc_keypair =
+            DracoonCrypto::encrypt_private_key("TopSecret1234!", keypair.clone()).unwrap();
+        let enc_keypair_json = serde_json::to_string(&enc_keypair).unwrap();
+
+        let keypair_mock = mock_server
+            .mock("GET", "/api/v4/user/account/keypair")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(enc_keypair_json)
+            .create();
+
+        let _kp = client
+            .get_keypair(Some("TopSecret1234!".into()))
+            .await
+            .unwrap();
+
+        keypair_mock.assert();
+
+        let file_meta = FileMeta::builder("test", 16).build();
+
+        let upload_options = UploadOptions::builder(file_meta).build();
+
+        // mock upload channel
+        let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
+
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads")
+            .with_status(201)
+            .with_body(channel_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        // mock S3 urls
+        let s3_urls_response =
+            include_str!("../tests/responses/upload/s3_urls_ok_with_placeholder.json");
+        let s3_urls_response =
+            s3_urls_response.replace("$base_url/", client.get_base_url().as_str());
+
+        let s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
+            .with_status(201)
+            .with_body(s3_urls_response.clone())
+            .with_header("content-type", "application/json")
+            .create();
+
+        let upload_res =
+            serde_json::from_str::<PresignedUrlList>(s3_urls_response.as_str()).unwrap();
+
+        // mock upload to S3
+        let upload_mock = mock_server
+            .mock("PUT", "/upload_url")
+            .with_status(202)
+            .with_header("etag", "string")
+            .create();
+
+        // mock finalize upload
+        let finalize_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
+            .with_status(202)
+            .create();
+
+        // mock upload status
+        let status_res = include_str!("../tests/responses/upload/upload_status_ok.json");
+        let status_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads/string")
+            .with_status(200)
+            .with_body(status_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        // mock missing file keys
+        let missing_keys = include_str!("../tests/responses/nodes/missing_file_keys_empty_ok.json");
+        let keys_mock = mock_server
+            .mock("GET", "/api/v4/nodes/missingFileKeys?file_id=2&limit=50")
+            .with_status(200)
+            .with_body(missing_keys)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let node = <Dracoon<Connected> as UploadInternal<Cursor<Vec<u8>>>>::upload_to_s3_encrypted(
+            &client,
+            &parent_node,
+            upload_options,
+            reader_clone,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        upload_channel_mock.assert();
+        s3_urls_mock.assert();
+        upload_mock.assert();
+        finalize_mock.assert();
+        status_mock.assert();
+        keys_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_upload_to_s3_encrypted_no_content() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let parent_node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+
+        // empty file
+        let mock_bytes: Vec<u8> = vec![];
+
+        let reader = Cursor::new(mock_bytes);
+        let reader_clone = BufReader::new(reader);
+
+        let keypair =
+            DracoonCrypto::create_plain_user_keypair(dco3_crypto::UserKeyPairVersion::RSA4096)
+                .unwrap();
+        let enc_keypair =
+            DracoonCrypto::encrypt_private_key("TopSecret1234!", keypair.clone()).unwrap();
+        let enc_keypair_json = serde_json::to_string(&enc_keypair).unwrap();
+
+        let keypair_mock = mock_server
+            .mock("GET", "/api/v4/user/account/keypair")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(enc_keypair_json)
+            .create();
+
+        let _kp = client
+            .get_keypair(Some("TopSecret1234!".into()))
+            .await
+            .unwrap();
+
+        keypair_mock.assert();
+
+        let file_meta = FileMeta::builder("test", 0).build();
+
+        let upload_options = UploadOptions::builder(file_meta).build();
+
+        // mock upload channel
+        let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
+
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads")
+            .with_status(201)
+            .with_body(channel_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        // mock S3 urls
+        let s3_urls_response =
+            include_str!("../tests/responses/upload/s3_urls_ok_with_placeholder.json");
+        let s3_urls_response =
+            s3_urls_response.replace("$base_url/", client.get_base_url().as_str());
+
+        let s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
+            .with_status(201)
+            .with_body(s3_urls_response.clone())
+            .with_header("content-type", "application/json")
+            .create();
+
+        let upload_res =
+            serde_json::from_str::<PresignedUrlList>(s3_urls_response.as_str()).unwrap();
+
+        // mock upload to S3
+        let upload_mock = mock_server
+            .mock("PUT", "/upload_url")
+            .with_status(202)
+            .with_header("etag", "string")
+            .create();
+
+        // mock finalize upload
+        let finalize_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
+            .with_status(202)
+            .create();
+
+        // mock upload status
+        let status_res = include_str!("../tests/responses/upload/upload_status_ok.json");
+        let status_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads/string")
+            .with_status(200)
+            .with_body(status_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        // mock missing file keys
+        let missing_keys = include_str!("../tests/responses/nodes/missing_file_keys_empty_ok.json");
+        let keys_mock = mock_server
+            .mock("GET", "/api/v4/nodes/missingFileKeys?file_id=2&limit=50")
+            .with_status(200)
+            .with_body(missing_keys)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let node = <Dracoon<Connected> as UploadInternal<Cursor<Vec<u8>>>>::upload_to_s3_encrypted(
+            &client,
+            &parent_node,
+            upload_options,
+            reader_clone,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        upload_channel_mock.assert();
+        s3_urls_mock.assert();
+        upload_mock.assert();
+        finalize_mock.assert();
+        status_mock.assert();
+        keys_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_full_upload_unencrypted_s3() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let parent_node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+
+        let mock_bytes: Vec<u8> = vec![
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+
+        let reader = Cursor::new(mock_bytes);
+        let reader_clone = BufReader::new(reader);
+
+        let file_meta = FileMeta::builder("test", 16).build();
+
+        let sys_info_res = include_str!("../tests/responses/public/system_info_ok.json");
+
+        let system_info_mock = mock_server
+            .mock("GET", "/api/v4/public/system/info")
+            .with_status(200)
+            .with_body(sys_info_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let upload_options = UploadOptions::builder(file_meta).build();
+
+        // mock upload channel
+        let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
+
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads")
+            .with_status(201)
+            .with_body(channel_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        // mock S3 urls
+        let s3_urls_response =
+            include_str!("../tests/responses/upload/s3_urls_ok_with_placeholder.json");
+        let s3_urls_response =
+            s3_urls_response.replace("$base_url/", client.get_base_url().as_str());
+
+        let s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
+            .with_status(201)
+            .with_body(s3_urls_response.clone())
+            .with_header("content-type", "application/json")
+            .create();
+
+        let upload_res =
+            serde_json::from_str::<PresignedUrlList>(s3_urls_response.as_str()).unwrap();
+
+        // mock upload to S3
+        let upload_mock = mock_server
+            .mock("PUT", "/upload_url")
+            .with_status(202)
+            .with_header("etag", "string")
+            .create();
+
+        // mock finalize upload
+        let finalize_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
+            .with_status(202)
+            .create();
+
+        // mock upload status
+        let status_res = include_str!("../tests/responses/upload/upload_status_ok.json");
+        let status_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads/string")
+            .with_status(200)
+            .with_body(status_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let node = <Dracoon<Connected> as Upload<Cursor<Vec<u8>>>>::upload(
+            &client,
+            &parent_node,
+            upload_options,
+            reader_clone,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        upload_channel_mock.assert();
+        s3_urls_mock.assert();
+        upload_mock.assert();
+        finalize_mock.assert();
+        status_mock.assert();
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[tokio::test]
+    async fn test_full_upload_with_result_clamps_part_size() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let parent_node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+
+        let mock_bytes: Vec<u8> = vec![
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+
+        let reader = Cursor::new(mock_bytes);
+        let reader_clone = BufReader::new(reader);
+
+        let file_meta = FileMeta::builder("test", 16).build();
+
+        let sys_info_res = include_str!("../tests/responses/public/system_info_ok.json");
 
-        let keypair_mock = mock_server
-            .mock("GET", "/api/v4/user/account/keypair")
+        let system_info_mock = mock_server
+            .mock("GET", "/api/v4/public/system/info")
             .with_status(200)
+            .with_body(sys_info_res)
             .with_header("content-type", "application/json")
-            .with_body(enc_keypair_json)
             .create();
 
-        let _kp = client
-            .get_keypair(Some("TopSecret1234!".into()))
-            .await
-            .unwrap();
-
-        keypair_mock.assert();
-
-        let file_meta = FileMeta::builder("test", 0).build();
-
         let upload_options = UploadOptions::builder(file_meta).build();
 
         // mock upload channel
@@ -1844,22 +3731,16 @@ mod tests {
             .with_header("content-type", "application/json")
             .create();
 
-        // mock missing file keys
-        let missing_keys = include_str!("../tests/responses/nodes/missing_file_keys_empty_ok.json");
-        let keys_mock = mock_server
-            .mock("GET", "/api/v4/nodes/missingFileKeys?file_id=2&limit=50")
-            .with_status(200)
-            .with_body(missing_keys)
-            .with_header("content-type", "application/json")
-            .create();
+        // requested chunk size exceeds the max. S3 part size (5 GB)
+        let chunk_size = 6 * 1024 * 1024 * 1024;
 
-        let node = <Dracoon<Connected> as UploadInternal<Cursor<Vec<u8>>>>::upload_to_s3_encrypted(
+        let upload_result = <Dracoon<Connected> as Upload<Cursor<Vec<u8>>>>::upload_with_result(
             &client,
             &parent_node,
             upload_options,
             reader_clone,
             None,
-            None,
+            Some(chunk_size),
         )
         .await
         .unwrap();
@@ -1869,11 +3750,16 @@ mod tests {
         upload_mock.assert();
         finalize_mock.assert();
         status_mock.assert();
-        keys_mock.assert();
+
+        assert_eq!(upload_result.plan.part_size, S3_MAX_UPLOAD_PART_SIZE);
+        assert_eq!(upload_result.plan.part_count, 1);
+        assert_eq!(upload_result.plan.last_part_size, 16);
+        assert!(upload_result.plan.use_s3_storage);
+        assert_eq!(upload_result.node.id, 2);
     }
 
     #[tokio::test]
-    async fn test_full_upload_unencrypted_s3() {
+    async fn test_full_upload_unencrypted_s3_preserves_timestamps() {
         let (client, mut mock_server) = get_connected_client().await;
 
         let parent_node: Node =
@@ -1883,81 +3769,88 @@ mod tests {
             0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
         ];
 
-        let reader = Cursor::new(mock_bytes);
-        let reader_clone = BufReader::new(reader);
+        let reader = BufReader::new(Cursor::new(mock_bytes));
 
-        let file_meta = FileMeta::builder("test", 16).build();
+        let timestamp_creation = chrono::DateTime::parse_from_rfc3339("2020-01-02T03:04:05Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let timestamp_modification = chrono::DateTime::parse_from_rfc3339("2021-06-07T08:09:10Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
 
-        let sys_info_res = include_str!("../tests/responses/public/system_info_ok.json");
+        let file_meta = FileMeta::builder("test", 16)
+            .with_timestamp_creation(timestamp_creation)
+            .with_timestamp_modification(timestamp_modification)
+            .build();
+
+        let upload_options = UploadOptions::builder(file_meta).build();
 
         let system_info_mock = mock_server
             .mock("GET", "/api/v4/public/system/info")
             .with_status(200)
-            .with_body(sys_info_res)
+            .with_body(include_str!(
+                "../tests/responses/public/system_info_ok.json"
+            ))
             .with_header("content-type", "application/json")
             .create();
 
-        let upload_options = UploadOptions::builder(file_meta).build();
-
-        // mock upload channel
-        let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
-
+        // both timestamps are sent on creating the upload channel
         let upload_channel_mock = mock_server
             .mock("POST", "/api/v4/nodes/files/uploads")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"timestampCreation":"2020-01-02T03:04:05+00:00","timestampModification":"2021-06-07T08:09:10+00:00"}"#
+                    .into(),
+            ))
             .with_status(201)
-            .with_body(channel_res)
+            .with_body(include_str!(
+                "../tests/responses/upload/upload_channel_ok.json"
+            ))
             .with_header("content-type", "application/json")
             .create();
 
-        // mock S3 urls
-        let s3_urls_response =
-            include_str!("../tests/responses/upload/s3_urls_ok_with_placeholder.json");
         let s3_urls_response =
-            s3_urls_response.replace("$base_url/", client.get_base_url().as_str());
+            include_str!("../tests/responses/upload/s3_urls_ok_with_placeholder.json")
+                .replace("$base_url/", client.get_base_url().as_str());
 
         let s3_urls_mock = mock_server
             .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
             .with_status(201)
-            .with_body(s3_urls_response.clone())
+            .with_body(s3_urls_response)
             .with_header("content-type", "application/json")
             .create();
 
-        let upload_res =
-            serde_json::from_str::<PresignedUrlList>(s3_urls_response.as_str()).unwrap();
-
-        // mock upload to S3
         let upload_mock = mock_server
             .mock("PUT", "/upload_url")
             .with_status(202)
             .with_header("etag", "string")
             .create();
 
-        // mock finalize upload
         let finalize_mock = mock_server
             .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
             .with_status(202)
             .create();
 
-        // mock upload status
-        let status_res = include_str!("../tests/responses/upload/upload_status_ok.json");
         let status_mock = mock_server
             .mock("GET", "/api/v4/nodes/files/uploads/string")
             .with_status(200)
-            .with_body(status_res)
+            .with_body(include_str!(
+                "../tests/responses/upload/upload_status_ok.json"
+            ))
             .with_header("content-type", "application/json")
             .create();
 
-        let node = <Dracoon<Connected> as Upload<Cursor<Vec<u8>>>>::upload(
+        <Dracoon<Connected> as Upload<Cursor<Vec<u8>>>>::upload(
             &client,
             &parent_node,
             upload_options,
-            reader_clone,
+            reader,
             None,
             None,
         )
         .await
         .unwrap();
 
+        system_info_mock.assert();
         upload_channel_mock.assert();
         s3_urls_mock.assert();
         upload_mock.assert();
@@ -2091,34 +3984,215 @@ mod tests {
         keys_mock.assert();
     }
 
+    #[test]
+    fn test_resolve_s3_chunk_size_within_limit() {
+        let chunk_size = resolve_s3_chunk_size(1024 * 1024 * 1024, 32 * 1024 * 1024).unwrap();
+        assert_eq!(chunk_size, 32 * 1024 * 1024);
+
+        // exactly 10,000 parts
+        let chunk_size = resolve_s3_chunk_size(10_000 * 5 * 1024 * 1024, 5 * 1024 * 1024).unwrap();
+        assert_eq!(chunk_size, 5 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_resolve_s3_chunk_size_auto_increase() {
+        // 1 TB at 32 MB chunks would need 32,768 parts
+        let total_size: u64 = 1024 * 1024 * 1024 * 1024;
+        let chunk_size = resolve_s3_chunk_size(total_size, 32 * 1024 * 1024).unwrap();
+
+        assert!(chunk_size > 32 * 1024 * 1024);
+        assert_eq!(chunk_size % (1024 * 1024), 0);
+
+        let (count, _) = calculate_upload_chunks(total_size, chunk_size as u64).unwrap();
+        assert!(u64::from(count) <= S3_MAX_UPLOAD_PARTS);
+    }
+
+    #[test]
+    fn test_resolve_s3_chunk_size_file_too_large() {
+        let total_size = S3_MAX_UPLOAD_PARTS * S3_MAX_UPLOAD_PART_SIZE + 1;
+        let res = resolve_s3_chunk_size(total_size, 32 * 1024 * 1024);
+
+        assert_eq!(
+            res.unwrap_err(),
+            DracoonClientError::FileTooLarge {
+                size: total_size,
+                max_size: S3_MAX_UPLOAD_PARTS * S3_MAX_UPLOAD_PART_SIZE,
+            }
+        );
+    }
+
+    #[test]
+    fn test_resolve_s3_chunk_size_with_max_clamped() {
+        let max_part_size = 16 * 1024 * 1024;
+        let chunk_size =
+            resolve_s3_chunk_size_with_max(1024 * 1024 * 1024, 64 * 1024 * 1024, max_part_size)
+                .unwrap();
+
+        assert_eq!(chunk_size as u64, max_part_size);
+
+        // too large for 10,000 parts of the instance max. part size
+        let total_size = S3_MAX_UPLOAD_PARTS * max_part_size + 1;
+        let res = resolve_s3_chunk_size_with_max(total_size, 64 * 1024 * 1024, max_part_size);
+
+        assert_eq!(
+            res.unwrap_err(),
+            DracoonClientError::FileTooLarge {
+                size: total_size,
+                max_size: S3_MAX_UPLOAD_PARTS * max_part_size,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_plan_upload_s3_multiple_parts() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let sys_info_res = include_str!("../tests/responses/public/system_info_ok.json");
+
+        let system_info_mock = mock_server
+            .mock("GET", "/api/v4/public/system/info")
+            .with_status(200)
+            .with_body(sys_info_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        // 100 MB file with default chunk size (32 MB)
+        let plan = <Dracoon<Connected> as Upload<Cursor<Vec<u8>>>>::plan_upload(
+            &client,
+            100 * 1024 * 1024,
+            None,
+        )
+        .await
+        .unwrap();
+
+        system_info_mock.assert();
+        assert_eq!(
+            plan,
+            UploadPlan {
+                file_size: 100 * 1024 * 1024,
+                part_count: 4,
+                part_size: 32 * 1024 * 1024,
+                last_part_size: 4 * 1024 * 1024,
+                use_s3_storage: true,
+            }
+        );
+    }
+
     #[test]
     fn test_calculate_chunk_count_and_last_size() {
         // exact multiple of chunk size
-        let (count, last_size) = calculate_s3_url_count(10 * 1024 * 1024, 5 * 1024 * 1024);
+        let (count, last_size) =
+            calculate_upload_chunks(10 * 1024 * 1024, 5 * 1024 * 1024).unwrap();
         assert_eq!(count, 2);
         assert_eq!(last_size, 5 * 1024 * 1024);
 
         // exact value as chunk size
-        let (count, last_size) = calculate_s3_url_count(10 * 1024 * 1024, 10 * 1024 * 1024);
+        let (count, last_size) =
+            calculate_upload_chunks(10 * 1024 * 1024, 10 * 1024 * 1024).unwrap();
         assert_eq!(count, 1);
         assert_eq!(last_size, 10 * 1024 * 1024);
 
         // not multiple of chunk size
-        let (count, last_size) = calculate_s3_url_count(10 * 1024 * 1024 + 13, 5 * 1024 * 1024);
+        let (count, last_size) =
+            calculate_upload_chunks(10 * 1024 * 1024 + 13, 5 * 1024 * 1024).unwrap();
         assert_eq!(count, 3);
         assert_eq!(last_size, 13);
 
         // empty file
-        let (count, last_size) = calculate_s3_url_count(0, 5 * 1024 * 1024);
+        let (count, last_size) = calculate_upload_chunks(0, 5 * 1024 * 1024).unwrap();
         assert_eq!(count, 1);
         assert_eq!(last_size, 0);
 
         // large file
-        let (count, last_size) = calculate_s3_url_count(1024 * 1024 * 1024, 5 * 1024 * 1024);
+        let (count, last_size) =
+            calculate_upload_chunks(1024 * 1024 * 1024, 5 * 1024 * 1024).unwrap();
         assert_eq!(count, 205);
         assert_eq!(last_size, 4 * 1024 * 1024);
     }
 
+    #[test]
+    fn test_calculate_chunk_offsets_large_file() {
+        const MB: u64 = 1024 * 1024;
+        // 3 GB - computed without any I/O
+        let total_size: u64 = 3 * 1024 * MB;
+
+        for chunk_size in [
+            32 * MB as usize,
+            DEFAULT_UPLOAD_CHUNK_SIZE,
+            5 * MB as usize + 7,
+        ] {
+            let (count, last_size) =
+                calculate_s3_upload_parts(total_size, chunk_size as u64).unwrap();
+
+            let last_offset = calculate_chunk_offset(count, chunk_size);
+            assert_eq!(last_offset + last_size, total_size);
+
+            // all chunks cover the file without gaps
+            let covered = (1..count)
+                .map(|part| {
+                    calculate_chunk_offset(part + 1, chunk_size)
+                        - calculate_chunk_offset(part, chunk_size)
+                })
+                .sum::<u64>()
+                + last_size;
+            assert_eq!(covered, total_size);
+        }
+
+        // NFS uploads are not limited to 10,000 parts
+        let (count, last_size) = calculate_upload_chunks(total_size, MB / 4).unwrap();
+        assert_eq!(count, 12_288);
+        assert_eq!(last_size, MB / 4);
+        assert_eq!(
+            calculate_chunk_offset(count, (MB / 4) as usize),
+            total_size - MB / 4
+        );
+    }
+
+    #[test]
+    fn test_calculate_chunk_offsets_max_s3_file() {
+        // 5 TB (S3 multipart limit) at the resolved part size
+        let total_size: u64 = 5 * 1024 * 1024 * 1024 * 1024;
+        let chunk_size = resolve_s3_chunk_size(total_size, DEFAULT_UPLOAD_CHUNK_SIZE).unwrap();
+
+        let (count, last_size) = calculate_s3_upload_parts(total_size, chunk_size as u64).unwrap();
+
+        assert!(u64::from(count) <= S3_MAX_UPLOAD_PARTS);
+        assert_eq!(
+            calculate_chunk_offset(count, chunk_size) + last_size,
+            total_size
+        );
+    }
+
+    #[test]
+    fn test_calculate_s3_upload_parts_too_many_parts() {
+        const MB: u64 = 1024 * 1024;
+        let total_size: u64 = 3 * 1024 * MB;
+
+        // 256 KB chunks would need 12,288 parts
+        let err = calculate_s3_upload_parts(total_size, MB / 4).unwrap_err();
+
+        assert!(err.is_file_too_large());
+        assert_eq!(
+            err,
+            DracoonClientError::FileTooLarge {
+                size: total_size,
+                max_size: S3_MAX_UPLOAD_PARTS * MB / 4,
+            }
+        );
+    }
+
+    #[test]
+    fn test_calculate_upload_chunks_zero_chunk_size() {
+        let err = calculate_upload_chunks(16, 0).unwrap_err();
+        assert!(err.is_invalid_chunk_size());
+
+        let err = calculate_upload_chunks(0, 0).unwrap_err();
+        assert!(err.is_invalid_chunk_size());
+
+        let err = resolve_s3_chunk_size(16, 0).unwrap_err();
+        assert!(err.is_invalid_chunk_size());
+    }
+
     #[tokio::test]
     async fn test_upload_callback_s3_unencrypted() {
         let (client, mut mock_server) = get_connected_client().await;
@@ -2321,5 +4395,211 @@ mod tests {
         );
     }
 
+    fn resumable_upload_session() -> UploadSession {
+        let upload_channel: CreateFileUploadResponse = serde_json::from_str(include_str!(
+            "../tests/responses/upload/upload_channel_ok.json"
+        ))
+        .unwrap();
+        let file_meta = FileMeta::builder("test", 16).build();
+        let upload_options = UploadOptions::builder(file_meta).build();
+
+        UploadSession::new(2, upload_channel, &upload_options, 8)
+    }
+
+    #[test]
+    fn test_upload_session_token_redacted() {
+        let mut session = resumable_upload_session();
+        session.token = SecretString::from("secret-token");
+
+        assert!(!format!("{session:?}").contains("secret-token"));
+
+        // the token is persisted with the session
+        let session_json = serde_json::to_string(&session).unwrap();
+        assert!(session_json.contains(r#""token":"secret-token""#));
+
+        let session: UploadSession = serde_json::from_str(&session_json).unwrap();
+        assert_eq!(session.token.expose_secret(), "secret-token");
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_upload_status_timeout() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let status_res = include_str!("../tests/responses/upload/upload_status_pending_ok.json");
+        let status_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads/string")
+            .with_status(200)
+            .with_body(status_res)
+            .with_header("content-type", "application/json")
+            .expect_at_least(2)
+            .create();
+
+        let err = client
+            .wait_for_upload_status::<Cursor<Vec<u8>>>("string", Duration::from_millis(500))
+            .await
+            .err()
+            .unwrap();
+
+        status_mock.assert();
+        assert!(err.is_upload_status_timeout());
+    }
+
+    #[tokio::test]
+    async fn test_resume_upload_skips_completed_parts() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let mock_bytes: Vec<u8> = vec![
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+        let reader = BufReader::new(Cursor::new(mock_bytes.clone()));
+
+        // first part was uploaded before the interruption
+        let mut session = resumable_upload_session();
+        session
+            .parts
+            .push(S3FileUploadPart::new(1, "etag1".to_string()));
+
+        // persist and restore session
+        let session_json = serde_json::to_string(&session).unwrap();
+        let mut session: UploadSession = serde_json::from_str(&session_json).unwrap();
+
+        let node_res = include_str!("../tests/responses/nodes/node_ok.json");
+        let node_mock = mock_server
+            .mock("GET", "/api/v4/nodes/2")
+            .with_status(200)
+            .with_body(node_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let s3_urls_response =
+            include_str!("../tests/responses/upload/s3_urls_ok_with_placeholder.json");
+        let s3_urls_response =
+            s3_urls_response.replace("$base_url/", client.get_base_url().as_str());
+
+        let s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "firstPartNumber": 2,
+                "lastPartNumber": 2
+            })))
+            .with_status(201)
+            .with_body(s3_urls_response)
+            .with_header("content-type", "application/json")
+            .expect(1)
+            .create();
+
+        let upload_mock = mock_server
+            .mock("PUT", "/upload_url")
+            .match_body(mock_bytes[8..].to_vec())
+            .with_status(202)
+            .with_header("etag", "etag2")
+            .expect(1)
+            .create();
+
+        let finalize_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "parts": [
+                    { "partNumber": 1, "partEtag": "etag1" },
+                    { "partNumber": 2, "partEtag": "etag2" }
+                ]
+            })))
+            .with_status(202)
+            .create();
+
+        let status_res = include_str!("../tests/responses/upload/upload_status_ok.json");
+        let status_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads/string")
+            .with_status(200)
+            .with_body(status_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        client
+            .resume_upload(&mut session, reader, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(session.parts.len(), 2);
+        assert!(session.is_part_completed(2));
+
+        node_mock.assert();
+        s3_urls_mock.assert();
+        upload_mock.assert();
+        finalize_mock.assert();
+        status_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_resume_upload_chunk_size_mismatch() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let reader = BufReader::new(Cursor::new(vec![0u8; 16]));
+        let mut session = resumable_upload_session();
+
+        let node_mock = mock_server
+            .mock("GET", "/api/v4/nodes/2")
+            .with_status(200)
+            .expect(0)
+            .create();
+
+        let err = client
+            .resume_upload(&mut session, reader, None, Some(4))
+            .await
+            .unwrap_err();
+
+        assert!(err.is_upload_not_resumable());
+        node_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_resume_upload_parent_deleted() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let reader = BufReader::new(Cursor::new(vec![0u8; 16]));
+        let mut session = resumable_upload_session();
+
+        let error_res = include_str!("../tests/responses/error_not_found.json");
+        let node_mock = mock_server
+            .mock("GET", "/api/v4/nodes/2")
+            .with_status(404)
+            .with_body(error_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
+            .expect(0)
+            .create();
+
+        let err = client
+            .resume_upload(&mut session, reader, None, None)
+            .await
+            .unwrap_err();
+
+        assert!(err.is_not_found());
+        node_mock.assert();
+        s3_urls_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_create_upload_session_encrypted_parent() {
+        let (client, _mock_server) = get_connected_client().await;
+
+        let parent_node: Node = serde_json::from_str(include_str!(
+            "../tests/responses/nodes/node_encrypted_ok.json"
+        ))
+        .unwrap();
+        let file_meta = FileMeta::builder("test", 16).build();
+        let upload_options = UploadOptions::builder(file_meta).build();
+
+        let err = client
+            .create_upload_session(&parent_node, &upload_options, None)
+            .await
+            .unwrap_err();
+
+        assert!(err.is_upload_not_resumable());
+    }
+
     //TODO: test NFS upload (unencrypted and encrypted)
 }
@@ -0,0 +1,589 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use tokio::io::BufReader;
+use tracing::{debug, error, warn};
+
+use crate::{
+    client::{errors::DracoonClientError, Connected},
+    Dracoon,
+};
+
+use super::{
+    models::{
+        CreateFolderRequest, DirectoryUploadOperation, DirectoryUploadProgress,
+        DirectoryUploadProgressCallback, FileMeta, Node, UploadDirectoryOptions, UploadOptions,
+        UploadProgressCallback,
+    },
+    nodes::build_node_path,
+    Folders, Upload, UploadDirectory,
+};
+
+/// A file or folder of the local directory tree
+#[derive(Debug)]
+struct LocalEntry {
+    path: PathBuf,
+    // path relative to the uploaded directory (empty for the directory itself)
+    relative: PathBuf,
+    name: String,
+    is_dir: bool,
+    size: u64,
+    // local file metadata (files only) - used to preserve timestamps
+    metadata: Option<std::fs::Metadata>,
+}
+
+#[async_trait]
+impl UploadDirectory for Dracoon<Connected> {
+    async fn upload_directory(
+        &self,
+        local_dir: impl AsRef<Path> + Send,
+        parent_node: &Node,
+        options: UploadDirectoryOptions,
+        callback: Option<DirectoryUploadProgressCallback>,
+    ) -> Result<Vec<DirectoryUploadOperation>, DracoonClientError> {
+        let entries = collect_local_entries(local_dir.as_ref(), options.follow_symlinks).await?;
+
+        let overall_total = entries
+            .iter()
+            .filter(|entry| !entry.is_dir)
+            .map(|entry| entry.size)
+            .sum::<u64>();
+        let mut overall_bytes = 0;
+
+        let callback = callback.map(|cb| Arc::new(Mutex::new(cb)));
+
+        let mut operations = Vec::new();
+
+        // remote path and folder per local (relative) folder - no folder if not created (dry run)
+        let mut folders: HashMap<PathBuf, (String, Option<Node>)> = HashMap::new();
+
+        for entry in entries {
+            let (parent_path, parent) = match entry.relative.parent() {
+                Some(parent) => folders
+                    .get(parent)
+                    .map(|(path, node)| (path.clone(), node.clone()))
+                    .ok_or(DracoonClientError::Unknown)?,
+                None => (build_node_path(parent_node), Some(parent_node.clone())),
+            };
+
+            let remote_path = format!("{}/{}", parent_path.trim_end_matches('/'), entry.name);
+
+            if entry.is_dir {
+                let existing = match &parent {
+                    Some(parent) => self.nodes().find_folder(parent.id, &entry.name).await?,
+                    None => None,
+                };
+
+                let folder = match (existing, parent) {
+                    (Some(folder), _) => {
+                        debug!("Folder {} already exists", remote_path);
+                        Some(folder)
+                    }
+                    (None, parent) => {
+                        operations.push(DirectoryUploadOperation::CreateFolder {
+                            remote_path: remote_path.clone(),
+                        });
+
+                        match parent {
+                            Some(parent) if !options.dry_run => {
+                                let mut req = CreateFolderRequest::builder(&entry.name, parent.id);
+                                if let Some(classification) = options.classification {
+                                    req = req.with_classification(classification);
+                                }
+
+                                Some(self.nodes().create_folder(req.build()?).await?)
+                            }
+                            _ => None,
+                        }
+                    }
+                };
+
+                folders.insert(entry.relative, (remote_path, folder));
+                continue;
+            }
+
+            operations.push(DirectoryUploadOperation::UploadFile {
+                local_path: entry.path.clone(),
+                remote_path: remote_path.clone(),
+                size: entry.size,
+            });
+
+            if let Some(parent) = parent.filter(|_| !options.dry_run) {
+                let file = tokio::fs::File::open(&entry.path).await.map_err(|err| {
+                    error!("Error opening file {}: {}", entry.path.display(), err);
+                    DracoonClientError::IoError
+                })?;
+
+                // preserves the local creation / modification timestamps
+                let mut file_meta = FileMeta::builder(&entry.name, entry.size);
+                if let Some(metadata) = &entry.metadata {
+                    file_meta = file_meta.with_timestamps_from_metadata(metadata);
+                }
+
+                let mut upload_options = UploadOptions::builder(file_meta.build()).build();
+                upload_options.classification = options.classification;
+                upload_options.keep_share_links = options.keep_share_links;
+                upload_options
+                    .resolution_strategy
+                    .clone_from(&options.resolution_strategy);
+                upload_options.upload_retries = options.upload_retries;
+
+                let file_callback = callback.as_ref().map(|cb| {
+                    file_progress_callback(
+                        cb.clone(),
+                        entry.relative.clone(),
+                        entry.size,
+                        overall_bytes,
+                        overall_total,
+                    )
+                });
+
+                <Dracoon<Connected> as Upload<tokio::fs::File>>::upload_seekable(
+                    self,
+                    &parent,
+                    upload_options,
+                    BufReader::new(file),
+                    file_callback,
+                    options.chunk_size,
+                )
+                .await
+                .map_err(|err| {
+                    error!("Error uploading {}: {}", entry.path.display(), err);
+                    err
+                })?;
+            }
+
+            overall_bytes += entry.size;
+        }
+
+        Ok(operations)
+    }
+}
+
+/// helper to report the progress of a single file upload as directory upload progress
+/// (the upload callback reports the processed bytes of each chunk)
+fn file_progress_callback(
+    callback: Arc<Mutex<DirectoryUploadProgressCallback>>,
+    current_file: PathBuf,
+    current_total: u64,
+    overall_offset: u64,
+    overall_total: u64,
+) -> UploadProgressCallback {
+    let mut current_bytes = 0;
+
+    Box::new(move |bytes: u64, _| {
+        current_bytes += bytes;
+
+        let progress = DirectoryUploadProgress {
+            current_file: current_file.clone(),
+            current_bytes,
+            current_total,
+            overall_bytes: overall_offset + current_bytes,
+            overall_total,
+        };
+
+        (callback.lock().unwrap())(progress);
+    })
+}
+
+/// Collects all folders and files of a local directory (including the directory itself).
+/// Parent folders are always listed before their content, entries of a folder are sorted by name.
+async fn collect_local_entries(
+    root: &Path,
+    follow_symlinks: bool,
+) -> Result<Vec<LocalEntry>, DracoonClientError> {
+    let invalid_path = || DracoonClientError::InvalidPath(root.display().to_string());
+
+    let canonical_root = tokio::fs::canonicalize(root).await.map_err(|err| {
+        error!("Error resolving directory {}: {}", root.display(), err);
+        invalid_path()
+    })?;
+
+    if !canonical_root.is_dir() {
+        error!("Not a directory: {}", root.display());
+        return Err(invalid_path());
+    }
+
+    let root_name = canonical_root
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .ok_or_else(invalid_path)?;
+
+    let mut entries = vec![LocalEntry {
+        path: root.to_path_buf(),
+        relative: PathBuf::new(),
+        name: root_name,
+        is_dir: true,
+        size: 0,
+        metadata: None,
+    }];
+
+    // prevents endless loops if symlinks point to a parent folder
+    let mut visited = HashSet::from([canonical_root]);
+    let mut pending = vec![(root.to_path_buf(), PathBuf::new())];
+
+    while let Some((dir, relative)) = pending.pop() {
+        let mut read_dir = tokio::fs::read_dir(&dir).await.map_err(|err| {
+            error!("Error reading directory {}: {}", dir.display(), err);
+            DracoonClientError::IoError
+        })?;
+
+        let mut children = Vec::new();
+        while let Some(child) = read_dir.next_entry().await.map_err(|err| {
+            error!("Error reading directory {}: {}", dir.display(), err);
+            DracoonClientError::IoError
+        })? {
+            children.push(child);
+        }
+        children.sort_by_key(tokio::fs::DirEntry::file_name);
+
+        let mut subfolders = Vec::new();
+
+        for child in children {
+            let path = child.path();
+
+            let file_type = child.file_type().await.map_err(|err| {
+                error!("Error reading file type of {}: {}", path.display(), err);
+                DracoonClientError::IoError
+            })?;
+
+            if file_type.is_symlink() && !follow_symlinks {
+                debug!("Skipping symlink {}", path.display());
+                continue;
+            }
+
+            // follows symlinks
+            let metadata = match tokio::fs::metadata(&path).await {
+                Ok(metadata) => metadata,
+                Err(err) if file_type.is_symlink() => {
+                    warn!("Skipping broken symlink {}: {}", path.display(), err);
+                    continue;
+                }
+                Err(err) => {
+                    error!("Error reading metadata of {}: {}", path.display(), err);
+                    return Err(DracoonClientError::IoError);
+                }
+            };
+
+            let name = child.file_name().to_string_lossy().to_string();
+            let child_relative = relative.join(&name);
+
+            if metadata.is_dir() {
+                if file_type.is_symlink() {
+                    let canonical = tokio::fs::canonicalize(&path).await.map_err(|err| {
+                        error!("Error resolving symlink {}: {}", path.display(), err);
+                        DracoonClientError::IoError
+                    })?;
+
+                    if !visited.insert(canonical) {
+                        warn!("Skipping symlink loop {}", path.display());
+                        continue;
+                    }
+                }
+
+                entries.push(LocalEntry {
+                    path: path.clone(),
+                    relative: child_relative.clone(),
+                    name,
+                    is_dir: true,
+                    size: 0,
+                    metadata: None,
+                });
+                subfolders.push((path, child_relative));
+            } else if metadata.is_file() {
+                entries.push(LocalEntry {
+                    path,
+                    relative: child_relative,
+                    name,
+                    is_dir: false,
+                    size: metadata.len(),
+                    metadata: Some(metadata),
+                });
+            }
+        }
+
+        // reversed to process subfolders in alphabetical order
+        pending.extend(subfolders.into_iter().rev());
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::tests::dracoon::get_connected_client;
+
+    use super::*;
+
+    fn create_local_tree(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("dco3_{}_{}", std::process::id(), name));
+        let _ = std::fs::remove_dir_all(&root);
+
+        std::fs::create_dir_all(root.join("sub")).unwrap();
+        std::fs::write(root.join("a.txt"), b"test").unwrap();
+        std::fs::write(root.join("sub").join("b.txt"), b"testtest").unwrap();
+
+        root
+    }
+
+    fn parent_node() -> Node {
+        let mut node: Node =
+            serde_json::from_str(include_str!("../tests/responses/nodes/node_ok.json")).unwrap();
+        node.parent_path = Some("/".to_string());
+
+        node
+    }
+
+    #[tokio::test]
+    async fn test_upload_directory_dry_run_reuses_existing_folder() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let root = create_local_tree("upload_dir_dry_run");
+        let root_name = root.file_name().unwrap().to_string_lossy().to_string();
+
+        // the uploaded directory already exists in the parent
+        let folder_res = include_str!("../tests/responses/nodes/folder_ok.json")
+            .replace(r#""name": "string""#, &format!(r#""name": "{root_name}""#));
+        let folders_res = format!(
+            r#"{{"range": {{"offset": 0, "limit": 500, "total": 1}}, "items": [{folder_res}]}}"#
+        );
+
+        let folders_mock = mock_server
+            .mock("GET", "/api/v4/nodes")
+            .match_query(Matcher::UrlEncoded("parent_id".into(), "2".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(folders_res)
+            .expect(2)
+            .create();
+
+        let create_folder_mock = mock_server
+            .mock("POST", "/api/v4/nodes/folders")
+            .expect(0)
+            .create();
+
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads")
+            .expect(0)
+            .create();
+
+        let options = UploadDirectoryOptions::builder().with_dry_run(true).build();
+
+        let operations = client
+            .upload_directory(&root, &parent_node(), options, None)
+            .await
+            .unwrap();
+
+        folders_mock.assert();
+        create_folder_mock.assert();
+        upload_channel_mock.assert();
+
+        assert_eq!(
+            operations,
+            vec![
+                DirectoryUploadOperation::UploadFile {
+                    local_path: root.join("a.txt"),
+                    remote_path: format!("/string/{root_name}/a.txt"),
+                    size: 4,
+                },
+                DirectoryUploadOperation::CreateFolder {
+                    remote_path: format!("/string/{root_name}/sub"),
+                },
+                DirectoryUploadOperation::UploadFile {
+                    local_path: root.join("sub").join("b.txt"),
+                    remote_path: format!("/string/{root_name}/sub/b.txt"),
+                    size: 8,
+                },
+            ]
+        );
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_upload_directory() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let root = create_local_tree("upload_dir");
+
+        let folders_mock = mock_server
+            .mock("GET", "/api/v4/nodes")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!(
+                "../tests/responses/nodes/nodes_search_no_result.json"
+            ))
+            .expect(2)
+            .create();
+
+        let create_folder_mock = mock_server
+            .mock("POST", "/api/v4/nodes/folders")
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/nodes/folder_ok.json"))
+            .expect(2)
+            .create();
+
+        let _system_info_mock = mock_server
+            .mock("GET", "/api/v4/public/system/info")
+            .with_status(200)
+            .with_body(include_str!(
+                "../tests/responses/public/system_info_ok.json"
+            ))
+            .with_header("content-type", "application/json")
+            .create();
+
+        // local timestamps are preserved
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads")
+            .match_body(Matcher::Regex(r#""timestampModification":"\d{4}-"#.into()))
+            .with_status(201)
+            .with_body(include_str!(
+                "../tests/responses/upload/upload_channel_ok.json"
+            ))
+            .with_header("content-type", "application/json")
+            .expect(2)
+            .create();
+
+        let s3_urls_res =
+            include_str!("../tests/responses/upload/s3_urls_ok_with_placeholder.json")
+                .replace("$base_url/", client.get_base_url().as_str());
+
+        let _s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/uploads/string/s3_urls")
+            .with_status(201)
+            .with_body(s3_urls_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let _upload_mock = mock_server
+            .mock("PUT", "/upload_url")
+            .with_status(202)
+            .with_header("etag", "string")
+            .create();
+
+        let finalize_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/files/uploads/string/s3")
+            .with_status(202)
+            .expect(2)
+            .create();
+
+        let _status_mock = mock_server
+            .mock("GET", "/api/v4/nodes/files/uploads/string")
+            .with_status(200)
+            .with_body(include_str!(
+                "../tests/responses/upload/upload_status_ok.json"
+            ))
+            .with_header("content-type", "application/json")
+            .create();
+
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let progress_clone = progress.clone();
+        let callback = Box::new(move |p: DirectoryUploadProgress| {
+            progress_clone.lock().unwrap().push(p);
+        });
+
+        let options = UploadDirectoryOptions::builder().build();
+
+        let operations = client
+            .upload_directory(&root, &parent_node(), options, Some(callback))
+            .await
+            .unwrap();
+
+        folders_mock.assert();
+        create_folder_mock.assert();
+        upload_channel_mock.assert();
+        finalize_mock.assert();
+
+        assert_eq!(operations.len(), 4);
+
+        let progress = progress.lock().unwrap();
+        let last_a = progress
+            .iter()
+            .rfind(|p| p.current_file == Path::new("a.txt"))
+            .unwrap();
+        assert_eq!(last_a.current_bytes, 4);
+        assert_eq!(last_a.current_total, 4);
+        assert_eq!(last_a.overall_bytes, 4);
+
+        let last = progress.last().unwrap();
+        assert_eq!(last.current_file, Path::new("sub").join("b.txt"));
+        assert_eq!(last.current_bytes, 8);
+        assert_eq!(last.overall_bytes, 12);
+        assert_eq!(last.overall_total, 12);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_upload_directory_not_a_directory() {
+        let (client, _mock_server) = get_connected_client().await;
+
+        let root = create_local_tree("upload_dir_no_dir");
+
+        let res = client
+            .upload_directory(
+                root.join("a.txt"),
+                &parent_node(),
+                UploadDirectoryOptions::default(),
+                None,
+            )
+            .await;
+
+        assert!(matches!(res, Err(DracoonClientError::InvalidPath(_))));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_collect_local_entries_symlinks() {
+        let root = create_local_tree("upload_dir_symlinks");
+
+        std::os::unix::fs::symlink(root.join("a.txt"), root.join("link.txt")).unwrap();
+        // points to its own parent folder
+        std::os::unix::fs::symlink(&root, root.join("sub").join("loop")).unwrap();
+
+        let relative = |entries: &[LocalEntry]| {
+            entries
+                .iter()
+                .map(|entry| entry.relative.clone())
+                .collect::<Vec<_>>()
+        };
+
+        // skipped by default
+        let entries = collect_local_entries(&root, false).await.unwrap();
+        assert_eq!(
+            relative(&entries),
+            vec![
+                PathBuf::new(),
+                PathBuf::from("a.txt"),
+                PathBuf::from("sub"),
+                Path::new("sub").join("b.txt"),
+            ]
+        );
+
+        // followed, loops are skipped
+        let entries = collect_local_entries(&root, true).await.unwrap();
+        assert_eq!(
+            relative(&entries),
+            vec![
+                PathBuf::new(),
+                PathBuf::from("a.txt"),
+                PathBuf::from("link.txt"),
+                PathBuf::from("sub"),
+                Path::new("sub").join("b.txt"),
+            ]
+        );
+        assert_eq!(entries[2].size, 4);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use tracing::error;
 
 mod models;
 
@@ -18,6 +19,9 @@ use crate::{
 #[async_trait]
 /// This trait contains all methods for customer provisioning.
 /// To use this trait, you need to create a client in `Provisioning` state.
+/// Provisioning requires a separate service token (`X-Sds-Service-Token`) - a connected
+/// (OAuth) client cannot access the provisioning API.
+/// Calls with a missing or invalid service token fail with `DracoonClientError::ProvisioningNotAvailable`.
 ///
 /// ```no_run
 ///
@@ -232,7 +236,9 @@ impl CustomerProvisioning for ProvisioningEndpoint<Provisioning> {
             .send()
             .await?;
 
-        CustomerList::from_response(response).await
+        CustomerList::from_response(response)
+            .await
+            .map_err(map_provisioning_not_available)
     }
     async fn create_customer(
         &self,
@@ -250,7 +256,9 @@ impl CustomerProvisioning for ProvisioningEndpoint<Provisioning> {
             .send()
             .await?;
 
-        NewCustomerResponse::from_response(response).await
+        NewCustomerResponse::from_response(response)
+            .await
+            .map_err(map_provisioning_not_available)
     }
     async fn get_customer(
         &self,
@@ -277,7 +285,9 @@ impl CustomerProvisioning for ProvisioningEndpoint<Provisioning> {
             .send()
             .await?;
 
-        Customer::from_response(response).await
+        Customer::from_response(response)
+            .await
+            .map_err(map_provisioning_not_available)
     }
     async fn update_customer(
         &self,
@@ -297,7 +307,9 @@ impl CustomerProvisioning for ProvisioningEndpoint<Provisioning> {
             .send()
             .await?;
 
-        UpdateCustomerResponse::from_response(response).await
+        UpdateCustomerResponse::from_response(response)
+            .await
+            .map_err(map_provisioning_not_available)
     }
 
     async fn delete_customer(&self, id: u64) -> Result<(), DracoonClientError> {
@@ -315,9 +327,11 @@ impl CustomerProvisioning for ProvisioningEndpoint<Provisioning> {
             .await?;
 
         if response.status().is_server_error() || response.status().is_client_error() {
-            return Err(DracoonClientError::from_response(response)
-                .await
-                .expect("Could not parse error response"));
+            return Err(map_provisioning_not_available(
+                DracoonClientError::from_response(response)
+                    .await
+                    .expect("Could not parse error response"),
+            ));
         }
 
         Ok(())
@@ -351,7 +365,9 @@ impl CustomerProvisioning for ProvisioningEndpoint<Provisioning> {
             .send()
             .await?;
 
-        UserList::from_response(response).await
+        UserList::from_response(response)
+            .await
+            .map_err(map_provisioning_not_available)
     }
     async fn get_customer_attributes(
         &self,
@@ -382,7 +398,9 @@ impl CustomerProvisioning for ProvisioningEndpoint<Provisioning> {
             .send()
             .await?;
 
-        AttributesResponse::from_response(response).await
+        AttributesResponse::from_response(response)
+            .await
+            .map_err(map_provisioning_not_available)
     }
     async fn update_customer_attributes(
         &self,
@@ -402,7 +420,9 @@ impl CustomerProvisioning for ProvisioningEndpoint<Provisioning> {
             .send()
             .await?;
 
-        Customer::from_response(response).await
+        Customer::from_response(response)
+            .await
+            .map_err(map_provisioning_not_available)
     }
     async fn delete_customer_attribute(
         &self,
@@ -422,11 +442,27 @@ impl CustomerProvisioning for ProvisioningEndpoint<Provisioning> {
             .await?;
 
         if response.status().is_server_error() || response.status().is_client_error() {
-            return Err(DracoonClientError::from_response(response)
-                .await
-                .expect("Could not parse error response"));
+            return Err(map_provisioning_not_available(
+                DracoonClientError::from_response(response)
+                    .await
+                    .expect("Could not parse error response"),
+            ));
         }
 
         Ok(())
     }
 }
+
+/// helper to detect a missing or invalid service token on a provisioning request
+/// maps 401 Unauthorized and 403 Forbidden to `DracoonClientError::ProvisioningNotAvailable`
+fn map_provisioning_not_available(err: DracoonClientError) -> DracoonClientError {
+    match err {
+        DracoonClientError::Http(response)
+            if response.is_unauthorized() || response.is_forbidden() =>
+        {
+            error!("Provisioning API not available: {}", response);
+            DracoonClientError::ProvisioningNotAvailable(response)
+        }
+        err => err,
+    }
+}
@@ -3,22 +3,25 @@ use std::cmp::min;
 use async_trait::async_trait;
 use dco3_crypto::{ChunkedEncryption, Decrypter, DracoonCrypto, DracoonRSACrypto};
 use futures_util::TryStreamExt;
-use reqwest::header::{self, RANGE};
+use reqwest::{
+    header::{self, LOCATION, RANGE},
+    Response, Url,
+};
 use tokio::io::{AsyncWrite, AsyncWriteExt};
 use tracing::error;
 
 use crate::{
     constants::{
-        DEFAULT_DOWNLOAD_CHUNK_SIZE, DRACOON_API_PREFIX, PUBLIC_BASE, PUBLIC_DOWNLOAD_SHARES,
-        PUBLIC_SHARES_BASE,
+        DEFAULT_DOWNLOAD_CHUNK_SIZE, DRACOON_API_PREFIX, MAX_DOWNLOAD_REDIRECTS, PUBLIC_BASE,
+        PUBLIC_DOWNLOAD_SHARES, PUBLIC_SHARES_BASE,
     },
     nodes::DownloadProgressCallback,
-    utils::{build_s3_error, FromResponse},
+    utils::{build_s3_error, FromResponse, HashingWriter},
     DracoonClientError,
 };
 
 use super::{
-    PublicDownload, PublicDownloadShare, PublicDownloadTokenGenerateRequest,
+    PublicDownload, PublicDownloadOptions, PublicDownloadShare, PublicDownloadTokenGenerateRequest,
     PublicDownloadTokenGenerateResponse, PublicEndpoint, PublicShareEncryption,
 };
 
@@ -32,57 +35,134 @@ impl<S: Send + Sync> PublicDownload for PublicEndpoint<S> {
         writer: &'w mut (dyn AsyncWrite + Send + Unpin),
         callback: Option<DownloadProgressCallback>,
         chunksize: Option<usize>,
+    ) -> Result<(), DracoonClientError> {
+        let options = PublicDownloadOptions {
+            callback,
+            chunksize,
+            expected_hash: None,
+        };
+
+        self.download_with_options(access_key, share, password, writer, options)
+            .await
+    }
+
+    async fn download_with_options<'w>(
+        &'w self,
+        access_key: impl Into<String> + Send + Sync,
+        share: PublicDownloadShare,
+        password: Option<String>,
+        writer: &'w mut (dyn AsyncWrite + Send + Unpin),
+        options: PublicDownloadOptions,
     ) -> Result<(), DracoonClientError> {
         if password.is_none() && (share.is_protected || share.is_encrypted.unwrap_or(false)) {
             return Err(DracoonClientError::MissingArgument);
         }
 
-        match share.is_encrypted.unwrap_or(false) {
-            true => {
-                let password = password.ok_or(DracoonClientError::MissingEncryptionSecret)?;
-                let file_key = share
-                    .file_key
-                    .ok_or(DracoonClientError::MissingEncryptionSecret)?;
-                let private_key_container = share
-                    .private_key_container
-                    .ok_or(DracoonClientError::MissingEncryptionSecret)?;
+        let PublicDownloadOptions {
+            callback,
+            chunksize,
+            expected_hash,
+        } = options;
 
-                self.download_encrypted(
+        let Some(expected_hash) = expected_hash else {
+            return self
+                .download_share(
                     access_key.into(),
+                    share,
                     password,
-                    PublicShareEncryption::new(file_key, private_key_container),
                     writer,
-                    share.size,
-                    chunksize,
                     callback,
-                )
-                .await?;
-            }
-            false => {
-                self.download_unencrypted(
-                    access_key.into(),
-                    writer,
-                    share.size,
-                    password,
                     chunksize,
-                    callback,
                 )
-                .await?;
-            }
+                .await;
+        };
+
+        let mut hashing_writer = HashingWriter::new(writer, expected_hash.message_digest())?;
+
+        self.download_share(
+            access_key.into(),
+            share,
+            password,
+            &mut hashing_writer,
+            callback,
+            chunksize,
+        )
+        .await?;
+
+        let hash = hashing_writer.finish()?;
+
+        if !expected_hash.matches(&hash) {
+            error!(
+                "Content hash mismatch (expected: {}, actual: {})",
+                expected_hash.value(),
+                hash
+            );
+            return Err(DracoonClientError::HashMismatch {
+                expected: expected_hash.value().to_string(),
+                actual: hash,
+            });
         }
 
         Ok(())
     }
+
+    async fn get_download_url_chain(
+        &self,
+        access_key: impl Into<String> + Send + Sync,
+        password: Option<String>,
+    ) -> Result<Vec<String>, DracoonClientError> {
+        let req = if let Some(password) = password {
+            PublicDownloadTokenGenerateRequest::new(password)
+        } else {
+            PublicDownloadTokenGenerateRequest::default()
+        };
+
+        let url = self
+            .generate_download_url(access_key.into(), req)
+            .await?
+            .download_url;
+
+        // only request the first byte to resolve the redirects
+        let (response, url_chain) = self
+            .get_download_response(url, "bytes=0-0".to_string())
+            .await?;
+
+        if response.error_for_status_ref().is_err() {
+            let error = build_s3_error(response).await;
+            return Err(error);
+        }
+
+        Ok(url_chain)
+    }
 }
 
 #[async_trait]
-trait PublicDownloadInternal {
+pub(super) trait PublicDownloadInternal {
+    /// Downloads the shared file (encrypted or unencrypted) into the writer
+    async fn download_share(
+        &self,
+        access_key: String,
+        share: PublicDownloadShare,
+        password: Option<String>,
+        writer: &mut (dyn AsyncWrite + Send + Unpin),
+        callback: Option<DownloadProgressCallback>,
+        chunksize: Option<usize>,
+    ) -> Result<(), DracoonClientError>;
+
     async fn generate_download_url(
         &self,
         access_key: String,
         req: PublicDownloadTokenGenerateRequest,
     ) -> Result<PublicDownloadTokenGenerateResponse, DracoonClientError>;
 
+    /// Requests a (presigned) download url and follows any redirects manually.
+    /// Returns the response of the final target and the chain of visited urls.
+    async fn get_download_response(
+        &self,
+        url: String,
+        range: String,
+    ) -> Result<(Response, Vec<String>), DracoonClientError>;
+
     async fn download_unencrypted(
         &self,
         acess_key: String,
@@ -108,6 +188,47 @@ trait PublicDownloadInternal {
 
 #[async_trait]
 impl<S: Send + Sync> PublicDownloadInternal for PublicEndpoint<S> {
+    async fn download_share(
+        &self,
+        access_key: String,
+        share: PublicDownloadShare,
+        password: Option<String>,
+        writer: &mut (dyn AsyncWrite + Send + Unpin),
+        callback: Option<DownloadProgressCallback>,
+        chunksize: Option<usize>,
+    ) -> Result<(), DracoonClientError> {
+        match share.is_encrypted.unwrap_or(false) {
+            true => {
+                let password = password.ok_or(DracoonClientError::MissingEncryptionSecret)?;
+                let file_key = share
+                    .file_key
+                    .ok_or(DracoonClientError::MissingEncryptionSecret)?;
+                let private_key_container = share
+                    .private_key_container
+                    .ok_or(DracoonClientError::MissingEncryptionSecret)?;
+
+                self.download_encrypted(
+                    access_key,
+                    password,
+                    PublicShareEncryption::new(file_key, private_key_container),
+                    writer,
+                    share.size,
+                    chunksize,
+                    callback,
+                )
+                .await?;
+            }
+            false => {
+                self.download_unencrypted(
+                    access_key, writer, share.size, password, chunksize, callback,
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
     async fn generate_download_url(
         &self,
         access_key: String,
@@ -140,6 +261,58 @@ impl<S: Send + Sync> PublicDownloadInternal for PublicEndpoint<S> {
         PublicDownloadTokenGenerateResponse::from_response(response).await
     }
 
+    async fn get_download_response(
+        &self,
+        url: String,
+        range: String,
+    ) -> Result<(Response, Vec<String>), DracoonClientError> {
+        let mut url_chain = vec![url.clone()];
+        let mut url = url;
+
+        loop {
+            // no auth header is set here - redirect targets (e.g. S3 storage) must never receive it
+            let response = self
+                .client()
+                .download_http
+                .get(&url)
+                .header(RANGE, range.clone())
+                .send()
+                .await
+                .map_err(|err| {
+                    error!("Error while downloading chunk: {}", err);
+                    err
+                })?;
+
+            if !response.status().is_redirection() {
+                return Ok((response, url_chain));
+            }
+
+            if url_chain.len() > MAX_DOWNLOAD_REDIRECTS {
+                error!("Too many redirects for download url: {}", url);
+                return Err(DracoonClientError::ConnectionFailed(
+                    "Too many redirects".into(),
+                ));
+            }
+
+            let Some(location) = response
+                .headers()
+                .get(LOCATION)
+                .and_then(|location| location.to_str().ok())
+            else {
+                error!("Missing location header in redirect from: {}", url);
+                return Err(DracoonClientError::InvalidUrl(url));
+            };
+
+            // location may be relative to the current url
+            let next_url = Url::parse(&url)
+                .and_then(|base| base.join(location))
+                .map_err(|_| DracoonClientError::InvalidUrl(location.to_string()))?;
+
+            url = next_url.to_string();
+            url_chain.push(url.clone());
+        }
+    }
+
     async fn download_unencrypted(
         &self,
         access_key: String,
@@ -172,18 +345,8 @@ impl<S: Send + Sync> PublicDownloadInternal for PublicEndpoint<S> {
             let end = min(start + chunksize as u64 - 1, size - 1);
             let range = format!("bytes={start}-{end}");
 
-            // get chunk
-            let response = self
-                .client()
-                .http
-                .get(url)
-                .header(RANGE, range)
-                .send()
-                .await
-                .map_err(|err| {
-                    error!("Error while downloading chunk: {}", err);
-                    err
-                })?;
+            // get chunk (following redirects)
+            let (response, _) = self.get_download_response(url, range).await?;
 
             // handle error
             if response.error_for_status_ref().is_err() {
@@ -253,18 +416,8 @@ impl<S: Send + Sync> PublicDownloadInternal for PublicEndpoint<S> {
             let end = min(start + chunksize as u64 - 1, size - 1);
             let range = format!("bytes={start}-{end}");
 
-            // get chunk
-            let response = self
-                .client()
-                .http
-                .get(url)
-                .header(RANGE, range)
-                .send()
-                .await
-                .map_err(|err| {
-                    error!("Error while downloading chunk: {}", err);
-                    err
-                })?;
+            // get chunk (following redirects)
+            let (response, _) = self.get_download_response(url, range).await?;
 
             // handle error
             if response.error_for_status_ref().is_err() {
@@ -304,13 +457,15 @@ impl<S: Send + Sync> PublicDownloadInternal for PublicEndpoint<S> {
 #[cfg(test)]
 mod tests {
     use dco3_crypto::{DracoonCrypto, DracoonRSACrypto, Encrypt};
+    use mockito::Matcher;
 
     use crate::{
         public::{
-            download::PublicDownloadInternal, PublicDownloadTokenGenerateRequest,
+            download::PublicDownloadInternal, PublicDownload, PublicDownloadTokenGenerateRequest,
             PublicShareEncryption,
         },
-        Dracoon,
+        tests::dracoon::get_connected_client,
+        Dracoon, DracoonClientError,
     };
 
     #[tokio::test]
@@ -625,4 +780,143 @@ mod tests {
         download_mock_2.assert();
         url_mock.assert();
     }
+
+    #[tokio::test]
+    async fn test_download_unencrypted_redirect() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        // create bytes for mocking byte response
+        let mock_bytes: [u8; 16] = [
+            0, 12, 33, 44, 55, 66, 77, 88, 99, 111, 222, 255, 0, 12, 33, 44,
+        ];
+
+        // presigned url redirects to the storage url (relative location)
+        let redirect_mock = mock_server
+            .mock("GET", "/some/download/url")
+            .match_header("authorization", Matcher::Missing)
+            .with_status(302)
+            .with_header("location", "/storage/url")
+            .create();
+
+        let storage_mock = mock_server
+            .mock("GET", "/storage/url")
+            .match_header("authorization", Matcher::Missing)
+            .match_header("Range", "bytes=0-15")
+            .with_status(200)
+            .with_body(mock_bytes)
+            .create();
+
+        let download_url = format!("{}/some/download/url", mock_server.url());
+
+        let buffer = Vec::with_capacity(16);
+
+        let mut writer = tokio::io::BufWriter::new(buffer);
+
+        let access_key = "123456";
+
+        let res = include_str!("../tests/responses/download/download_url_ok_template.json");
+        let res = res.replace("$url", &download_url);
+
+        let url_mock = mock_server
+            .mock("POST", "/api/v4/public/shares/downloads/123456")
+            .with_status(200)
+            .with_body(res)
+            .create();
+
+        client
+            .public()
+            .download_unencrypted(access_key.to_string(), &mut writer, 16, None, None, None)
+            .await
+            .unwrap();
+
+        url_mock.assert();
+        redirect_mock.assert();
+        storage_mock.assert();
+
+        assert_eq!(writer.buffer(), &mock_bytes.to_vec());
+    }
+
+    #[tokio::test]
+    async fn test_get_download_url_chain() {
+        let mut mock_server = mockito::Server::new_async().await;
+
+        let client = Dracoon::builder()
+            .with_base_url(mock_server.url())
+            .with_client_id("client_id")
+            .with_client_secret("client_secret")
+            .build()
+            .unwrap();
+
+        let storage_url = format!("{}/storage/url", mock_server.url());
+
+        let redirect_mock = mock_server
+            .mock("GET", "/some/download/url")
+            .with_status(302)
+            .with_header("location", &storage_url)
+            .create();
+
+        let storage_mock = mock_server
+            .mock("GET", "/storage/url")
+            .match_header("Range", "bytes=0-0")
+            .with_status(206)
+            .with_body([0u8])
+            .create();
+
+        let download_url = format!("{}/some/download/url", mock_server.url());
+
+        let res = include_str!("../tests/responses/download/download_url_ok_template.json");
+        let res = res.replace("$url", &download_url);
+
+        let url_mock = mock_server
+            .mock("POST", "/api/v4/public/shares/downloads/123456")
+            .with_status(200)
+            .with_body(res)
+            .create();
+
+        let url_chain = client
+            .public()
+            .get_download_url_chain("123456", None)
+            .await
+            .unwrap();
+
+        url_mock.assert();
+        redirect_mock.assert();
+        storage_mock.assert();
+
+        assert_eq!(url_chain, vec![download_url, storage_url]);
+    }
+
+    #[tokio::test]
+    async fn test_download_too_many_redirects() {
+        let mut mock_server = mockito::Server::new_async().await;
+
+        let client = Dracoon::builder()
+            .with_base_url(mock_server.url())
+            .with_client_id("client_id")
+            .with_client_secret("client_secret")
+            .build()
+            .unwrap();
+
+        // redirect loop
+        let redirect_mock = mock_server
+            .mock("GET", "/some/download/url")
+            .with_status(302)
+            .with_header("location", "/some/download/url")
+            .expect_at_least(1)
+            .create();
+
+        let download_url = format!("{}/some/download/url", mock_server.url());
+
+        let res = client
+            .public()
+            .get_download_response(download_url, "bytes=0-0".to_string())
+            .await;
+
+        redirect_mock.assert();
+
+        assert_eq!(
+            res.unwrap_err(),
+            DracoonClientError::ConnectionFailed("Too many redirects".into())
+        );
+    }
 }
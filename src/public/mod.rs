@@ -2,6 +2,7 @@ mod models;
 use async_trait::async_trait;
 use reqwest::header;
 use tokio::io::{AsyncRead, AsyncWrite, BufReader};
+use tracing::error;
 
 use crate::{
     constants::{
@@ -14,9 +15,11 @@ use crate::{
 };
 
 mod download;
+mod session;
 mod upload;
 
 pub use self::models::*;
+pub use self::session::PublicShareSession;
 
 #[async_trait]
 pub trait Public {
@@ -40,6 +43,27 @@ pub trait Public {
     /// # }
     ///
     async fn get_software_version(&self) -> Result<SoftwareVersionData, DracoonClientError>;
+    /// Checks if the DRACOON API version is at least the given minimum version.
+    /// Returns `DracoonClientError::UnsupportedApiVersion` if the API version is too old.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Public};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::password_flow("username", "password"))
+    /// #  .await
+    /// #  .unwrap();
+    ///
+    /// dracoon.public().require_min_api_version("4.42.0").await.unwrap();
+    ///
+    /// # }
+    /// ```
+    async fn require_min_api_version(&self, min: &str) -> Result<(), DracoonClientError>;
     /// Get system information for the DRACOON backend (API).
     /// ```no_run
     /// # use dco3::{Dracoon, auth::OAuth2Flow, Public};
@@ -154,6 +178,76 @@ pub trait PublicDownload {
         mut callback: Option<DownloadProgressCallback>,
         chunksize: Option<usize>,
     ) -> Result<(), DracoonClientError>;
+
+    /// Download a file from a public download share with options (progress callback, chunk size, expected hash).
+    /// If an expected hash is set, the (decrypted) content is hashed while downloading
+    /// and `DracoonClientError::HashMismatch` is returned if it does not match.
+    /// The content is not buffered: on a mismatch it has already been written to the writer
+    /// and must be discarded by the caller (e.g. by removing the target file).
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Public, PublicDownload, ContentHash, public::PublicDownloadOptions};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::password_flow("username", "password"))
+    /// #  .await
+    /// #  .unwrap();
+    /// let access_key = "access_key";
+    /// let share = dracoon.public().get_public_download_share(access_key.to_string()).await.unwrap();
+    /// let password = Some("TopSecret123!".to_string());
+    ///
+    /// let mut writer = tokio::io::BufWriter::new(tokio::fs::File::create("test.txt").await.unwrap());
+    ///
+    /// let options = PublicDownloadOptions::new()
+    ///     .with_expected_hash(ContentHash::md5("d41d8cd98f00b204e9800998ecf8427e"));
+    ///
+    /// if let Err(err) = dracoon.public().download_with_options(access_key.to_string(), share, password, &mut writer, options).await {
+    ///     if err.is_hash_mismatch() {
+    ///         // the content has already been written - discard it
+    ///         tokio::fs::remove_file("test.txt").await.unwrap();
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    async fn download_with_options<'w>(
+        &'w self,
+        access_key: impl Into<String> + Send + Sync,
+        share: PublicDownloadShare,
+        password: Option<String>,
+        writer: &'w mut (dyn AsyncWrite + Send + Unpin),
+        options: PublicDownloadOptions,
+    ) -> Result<(), DracoonClientError>;
+
+    /// Returns the chain of urls visited when downloading a file from a public download share.
+    /// The first entry is the generated download url, the last entry is the final (storage) url.
+    /// Redirects are followed without forwarding any DRACOON auth header.
+    /// ```no_run
+    /// # use dco3::{Dracoon, Public, PublicDownload};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap();
+    /// let access_key = "access_key";
+    /// // the password must be set for protected shares
+    /// let url_chain = dracoon.public().get_download_url_chain(access_key, None).await.unwrap();
+    ///
+    /// let storage_url = url_chain.last().unwrap();
+    /// # }
+    /// ```
+    async fn get_download_url_chain(
+        &self,
+        access_key: impl Into<String> + Send + Sync,
+        password: Option<String>,
+    ) -> Result<Vec<String>, DracoonClientError>;
 }
 
 #[async_trait]
@@ -188,6 +282,23 @@ impl<S: Send + Sync> Public for PublicEndpoint<S> {
         Ok(SoftwareVersionData::from_response(response).await?)
     }
 
+    async fn require_min_api_version(&self, min: &str) -> Result<(), DracoonClientError> {
+        let software_version = self.get_software_version().await?;
+
+        if software_version.is_api_version_at_least(min) {
+            Ok(())
+        } else {
+            error!(
+                "Unsupported API version: {} (required: {})",
+                software_version.rest_api_version, min
+            );
+            Err(DracoonClientError::UnsupportedApiVersion {
+                required: min.to_string(),
+                actual: software_version.rest_api_version,
+            })
+        }
+    }
+
     async fn get_system_info(&self) -> Result<SystemInfo, DracoonClientError> {
         let url_part =
             format!("{DRACOON_API_PREFIX}/{PUBLIC_BASE}/{PUBLIC_SYSTEM_BASE}/{PUBLIC_INFO}");
@@ -253,16 +364,24 @@ impl<S: Send + Sync> Public for PublicEndpoint<S> {
 #[cfg(test)]
 mod tests {
 
+    use std::sync::{Arc, Mutex};
+
     use chrono::Datelike;
     use dco3_crypto::{
-        DracoonCrypto, DracoonRSACrypto, Encrypt, FileKeyVersion, UserKeyPairVersion,
+        Decrypt, DracoonCrypto, DracoonRSACrypto, Encrypt, FileKey, FileKeyVersion,
+        UserKeyPairVersion,
     };
+    use mockito::Matcher;
 
     use crate::{
+        models::ContentHash,
         nodes::{FileMeta, UploadOptions},
-        public::{PublicDownloadTokenGenerateRequest, PublicUpload},
+        public::{
+            PublicDownloadOptions, PublicDownloadTokenGenerateRequest, PublicUpload,
+            PublicUploadShare,
+        },
         tests::dracoon::get_connected_client,
-        Dracoon, Public, PublicDownload,
+        Dracoon, DracoonClientError, Public, PublicDownload,
     };
 
     #[tokio::test]
@@ -347,6 +466,84 @@ mod tests {
         assert!(software_version.is_dracoon_cloud.unwrap());
     }
 
+    #[tokio::test]
+    async fn test_require_min_api_version_compatible() {
+        let mut mock_server = mockito::Server::new_async().await;
+
+        let client = Dracoon::builder()
+            .with_base_url(mock_server.url())
+            .with_client_id("client_id")
+            .with_client_secret("client_secret")
+            .build()
+            .unwrap();
+
+        let software_version_res = include_str!("../tests/responses/public/version_ok.json");
+
+        let software_version_mock = mock_server
+            .mock("GET", "/api/v4/public/software/version")
+            .with_status(200)
+            .with_body(software_version_res)
+            .with_header("content-type", "application/json")
+            .expect(3)
+            .create();
+
+        client
+            .public()
+            .require_min_api_version("4.42.0")
+            .await
+            .unwrap();
+        client
+            .public()
+            .require_min_api_version("5.4.6")
+            .await
+            .unwrap();
+        client
+            .public()
+            .require_min_api_version("5.4")
+            .await
+            .unwrap();
+
+        software_version_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_require_min_api_version_incompatible() {
+        let mut mock_server = mockito::Server::new_async().await;
+
+        let client = Dracoon::builder()
+            .with_base_url(mock_server.url())
+            .with_client_id("client_id")
+            .with_client_secret("client_secret")
+            .build()
+            .unwrap();
+
+        let software_version_res = include_str!("../tests/responses/public/version_ok.json");
+
+        let software_version_mock = mock_server
+            .mock("GET", "/api/v4/public/software/version")
+            .with_status(200)
+            .with_body(software_version_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let err = client
+            .public()
+            .require_min_api_version("5.10.0")
+            .await
+            .unwrap_err();
+
+        software_version_mock.assert();
+
+        assert!(err.is_unsupported_api_version());
+        assert_eq!(
+            err,
+            DracoonClientError::UnsupportedApiVersion {
+                required: "5.10.0".to_string(),
+                actual: "5.4.6".to_string(),
+            }
+        );
+    }
+
     #[tokio::test]
     async fn test_get_system_info_disconnected() {
         let mut mock_server = mockito::Server::new_async().await;
@@ -595,6 +792,114 @@ mod tests {
         assert_eq!(writer.buffer(), mock_compare);
     }
 
+    async fn public_download_with_hash(
+        expected_hash: ContentHash,
+    ) -> (Result<(), DracoonClientError>, Vec<u8>) {
+        let mut mock_server = mockito::Server::new_async().await;
+
+        let client = Dracoon::builder()
+            .with_base_url(mock_server.url())
+            .with_client_id("client_id")
+            .with_client_secret("client_secret")
+            .build()
+            .unwrap();
+
+        let public_download_share_res =
+            include_str!("../tests/responses/public/download_share_ok_templated.json")
+                .replace(r#""$ENCRYPTED""#, "false")
+                .replace(r#""$SIZE""#, "16")
+                .replace(r#""privateKeyContainer": "$PRIVATE_KEY_CONTAINER","#, "")
+                .replace(r#""fileKey": "$FILE_KEY","#, "");
+
+        let public_download_share_mock = mock_server
+            .mock("GET", "/api/v4/public/shares/downloads/test")
+            .with_status(200)
+            .with_body(public_download_share_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let public_download_share = client
+            .public()
+            .get_public_download_share("test")
+            .await
+            .unwrap();
+
+        public_download_share_mock.assert();
+
+        let download_url_res = format!(
+            r#"{{"downloadUrl": "{}/some/download/url"}}"#,
+            mock_server.url()
+        );
+
+        let url_mock = mock_server
+            .mock("POST", "/api/v4/public/shares/downloads/test")
+            .with_status(200)
+            .with_body(download_url_res)
+            .create();
+
+        let download_mock = mock_server
+            .mock("GET", "/some/download/url")
+            .with_status(200)
+            .with_header("content-type", "application/octet-stream")
+            .with_body(b"testtesttesttest")
+            .create();
+
+        let mut buffer = Vec::with_capacity(16);
+
+        let res = client
+            .public()
+            .download_with_options(
+                "test",
+                public_download_share,
+                Some("TopSecret1234!".to_string()),
+                &mut buffer,
+                PublicDownloadOptions::new().with_expected_hash(expected_hash),
+            )
+            .await;
+
+        url_mock.assert();
+        download_mock.assert();
+
+        (res, buffer)
+    }
+
+    #[tokio::test]
+    async fn test_public_download_with_matching_hash() {
+        let (res, buffer) =
+            public_download_with_hash(ContentHash::md5("3dd0cd797a7399b56c470612887108eb")).await;
+
+        assert!(res.is_ok());
+        assert_eq!(buffer, b"testtesttesttest");
+
+        // hex encoded hashes are compared case-insensitive
+        let (res, _) = public_download_with_hash(ContentHash::sha256(
+            "5E8B64DA785F1572E6DA780648EAAFFA009152D297BDE80F852F068B0EC2989F",
+        ))
+        .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_public_download_with_mismatching_hash() {
+        let (res, buffer) =
+            public_download_with_hash(ContentHash::md5("d41d8cd98f00b204e9800998ecf8427e")).await;
+
+        let err = res.unwrap_err();
+
+        // content is streamed - it has already been written when the mismatch is detected
+        assert_eq!(buffer, b"testtesttesttest");
+
+        assert!(err.is_hash_mismatch());
+        assert_eq!(
+            err,
+            DracoonClientError::HashMismatch {
+                expected: "d41d8cd98f00b204e9800998ecf8427e".to_string(),
+                actual: "3dd0cd797a7399b56c470612887108eb".to_string(),
+            }
+        );
+    }
+
     #[tokio::test]
     async fn test_public_download_encrypted() {
         let mut mock_server = mockito::Server::new_async().await;
@@ -837,6 +1142,210 @@ mod tests {
         assert_eq!(uploaded_file.size, 16);
     }
 
+    #[tokio::test]
+    async fn test_public_upload_encrypted() {
+        let mut mock_server = mockito::Server::new_async().await;
+
+        let client = Dracoon::builder()
+            .with_base_url(mock_server.url())
+            .with_client_id("client_id")
+            .with_client_secret("client_secret")
+            .build()
+            .unwrap();
+
+        let keypair =
+            DracoonCrypto::create_plain_user_keypair(UserKeyPairVersion::RSA4096).unwrap();
+
+        // share without public keys - keys must be fetched before upload
+        let public_upload_share_res =
+            include_str!("../tests/responses/public/upload_share_ok.json");
+        let mut public_upload_share: PublicUploadShare =
+            serde_json::from_str(public_upload_share_res).unwrap();
+        public_upload_share.is_encrypted = Some(true);
+        public_upload_share.user_user_public_key_list = None;
+
+        // share as returned by the API (encrypted, containing the public key of user 42)
+        let mut public_upload_share_json: serde_json::Value =
+            serde_json::from_str(public_upload_share_res).unwrap();
+        public_upload_share_json["isEncrypted"] = serde_json::Value::Bool(true);
+        public_upload_share_json["userUserPublicKeyList"]["items"][0] = serde_json::json!({
+            "id": 42,
+            "publicKeyContainer": keypair.public_key_container.clone(),
+        });
+        let public_upload_share_res = public_upload_share_json.to_string();
+
+        let system_info_res = include_str!("../tests/responses/public/system_info_ok.json");
+
+        let system_info_mock = mock_server
+            .mock("GET", "/api/v4/public/system/info")
+            .with_status(200)
+            .with_body(system_info_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let public_upload_share_mock = mock_server
+            .mock("GET", "/api/v4/public/shares/uploads/test")
+            .with_status(200)
+            .with_body(public_upload_share_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let channel_res = include_str!("../tests/responses/upload/upload_channel_ok.json");
+
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/public/shares/uploads/test")
+            .with_status(201)
+            .with_body(channel_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let s3_urls_res =
+            include_str!("../tests/responses/upload/s3_urls_ok_with_placeholder.json");
+        let s3_urls_res = s3_urls_res.replace("$base_url", mock_server.url().as_str());
+
+        let s3_urls_mock = mock_server
+            .mock("POST", "/api/v4/public/shares/uploads/test/string/s3_urls")
+            .with_status(201)
+            .with_body(s3_urls_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        // capture the uploaded (encrypted) bytes
+        let uploaded_bytes = Arc::new(Mutex::new(Vec::new()));
+        let uploaded_bytes_clone = uploaded_bytes.clone();
+
+        let upload_mock = mock_server
+            .mock("PUT", "/upload_url")
+            .with_status(202)
+            .with_header("etag", "string")
+            .with_body_from_request(move |request| {
+                *uploaded_bytes_clone.lock().unwrap() = request.body().unwrap().clone();
+                Vec::new()
+            })
+            .create();
+
+        // capture the completion request containing the encrypted file key
+        let complete_req = Arc::new(Mutex::new(Vec::new()));
+        let complete_req_clone = complete_req.clone();
+
+        let finalize_mock = mock_server
+            .mock("PUT", "/api/v4/public/shares/uploads/test/string/s3")
+            .match_body(Matcher::Regex(
+                r#""userFileKeyList":\[\{"userId":42,"fileKey""#.into(),
+            ))
+            .with_status(202)
+            .with_body_from_request(move |request| {
+                *complete_req_clone.lock().unwrap() = request.body().unwrap().clone();
+                Vec::new()
+            })
+            .create();
+
+        let status_mock = mock_server
+            .mock("GET", "/api/v4/public/shares/uploads/test/string")
+            .with_status(200)
+            .with_body(r#"{"status": "done", "fileName": "test.txt", "size": 16}"#)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let mock_bytes = b"testtesttesttest";
+        let reader = tokio::io::BufReader::new(mock_bytes.as_slice());
+
+        let file_meta = FileMeta::builder("test.txt", 16).build();
+        let upload_opts = UploadOptions::builder(file_meta).build();
+
+        let file_name = client
+            .public()
+            .upload("test", public_upload_share, upload_opts, reader, None, None)
+            .await
+            .unwrap();
+
+        system_info_mock.assert();
+        public_upload_share_mock.assert();
+        upload_channel_mock.assert();
+        s3_urls_mock.assert();
+        upload_mock.assert();
+        finalize_mock.assert();
+        status_mock.assert();
+
+        assert_eq!(file_name, "test.txt");
+
+        // decrypt the submitted file key and the uploaded content
+        let complete_req: serde_json::Value =
+            serde_json::from_slice(&complete_req.lock().unwrap()).unwrap();
+        let file_key: FileKey =
+            serde_json::from_value(complete_req["userFileKeyList"][0]["fileKey"].clone()).unwrap();
+        let plain_file_key = DracoonCrypto::decrypt_file_key(file_key, keypair).unwrap();
+
+        let uploaded_bytes = uploaded_bytes.lock().unwrap().clone();
+        assert_ne!(uploaded_bytes.as_slice(), mock_bytes.as_slice());
+
+        let decrypted = DracoonCrypto::decrypt(&uploaded_bytes, plain_file_key).unwrap();
+        assert_eq!(decrypted.as_slice(), mock_bytes.as_slice());
+    }
+
+    #[tokio::test]
+    async fn test_public_upload_encrypted_without_public_keys() {
+        let mut mock_server = mockito::Server::new_async().await;
+
+        let client = Dracoon::builder()
+            .with_base_url(mock_server.url())
+            .with_client_id("client_id")
+            .with_client_secret("client_secret")
+            .build()
+            .unwrap();
+
+        let public_upload_share_res =
+            include_str!("../tests/responses/public/upload_share_ok.json");
+        let mut public_upload_share: PublicUploadShare =
+            serde_json::from_str(public_upload_share_res).unwrap();
+        public_upload_share.is_encrypted = Some(true);
+        public_upload_share.user_user_public_key_list = None;
+
+        // share as returned by the API (encrypted, but without any public keys)
+        let mut public_upload_share_json: serde_json::Value =
+            serde_json::from_str(public_upload_share_res).unwrap();
+        public_upload_share_json["isEncrypted"] = serde_json::Value::Bool(true);
+        public_upload_share_json["userUserPublicKeyList"]["items"] = serde_json::json!([]);
+
+        let system_info_mock = mock_server
+            .mock("GET", "/api/v4/public/system/info")
+            .with_status(200)
+            .with_body(include_str!(
+                "../tests/responses/public/system_info_ok.json"
+            ))
+            .with_header("content-type", "application/json")
+            .create();
+
+        let public_upload_share_mock = mock_server
+            .mock("GET", "/api/v4/public/shares/uploads/test")
+            .with_status(200)
+            .with_body(public_upload_share_json.to_string())
+            .with_header("content-type", "application/json")
+            .create();
+
+        // no upload must be started
+        let upload_channel_mock = mock_server
+            .mock("POST", "/api/v4/public/shares/uploads/test")
+            .expect(0)
+            .create();
+
+        let reader = tokio::io::BufReader::new(b"testtesttesttest".as_slice());
+        let file_meta = FileMeta::builder("test.txt", 16).build();
+        let upload_opts = UploadOptions::builder(file_meta).build();
+
+        let err = client
+            .public()
+            .upload("test", public_upload_share, upload_opts, reader, None, None)
+            .await
+            .unwrap_err();
+
+        system_info_mock.assert();
+        public_upload_share_mock.assert();
+        upload_channel_mock.assert();
+
+        assert!(err.is_missing_share_public_keys());
+    }
+
     #[tokio::test]
     #[ignore = "not needed in CI (only for manual testing)"]
     async fn test_upload_unencrypted_staging() {
@@ -1004,4 +1513,71 @@ mod tests {
 
         assert_eq!(file_name, "test.txt");
     }
+
+    #[tokio::test]
+    async fn test_public_share_session_download_twice() {
+        let mut mock_server = mockito::Server::new_async().await;
+
+        let client = Dracoon::builder()
+            .with_base_url(mock_server.url())
+            .with_client_id("client_id")
+            .with_client_secret("client_secret")
+            .build()
+            .unwrap();
+
+        let public_download_share_res =
+            include_str!("../tests/responses/public/download_share_ok_templated.json")
+                .replace(r#""$ENCRYPTED""#, "false")
+                .replace(r#""$SIZE""#, "16")
+                .replace(r#""privateKeyContainer": "$PRIVATE_KEY_CONTAINER","#, "")
+                .replace(r#""fileKey": "$FILE_KEY","#, "");
+
+        let public_download_share_mock = mock_server
+            .mock("GET", "/api/v4/public/shares/downloads/test")
+            .with_status(200)
+            .with_body(public_download_share_res)
+            .with_header("content-type", "application/json")
+            .expect(2)
+            .create();
+
+        let download_url_res = format!(
+            r#"{{"downloadUrl": "{}/some/download/url"}}"#,
+            mock_server.url()
+        );
+
+        let payload = PublicDownloadTokenGenerateRequest::new("TopSecret1234!");
+        let payload = serde_json::to_string(&payload).unwrap();
+
+        let url_mock = mock_server
+            .mock("POST", "/api/v4/public/shares/downloads/test")
+            .with_status(200)
+            .with_body(download_url_res)
+            .match_body(&*payload)
+            .expect(2)
+            .create();
+
+        let mock_bytes = b"testtesttesttest".to_vec();
+
+        let download_mock = mock_server
+            .mock("GET", "/some/download/url")
+            .with_status(200)
+            .with_header("content-type", "application/octet-stream")
+            .with_body(&mock_bytes)
+            .expect(2)
+            .create();
+
+        let session = client
+            .public()
+            .share_session("test", Some("TopSecret1234!".to_string()));
+
+        for _ in 0..2 {
+            let mut writer = tokio::io::BufWriter::new(Vec::with_capacity(16));
+            session.download(&mut writer, None, None).await.unwrap();
+            assert_eq!(writer.buffer(), mock_bytes);
+        }
+
+        public_download_share_mock.assert();
+        url_mock.assert();
+        download_mock.assert();
+    }
 }
@@ -7,7 +7,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     client::{DracoonClient, DracoonErrorResponse, GetClient},
-    nodes::{S3FileUploadPart, S3UploadStatus, UploadOptions, UserUserPublicKey},
+    models::ContentHash,
+    nodes::{
+        DownloadProgressCallback, S3FileUploadPart, S3UploadStatus, UploadOptions,
+        UserUserPublicKey,
+    },
 };
 
 #[derive(Clone)]
@@ -44,6 +48,34 @@ pub struct SoftwareVersionData {
     pub is_dracoon_cloud: Option<bool>,
 }
 
+impl SoftwareVersionData {
+    /// Checks if the API version is at least the given version (e.g. "4.42.0").
+    /// Missing components are treated as 0, suffixes (e.g. "-LTS") are ignored.
+    pub fn is_api_version_at_least(&self, min: &str) -> bool {
+        let actual = parse_version(&self.rest_api_version);
+        let min = parse_version(min);
+
+        let len = actual.len().max(min.len());
+        let pad = |v: Vec<u32>| v.into_iter().chain(std::iter::repeat(0)).take(len);
+
+        pad(actual).cmp(pad(min)).is_ge()
+    }
+}
+
+fn parse_version(version: &str) -> Vec<u32> {
+    version
+        .trim()
+        .split('.')
+        .map(|part| {
+            part.chars()
+                .take_while(char::is_ascii_digit)
+                .collect::<String>()
+                .parse()
+                .unwrap_or(0)
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone, Deserialize, FromResponse)]
 #[serde(rename_all = "camelCase")]
 pub struct SystemInfo {
@@ -312,3 +344,35 @@ impl CompleteS3ShareUploadRequest {
 }
 
 pub type FileName = String;
+
+/// public download options (progress callback, chunk size, expected content hash)
+#[derive(Default)]
+pub struct PublicDownloadOptions {
+    pub callback: Option<DownloadProgressCallback>,
+    pub chunksize: Option<usize>,
+    pub expected_hash: Option<ContentHash>,
+}
+
+impl PublicDownloadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_callback(mut self, callback: DownloadProgressCallback) -> Self {
+        self.callback = Some(callback);
+        self
+    }
+
+    pub fn with_chunksize(mut self, chunksize: usize) -> Self {
+        self.chunksize = Some(chunksize);
+        self
+    }
+
+    /// Hashes the (decrypted) content while downloading and compares it with the expected hash.
+    /// The content is streamed into the writer - on a mismatch it has already been written
+    /// and must be discarded by the caller.
+    pub fn with_expected_hash(mut self, expected_hash: ContentHash) -> Self {
+        self.expected_hash = Some(expected_hash);
+        self
+    }
+}
@@ -0,0 +1,110 @@
+use secrecy::{ExposeSecret, SecretString};
+use tokio::io::AsyncWrite;
+
+use crate::{nodes::DownloadProgressCallback, DracoonClientError};
+
+use super::{
+    download::PublicDownloadInternal, Public, PublicDownload, PublicDownloadTokenGenerateRequest,
+    PublicEndpoint,
+};
+
+/// A session for a single public download share.
+/// Holds the access key and the (optional) share password, so that repeated downloads
+/// (e.g. retries) do not require passing the password again.
+pub struct PublicShareSession<'a, S> {
+    endpoint: &'a PublicEndpoint<S>,
+    access_key: String,
+    password: Option<SecretString>,
+}
+
+impl<S> PublicEndpoint<S> {
+    /// Creates a [`PublicShareSession`] for the given access key.
+    /// The password is required for protected and encrypted shares.
+    /// ```no_run
+    /// # use dco3::Dracoon;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap();
+    /// let session = dracoon.public().share_session("access_key", Some("TopSecret123!".to_string()));
+    ///
+    /// let mut writer = tokio::io::BufWriter::new(tokio::fs::File::create("test.txt").await.unwrap());
+    ///
+    /// // the password is only passed once
+    /// if session.download(&mut writer, None, None).await.is_err() {
+    ///     // recreate the file - a failed attempt may have written partial content
+    ///     let mut writer = tokio::io::BufWriter::new(tokio::fs::File::create("test.txt").await.unwrap());
+    ///     session.download(&mut writer, None, None).await.unwrap();
+    /// }
+    /// # }
+    /// ```
+    pub fn share_session(
+        &self,
+        access_key: impl Into<String>,
+        password: Option<String>,
+    ) -> PublicShareSession<'_, S> {
+        PublicShareSession {
+            endpoint: self,
+            access_key: access_key.into(),
+            password: password.map(SecretString::from),
+        }
+    }
+}
+
+impl<S: Send + Sync> PublicShareSession<'_, S> {
+    pub fn access_key(&self) -> &str {
+        &self.access_key
+    }
+
+    fn password(&self) -> Option<String> {
+        self.password
+            .as_ref()
+            .map(|password| password.expose_secret().to_string())
+    }
+
+    /// Downloads the shared file into the writer.
+    /// The share information is fetched on every call, so each attempt uses a fresh download token.
+    /// The full content is written on every call - reset (e.g. recreate or truncate) the writer
+    /// before retrying, a failed attempt may already have written partial content.
+    pub async fn download<'w>(
+        &'w self,
+        writer: &'w mut (dyn AsyncWrite + Send + Unpin),
+        callback: Option<DownloadProgressCallback>,
+        chunksize: Option<usize>,
+    ) -> Result<(), DracoonClientError> {
+        let share = self
+            .endpoint
+            .get_public_download_share(self.access_key.as_str())
+            .await?;
+
+        self.endpoint
+            .download(
+                self.access_key.as_str(),
+                share,
+                self.password(),
+                writer,
+                callback,
+                chunksize,
+            )
+            .await
+    }
+
+    /// Generates a new download token and returns the (tokenized) download url.
+    pub async fn refresh_token(&self) -> Result<String, DracoonClientError> {
+        let req = match self.password() {
+            Some(password) => PublicDownloadTokenGenerateRequest::new(password),
+            None => PublicDownloadTokenGenerateRequest::default(),
+        };
+
+        let response = self
+            .endpoint
+            .generate_download_url(self.access_key.clone(), req)
+            .await?;
+
+        Ok(response.download_url)
+    }
+}
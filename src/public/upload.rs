@@ -9,7 +9,10 @@ use crate::{
         POLLING_START_DELAY, PUBLIC_BASE, PUBLIC_SHARES_BASE, PUBLIC_UPLOAD_SHARES,
     },
     nodes::{
-        upload::{calculate_s3_url_count, StreamUploadInternal},
+        upload::{
+            calculate_chunk_offset, calculate_s3_upload_parts, calculate_upload_chunks,
+            map_upload_session_expired, resolve_s3_chunk_size, StreamUploadInternal,
+        },
         CloneableUploadProgressCallback, GeneratePresignedUrlsRequest, PresignedUrlList,
         S3FileUploadPart, S3UploadStatus, UploadOptions, UploadProgressCallback,
     },
@@ -21,6 +24,7 @@ use super::{
     CompleteS3ShareUploadRequest, CreateShareUploadChannelRequest,
     CreateShareUploadChannelResponse, FileName, PublicEndpoint, PublicUpload, PublicUploadShare,
     PublicUploadedFileData, S3ShareUploadStatus, UserFileKey, UserFileKeyList,
+    UserUserPublicKeyList,
 };
 
 #[async_trait]
@@ -61,6 +65,37 @@ impl<S: Send + Sync, R: AsyncRead + Send + Sync + Unpin + 'static> PublicUpload<
 
 impl<S> StreamUploadInternal<S> for PublicEndpoint<S> {}
 
+impl<S: Send + Sync> PublicEndpoint<S> {
+    /// Returns the public keys required to encrypt the file key for an encrypted upload share.
+    /// If the passed share does not contain any public keys, the share is fetched again.
+    /// Fails if the share still has no public keys - nobody could decrypt the file key.
+    async fn get_share_public_keys(
+        &self,
+        access_key: &str,
+        share: &PublicUploadShare,
+    ) -> Result<UserUserPublicKeyList, DracoonClientError> {
+        match share.user_user_public_key_list.as_ref() {
+            Some(public_keys) if !public_keys.items.is_empty() => Ok(public_keys.clone()),
+            _ => {
+                let share = self
+                    .get_public_upload_share(access_key)
+                    .await
+                    .map_err(|err| {
+                        error!("Error fetching public keys of upload share: {}", err);
+                        err
+                    })?;
+                match share.user_user_public_key_list {
+                    Some(public_keys) if !public_keys.items.is_empty() => Ok(public_keys),
+                    _ => {
+                        error!("Encrypted upload share has no public keys");
+                        Err(DracoonClientError::MissingSharePublicKeys)
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[async_trait]
 impl<S: Send + Sync, R: AsyncRead + Send + Sync + Unpin + 'static> PublicUploadInternal<R, S>
     for PublicEndpoint<S>
@@ -109,7 +144,9 @@ impl<S: Send + Sync, R: AsyncRead + Send + Sync + Unpin + 'static> PublicUploadI
             .send()
             .await?;
 
-        PresignedUrlList::from_response(response).await
+        PresignedUrlList::from_response(response)
+            .await
+            .map_err(map_upload_session_expired)
     }
 
     async fn upload_to_s3_unencrypted(
@@ -123,7 +160,8 @@ impl<S: Send + Sync, R: AsyncRead + Send + Sync + Unpin + 'static> PublicUploadI
     ) -> Result<FileName, DracoonClientError> {
         let fm = upload_options.file_meta.clone();
 
-        let chunk_size = chunk_size.unwrap_or(DEFAULT_UPLOAD_CHUNK_SIZE);
+        let chunk_size =
+            resolve_s3_chunk_size(fm.size, chunk_size.unwrap_or(DEFAULT_UPLOAD_CHUNK_SIZE))?;
 
         // create upload channel
         let file_upload_req =
@@ -139,7 +177,7 @@ impl<S: Send + Sync, R: AsyncRead + Send + Sync + Unpin + 'static> PublicUploadI
 
         let mut s3_parts = Vec::new();
 
-        let (count_urls, last_chunk_size) = calculate_s3_url_count(fm.size, chunk_size as u64);
+        let (count_urls, last_chunk_size) = calculate_s3_upload_parts(fm.size, chunk_size as u64)?;
         let mut url_part: u32 = 1;
 
         let cloneable_callback = callback.map(CloneableUploadProgressCallback::new);
@@ -187,10 +225,6 @@ impl<S: Send + Sync, R: AsyncRead + Send + Sync + Unpin + 'static> PublicUploadI
                             .await?;
                         let url = url.urls.first().expect("Creating S3 url failed");
 
-                        // truncation is safe because chunk_size is 32 MB
-                        #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
-                        let curr_pos: u64 = ((url_part - 1) * (chunk_size as u32)) as u64;
-
                         let e_tag = self
                             .upload_stream_to_s3(
                                 Box::pin(stream),
@@ -260,8 +294,6 @@ impl<S: Send + Sync, R: AsyncRead + Send + Sync + Unpin + 'static> PublicUploadI
 
                 let url = url.urls.first().expect("Creating S3 url failed");
 
-                let curr_pos: u64 = (url_part - 1) as u64 * (DEFAULT_UPLOAD_CHUNK_SIZE as u64);
-
                 let e_tag = self
                     .upload_stream_to_s3(
                         Box::pin(stream),
@@ -330,7 +362,10 @@ impl<S: Send + Sync, R: AsyncRead + Send + Sync + Unpin + 'static> PublicUploadI
         callback: Option<UploadProgressCallback>,
         chunk_size: Option<usize>,
     ) -> Result<FileName, DracoonClientError> {
-        let chunk_size = chunk_size.unwrap_or(DEFAULT_UPLOAD_CHUNK_SIZE);
+        let chunk_size = resolve_s3_chunk_size(
+            upload_options.file_meta.size,
+            chunk_size.unwrap_or(DEFAULT_UPLOAD_CHUNK_SIZE),
+        )?;
 
         let mut crypto_buff = vec![
             0u8;
@@ -370,7 +405,7 @@ impl<S: Send + Sync, R: AsyncRead + Send + Sync + Unpin + 'static> PublicUploadI
         // drop the crypto buffer (enc bytes are still in the reader)
         drop(crypto_buff);
 
-        let public_keys = share.user_user_public_key_list.clone().unwrap_or_default();
+        let public_keys = self.get_share_public_keys(&access_key, share).await?;
 
         let user_file_keys: Vec<_> = public_keys
             .items
@@ -405,7 +440,7 @@ impl<S: Send + Sync, R: AsyncRead + Send + Sync + Unpin + 'static> PublicUploadI
 
         let mut s3_parts = Vec::new();
 
-        let (count_urls, last_chunk_size) = calculate_s3_url_count(fm.size, chunk_size as u64);
+        let (count_urls, last_chunk_size) = calculate_s3_upload_parts(fm.size, chunk_size as u64)?;
         let mut url_part: u32 = 1;
 
         let cloneable_callback = callback.map(CloneableUploadProgressCallback::new);
@@ -464,8 +499,6 @@ impl<S: Send + Sync, R: AsyncRead + Send + Sync + Unpin + 'static> PublicUploadI
                             })?;
                         let url = url.urls.first().expect("Creating S3 url failed");
 
-                        let curr_pos: u64 = (url_part - 1) as u64 * (chunk_size as u64);
-
                         let e_tag = self
                             .upload_stream_to_s3(
                                 Box::pin(stream),
@@ -544,10 +577,6 @@ impl<S: Send + Sync, R: AsyncRead + Send + Sync + Unpin + 'static> PublicUploadI
 
                 let url = url.urls.first().expect("Creating S3 url failed");
 
-                // truncation is safe because chunk_size is 32 MB
-                #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
-                let curr_pos: u64 = ((url_part - 1) * (DEFAULT_UPLOAD_CHUNK_SIZE as u32)) as u64;
-
                 let e_tag = self
                     .upload_stream_to_s3(
                         Box::pin(stream),
@@ -778,7 +807,7 @@ impl<R: AsyncRead + Send + Sync + Unpin + 'static, S: Send + Sync> PublicUploadI
                 err
             })?;
 
-        let (count_chunks, last_chunk_size) = calculate_s3_url_count(fm.size, chunk_size as u64);
+        let (count_chunks, last_chunk_size) = calculate_upload_chunks(fm.size, chunk_size as u64)?;
         let mut chunk_part: u32 = 1;
 
         let cloneable_callback = callback.map(CloneableUploadProgressCallback::new);
@@ -817,9 +846,7 @@ impl<R: AsyncRead + Send + Sync + Unpin + 'static, S: Send + Sync> PublicUploadI
 
                         let url = upload_channel.upload_url.clone();
 
-                        // truncation is safe because chunk_size is 32 MB
-                        #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
-                        let curr_pos: u64 = ((chunk_part - 1) * (chunk_size as u32)) as u64;
+                        let curr_pos = calculate_chunk_offset(chunk_part, chunk_size);
 
                         self.upload_stream_to_nfs(
                             Box::pin(stream),
@@ -875,7 +902,7 @@ impl<R: AsyncRead + Send + Sync + Unpin + 'static, S: Send + Sync> PublicUploadI
 
                 let url = upload_channel.upload_url.clone();
 
-                let curr_pos: u64 = (chunk_part - 1) as u64 * (DEFAULT_UPLOAD_CHUNK_SIZE as u64);
+                let curr_pos = calculate_chunk_offset(chunk_part, chunk_size);
 
                 let e_tag = self
                     .upload_stream_to_nfs(
@@ -958,7 +985,7 @@ impl<R: AsyncRead + Send + Sync + Unpin + 'static, S: Send + Sync> PublicUploadI
         // drop the crypto buffer (enc bytes are still in the reader)
         drop(crypto_buff);
 
-        let public_keys = share.user_user_public_key_list.clone().unwrap_or_default();
+        let public_keys = self.get_share_public_keys(&access_key, share).await?;
 
         let user_file_keys: Vec<_> = public_keys
             .items
@@ -993,7 +1020,7 @@ impl<R: AsyncRead + Send + Sync + Unpin + 'static, S: Send + Sync> PublicUploadI
                 err
             })?;
 
-        let (count_chunks, last_chunk_size) = calculate_s3_url_count(fm.size, chunk_size as u64);
+        let (count_chunks, last_chunk_size) = calculate_upload_chunks(fm.size, chunk_size as u64)?;
         let mut chunk_part: u32 = 1;
 
         let cloneable_callback = callback.map(CloneableUploadProgressCallback::new);
@@ -1033,7 +1060,7 @@ impl<R: AsyncRead + Send + Sync + Unpin + 'static, S: Send + Sync> PublicUploadI
 
                         let url = upload_channel.upload_url.clone();
 
-                        let curr_pos: u64 = (chunk_part - 1) as u64 * (chunk_size as u64);
+                        let curr_pos = calculate_chunk_offset(chunk_part, chunk_size);
 
                         self.upload_stream_to_nfs(
                             Box::pin(stream),
@@ -1090,9 +1117,7 @@ impl<R: AsyncRead + Send + Sync + Unpin + 'static, S: Send + Sync> PublicUploadI
 
                 let url = upload_channel.upload_url.clone();
 
-                // truncation is safe because chunk_size is 32 MB
-                #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
-                let curr_pos: u64 = ((chunk_part - 1) * (DEFAULT_UPLOAD_CHUNK_SIZE as u32)) as u64;
+                let curr_pos = calculate_chunk_offset(chunk_part, chunk_size);
 
                 self.upload_stream_to_nfs(
                     Box::pin(stream),
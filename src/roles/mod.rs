@@ -170,4 +170,24 @@ pub trait Roles {
         role_id: u64,
         user_ids: RevokeRoleBatchRequest,
     ) -> Result<RoleUserList, DracoonClientError>;
+    /// Get the rights of the current user (aggregated and deduplicated across all assigned roles).
+    /// The rights are only requested once and cached afterwards.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Roles};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let rights = dracoon.roles().get_effective_rights().await.unwrap();
+    /// let can_manage_users = rights.iter().any(|right| right == "manage_users");
+    /// # }
+    /// ```
+    async fn get_effective_rights(&self) -> Result<Vec<String>, DracoonClientError>;
 }
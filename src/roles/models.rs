@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     client::{DracoonClient, DracoonErrorResponse},
-    models::RangedItems,
+    models::{Container, RangedItems},
     nodes::UserInfo,
     utils::{parse_body, FromResponse},
     DracoonClientError,
@@ -15,6 +15,7 @@ use crate::{
 #[derive(Clone)]
 pub struct RolesEndpoint<S> {
     client: Arc<DracoonClient<S>>,
+    effective_rights: Container<Vec<String>>,
     state: std::marker::PhantomData<S>,
 }
 
@@ -22,6 +23,7 @@ impl<S> RolesEndpoint<S> {
     pub fn new(client: Arc<DracoonClient<S>>) -> Self {
         Self {
             client,
+            effective_rights: Container::new(),
             state: std::marker::PhantomData,
         }
     }
@@ -29,6 +31,10 @@ impl<S> RolesEndpoint<S> {
     pub fn client(&self) -> &Arc<DracoonClient<S>> {
         &self.client
     }
+
+    pub(crate) fn effective_rights(&self) -> &Container<Vec<String>> {
+        &self.effective_rights
+    }
 }
 
 pub type RoleGroupList = RangedItems<RoleGroup>;
@@ -1,9 +1,14 @@
+use std::collections::BTreeSet;
+
 use async_trait::async_trait;
 use reqwest::header;
 
 use crate::{
     client::Connected,
-    constants::{DRACOON_API_PREFIX, ROLES_BASE, ROLES_GROUPS, ROLES_USERS},
+    constants::{
+        DRACOON_API_PREFIX, ROLES_BASE, ROLES_GROUPS, ROLES_USERS, USER_ACCOUNT, USER_BASE,
+    },
+    user::UserAccount,
     utils::FromResponse,
     DracoonClientError, ListAllParams,
 };
@@ -182,4 +187,42 @@ impl Roles for RolesEndpoint<Connected> {
 
         RoleUserList::from_response(response).await
     }
+
+    async fn get_effective_rights(&self) -> Result<Vec<String>, DracoonClientError> {
+        if let Some(rights) = self.effective_rights().get().await {
+            return Ok(rights);
+        }
+
+        let url_part = format!("{DRACOON_API_PREFIX}/{USER_BASE}/{USER_ACCOUNT}");
+
+        let url = self.client().build_api_url(&url_part);
+
+        let response = self
+            .client()
+            .http
+            .get(url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .header(header::CONTENT_TYPE, "application/json")
+            .send()
+            .await?;
+
+        let user_account = UserAccount::from_response(response).await?;
+
+        let rights = user_account
+            .user_roles
+            .items
+            .into_iter()
+            .flat_map(|role| role.items.unwrap_or_default())
+            .map(|right| right.name)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        self.effective_rights().set(rights.clone()).await;
+
+        Ok(rights)
+    }
 }
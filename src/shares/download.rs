@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use reqwest::header;
+use tracing::error;
 
 use crate::constants::{DRACOON_API_PREFIX, SHARES_BASE, SHARES_DOWNLOAD, SHARES_EMAIL};
 use crate::models::ListAllParams;
@@ -46,6 +47,21 @@ impl DownloadShares for SharesEndpoint<Connected> {
         DownloadSharesList::from_response(response).await
     }
 
+    async fn get_node_download_shares(
+        &self,
+        node_id: u64,
+        params: Option<ListAllParams>,
+    ) -> Result<DownloadSharesList, DracoonClientError> {
+        let mut params = params.unwrap_or_default();
+
+        params
+            .filter
+            .get_or_insert_with(Vec::new)
+            .push(DownloadSharesFilter::node_id_equals(node_id).into());
+
+        self.get_download_shares(Some(params)).await
+    }
+
     async fn update_download_shares(
         &self,
         update: UpdateDownloadSharesBulkRequest,
@@ -221,6 +237,8 @@ impl DownloadShares for SharesEndpoint<Connected> {
         download_share_id: u64,
         email: DownloadShareLinkEmail,
     ) -> Result<(), DracoonClientError> {
+        email.validate()?;
+
         let url_part = format!(
             "{DRACOON_API_PREFIX}/{SHARES_BASE}/{SHARES_DOWNLOAD}/{id}/{SHARES_EMAIL}",
             id = download_share_id
@@ -249,4 +267,28 @@ impl DownloadShares for SharesEndpoint<Connected> {
 
         Ok(())
     }
+
+    async fn create_and_send(
+        &self,
+        create: CreateDownloadShareRequest,
+        email: DownloadShareLinkEmail,
+    ) -> Result<DownloadShare, DracoonClientError> {
+        // fail before creating the share
+        email.validate()?;
+
+        let share = self.create_download_share(create).await?;
+
+        if let Err(err) = self.send_download_share_email(share.id, email).await {
+            error!("Error sending download share email: {}", err);
+
+            // roll back the share - the email error is returned in any case
+            if let Err(delete_err) = self.delete_download_share(share.id).await {
+                error!("Error deleting download share {}: {}", share.id, delete_err);
+            }
+
+            return Err(err);
+        }
+
+        Ok(share)
+    }
 }
@@ -40,6 +40,33 @@ pub trait DownloadShares {
         &self,
         params: Option<ListAllParams>,
     ) -> Result<DownloadSharesList, DracoonClientError>;
+    /// Get a list of shares (download shares) of a node.
+    /// The node filter is added to the passed params (filters and sorting are kept).
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, DownloadShares};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let shares = dracoon.shares().get_node_download_shares(123, None).await.unwrap();
+    ///
+    /// for share in shares.items {
+    ///     println!("{}: {}", share.name, share.public_url(dracoon.get_base_url()));
+    /// }
+    /// # }
+    /// ```
+    async fn get_node_download_shares(
+        &self,
+        node_id: u64,
+        params: Option<ListAllParams>,
+    ) -> Result<DownloadSharesList, DracoonClientError>;
     /// Update list shares (download shares).
     /// ```no_run
     /// # use dco3::{Dracoon, auth::OAuth2Flow, DownloadShares, shares::{UpdateDownloadSharesBulkRequest}};
@@ -176,7 +203,8 @@ pub trait DownloadShares {
     /// # }
     /// ```
     async fn delete_download_share(&self, share_id: u64) -> Result<(), DracoonClientError>;
-    /// Send download share via email
+    /// Send download share via email (e.g. to re-notify recipients of an existing share).
+    /// Recipients are validated before the request is sent (at least one well-formed address).
     /// ```no_run
     /// # use dco3::{Dracoon, auth::OAuth2Flow, DownloadShares, shares::{DownloadShareLinkEmail}};
     /// # #[tokio::main]
@@ -200,6 +228,35 @@ pub trait DownloadShares {
         share_id: u64,
         email: DownloadShareLinkEmail,
     ) -> Result<(), DracoonClientError>;
+    /// Create download share and send it via email.
+    /// If sending the email fails, the created share is deleted again and the email error is returned.
+    /// Invalid recipients are rejected before the share is created.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, DownloadShares, shares::{CreateDownloadShareRequest, DownloadShareLinkEmail}};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let share = CreateDownloadShareRequest::builder(1)
+    ///     .with_name("test")
+    ///     .build();
+    /// let recipients = vec!["test@test.foo".to_string()];
+    /// let send_mail = DownloadShareLinkEmail::new("Test email", recipients, None);
+    /// let share = dracoon.shares().create_and_send(share, send_mail).await.unwrap();
+    /// # }
+    /// ```
+    async fn create_and_send(
+        &self,
+        create: CreateDownloadShareRequest,
+        email: DownloadShareLinkEmail,
+    ) -> Result<DownloadShare, DracoonClientError>;
 }
 
 /// This trait provides all methods to manage upload shares.
@@ -373,7 +430,8 @@ pub trait UploadShares {
     /// # }
     /// ```
     async fn delete_upload_share(&self, upload_share_id: u64) -> Result<(), DracoonClientError>;
-    /// Send upload share via email
+    /// Send upload share via email.
+    /// Recipients are validated before the request is sent (at least one well-formed address).
     /// ```no_run
     /// # use dco3::{Dracoon, auth::OAuth2Flow, UploadShares, shares::{UploadShareLinkEmail}};
     /// # #[tokio::main]
@@ -397,4 +455,26 @@ pub trait UploadShares {
         upload_share_id: u64,
         email: UploadShareLinkEmail,
     ) -> Result<(), DracoonClientError>;
+    /// Show or hide uploaded files of an upload share (file request)
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, UploadShares};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let share = dracoon.shares().set_show_uploaded_files(123, true).await.unwrap();
+    /// # }
+    /// ```
+    async fn set_show_uploaded_files(
+        &self,
+        upload_share_id: u64,
+        show_uploaded_files: bool,
+    ) -> Result<UploadShare, DracoonClientError>;
 }
@@ -1,12 +1,14 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use dco3_crypto::UserKeyPairContainer;
+use dco3_crypto::{FileKey, UserKeyPairContainer};
 use dco3_derive::FromResponse;
 use reqwest::Response;
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 use crate::{
     client::DracoonErrorResponse,
+    constants::PUBLIC_DOWNLOAD_SHARE_LINK,
     models::{FilterOperator, FilterQuery, ObjectExpiration, RangedItems, SortOrder, SortQuery},
     nodes::models::{NodeType, UserInfo},
     utils::{parse_body, FromResponse},
@@ -38,6 +40,16 @@ pub struct DownloadShare {
     pub expire_at: Option<DateTime<Utc>>,
 }
 
+impl DownloadShare {
+    /// Returns the public link of the share (e.g. `https://dracoon.team/public/download-shares/{access_key}`)
+    /// Pass the base url of the DRACOON instance (see `Dracoon::get_base_url`).
+    pub fn public_url(&self, base_url: &Url) -> Url {
+        base_url
+            .join(&format!("{PUBLIC_DOWNLOAD_SHARE_LINK}/{}", self.access_key))
+            .expect("Invalid base url or access key")
+    }
+}
+
 pub type DownloadSharesList = RangedItems<DownloadShare>;
 
 #[derive(Debug, Serialize, Clone)]
@@ -63,10 +75,22 @@ pub struct CreateDownloadShareRequest {
     receiver_language: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     text_message_recipients: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", rename = "keyPair")]
     keypair: Option<UserKeyPairContainer>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    file_key: Option<FileKey>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     max_downloads: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notify_creator: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    send_mail: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mail_recipients: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mail_subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mail_body: Option<String>,
 }
 
 impl CreateDownloadShareRequest {
@@ -87,7 +111,13 @@ pub struct CreateDownloadShareRequestBuilder {
     receiver_language: Option<String>,
     text_message_recipients: Option<Vec<String>>,
     keypair: Option<UserKeyPairContainer>,
+    file_key: Option<FileKey>,
     max_downloads: Option<u32>,
+    notify_creator: Option<bool>,
+    send_mail: Option<bool>,
+    mail_recipients: Option<String>,
+    mail_subject: Option<String>,
+    mail_body: Option<String>,
 }
 
 impl CreateDownloadShareRequestBuilder {
@@ -104,7 +134,13 @@ impl CreateDownloadShareRequestBuilder {
             receiver_language: None,
             text_message_recipients: None,
             keypair: None,
+            file_key: None,
             max_downloads: None,
+            notify_creator: None,
+            send_mail: None,
+            mail_recipients: None,
+            mail_subject: None,
+            mail_body: None,
         }
     }
 
@@ -153,16 +189,52 @@ impl CreateDownloadShareRequestBuilder {
         self
     }
 
+    /// Sets the share key pair (required for encrypted nodes)
+    /// The private key needs to be encrypted with the share password.
     pub fn with_keypair(mut self, keypair: UserKeyPairContainer) -> Self {
         self.keypair = Some(keypair);
         self
     }
 
+    /// Sets the file key encrypted with the public key of the share key pair (required for encrypted files)
+    pub fn with_file_key(mut self, file_key: FileKey) -> Self {
+        self.file_key = Some(file_key);
+        self
+    }
+
     pub fn with_max_downloads(mut self, max_downloads: u32) -> Self {
         self.max_downloads = Some(max_downloads);
         self
     }
 
+    /// Notifies the creator on every download
+    pub fn with_notify_creator(mut self, notify_creator: bool) -> Self {
+        self.notify_creator = Some(notify_creator);
+        self
+    }
+
+    /// Sends the share link to the mail recipients on creation
+    /// Note: `create_and_send` is the preferred way to send a share link via email.
+    pub fn with_send_mail(mut self, send_mail: bool) -> Self {
+        self.send_mail = Some(send_mail);
+        self
+    }
+
+    pub fn with_mail_recipients(mut self, mail_recipients: Vec<String>) -> Self {
+        self.mail_recipients = Some(mail_recipients.join(","));
+        self
+    }
+
+    pub fn with_mail_subject(mut self, mail_subject: impl Into<String>) -> Self {
+        self.mail_subject = Some(mail_subject.into());
+        self
+    }
+
+    pub fn with_mail_body(mut self, mail_body: impl Into<String>) -> Self {
+        self.mail_body = Some(mail_body.into());
+        self
+    }
+
     pub fn build(self) -> CreateDownloadShareRequest {
         CreateDownloadShareRequest {
             node_id: self.node_id,
@@ -176,7 +248,13 @@ impl CreateDownloadShareRequestBuilder {
             receiver_language: self.receiver_language,
             text_message_recipients: self.text_message_recipients,
             keypair: self.keypair,
+            file_key: self.file_key,
             max_downloads: self.max_downloads,
+            notify_creator: self.notify_creator,
+            send_mail: self.send_mail,
+            mail_recipients: self.mail_recipients,
+            mail_subject: self.mail_subject,
+            mail_body: self.mail_body,
         }
     }
 }
@@ -638,4 +716,9 @@ impl DownloadShareLinkEmail {
             receiver_language,
         }
     }
+
+    /// Validates the recipients (at least one well-formed address)
+    pub(crate) fn validate(&self) -> Result<(), DracoonClientError> {
+        super::validate_email_recipients(&self.recipients)
+    }
 }
@@ -6,7 +6,7 @@ use std::sync::Arc;
 pub use download::*;
 pub use upload::*;
 
-use crate::client::DracoonClient;
+use crate::{client::DracoonClient, DracoonClientError};
 
 #[derive(Clone)]
 pub struct SharesEndpoint<S> {
@@ -26,3 +26,27 @@ impl<S> SharesEndpoint<S> {
         &self.client
     }
 }
+
+/// helper to validate email recipients before sending a share link
+/// requires at least one recipient - each address needs a non-empty local part and domain
+pub(crate) fn validate_email_recipients(recipients: &[String]) -> Result<(), DracoonClientError> {
+    if recipients.is_empty() {
+        return Err(DracoonClientError::InvalidEmailRecipients(
+            "at least one recipient required".to_string(),
+        ));
+    }
+
+    for recipient in recipients {
+        let is_valid = recipient.split_once('@').is_some_and(|(local, domain)| {
+            !local.is_empty() && !domain.is_empty() && !domain.contains('@')
+        }) && !recipient.chars().any(char::is_whitespace);
+
+        if !is_valid {
+            return Err(DracoonClientError::InvalidEmailRecipients(format!(
+                "malformed address '{recipient}'"
+            )));
+        }
+    }
+
+    Ok(())
+}
@@ -70,6 +70,11 @@ impl UploadShareLinkEmail {
             receiver_language,
         }
     }
+
+    /// Validates the recipients (at least one well-formed address)
+    pub(crate) fn validate(&self) -> Result<(), DracoonClientError> {
+        super::validate_email_recipients(&self.recipients)
+    }
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -218,6 +218,8 @@ impl UploadShares for SharesEndpoint<Connected> {
         upload_share_id: u64,
         email: UploadShareLinkEmail,
     ) -> Result<(), DracoonClientError> {
+        email.validate()?;
+
         let url_part = format!(
             "{DRACOON_API_PREFIX}/{SHARES_BASE}/{SHARES_UPLOAD}/{id}/{SHARES_EMAIL}",
             id = upload_share_id
@@ -246,4 +248,16 @@ impl UploadShares for SharesEndpoint<Connected> {
 
         Ok(())
     }
+
+    async fn set_show_uploaded_files(
+        &self,
+        upload_share_id: u64,
+        show_uploaded_files: bool,
+    ) -> Result<UploadShare, DracoonClientError> {
+        let update = UpdateUploadShareRequest::builder()
+            .with_show_uploaded_files(show_uploaded_files)
+            .build();
+
+        self.update_upload_share(upload_share_id, update).await
+    }
 }
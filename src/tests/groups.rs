@@ -2,6 +2,7 @@
 mod tests {
 
     use chrono::DateTime;
+    use futures_util::TryStreamExt;
 
     use crate::*;
 
@@ -104,6 +105,87 @@ mod tests {
         assert_eq!(group.updated_by.as_ref().unwrap().avatar_uuid, "string");
     }
 
+    fn groups_page(offset: u64, id: u64, cnt_users: u64) -> String {
+        include_str!("./responses/groups/groups_ok.json")
+            .replace(
+                r#""offset": 0,
+      "limit": 0,
+      "total": 1"#,
+                &format!(
+                    r#""offset": {offset},
+      "limit": 1,
+      "total": 2"#
+                ),
+            )
+            .replace(r#""id": 1,"#, &format!(r#""id": {id},"#))
+            .replace(r#""cntUsers": 1,"#, &format!(r#""cntUsers": {cnt_users},"#))
+    }
+
+    #[tokio::test]
+    async fn test_get_all_groups() {
+        let (dracoon, mock_server) = get_connected_client().await;
+        let mut mock_server = mock_server;
+
+        let first_page_mock = mock_server
+            .mock("GET", "/api/v4/groups?offset=0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(groups_page(0, 1, 3))
+            .create();
+
+        let second_page_mock = mock_server
+            .mock("GET", "/api/v4/groups?offset=1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(groups_page(1, 2, 5))
+            .create();
+
+        let groups = dracoon.groups().get_all_groups(None).await.unwrap();
+
+        first_page_mock.assert();
+        second_page_mock.assert();
+
+        assert_eq!(groups.items.len(), 2);
+        assert_eq!(groups.range.total, 2);
+        assert_eq!(groups.items[0].id, 1);
+        assert_eq!(groups.items[0].cnt_users, Some(3));
+        assert_eq!(groups.items[1].id, 2);
+        assert_eq!(groups.items[1].cnt_users, Some(5));
+    }
+
+    #[tokio::test]
+    async fn test_get_groups_stream() {
+        let (dracoon, mock_server) = get_connected_client().await;
+        let mut mock_server = mock_server;
+
+        let first_page_mock = mock_server
+            .mock("GET", "/api/v4/groups?offset=0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(groups_page(0, 1, 3))
+            .create();
+
+        let second_page_mock = mock_server
+            .mock("GET", "/api/v4/groups?offset=1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(groups_page(1, 2, 5))
+            .create();
+
+        let groups: Vec<_> = dracoon
+            .groups()
+            .get_groups_stream(None)
+            .try_collect()
+            .await
+            .unwrap();
+
+        first_page_mock.assert();
+        second_page_mock.assert();
+
+        let cnt_users: Vec<_> = groups.iter().map(|group| group.cnt_users).collect();
+        assert_eq!(cnt_users, vec![Some(3), Some(5)]);
+    }
+
     #[tokio::test]
     async fn test_get_groups_with_filter() {
         let (dracoon, mock_server) = get_connected_client().await;
@@ -345,4 +427,216 @@ mod tests {
 
         groups_mock.assert();
     }
+
+    fn group_users_page(user_ids: &[i64]) -> String {
+        let mut users: serde_json::Value =
+            serde_json::from_str(include_str!("./responses/groups/group_users_ok.json")).unwrap();
+        let user = users["items"][0].clone();
+
+        users["items"] = user_ids
+            .iter()
+            .map(|id| {
+                let mut user = user.clone();
+                user["userInfo"]["id"] = (*id).into();
+                user
+            })
+            .collect();
+        users["range"]["total"] = user_ids.len().into();
+
+        users.to_string()
+    }
+
+    #[tokio::test]
+    async fn test_find_and_merge_duplicate_groups() {
+        let (dracoon, mock_server) = get_connected_client().await;
+        let mut mock_server = mock_server;
+
+        let groups_res = include_str!("./responses/groups/groups_duplicates_ok.json");
+
+        let groups_mock = mock_server
+            .mock("GET", "/api/v4/groups?offset=0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(groups_res)
+            .create();
+
+        let duplicates = dracoon.groups().find_duplicate_groups().await.unwrap();
+
+        groups_mock.assert();
+        assert_eq!(duplicates.len(), 1);
+        let ids: Vec<_> = duplicates[0].iter().map(|group| group.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+
+        let keep_users_mock = mock_server
+            .mock("GET", "/api/v4/groups/1/users?offset=0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(group_users_page(&[1]))
+            .create();
+
+        let merge_users_mock = mock_server
+            .mock("GET", "/api/v4/groups/2/users?offset=0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(group_users_page(&[1, 2]))
+            .create();
+
+        // only users not yet member of the kept group are added
+        let add_users_mock = mock_server
+            .mock("POST", "/api/v4/groups/1/users")
+            .match_body(mockito::Matcher::JsonString(r#"{"ids":[2]}"#.into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/groups/group_ok.json"))
+            .create();
+
+        let delete_mock = mock_server
+            .mock("DELETE", "/api/v4/groups/2")
+            .with_status(204)
+            .create();
+
+        let group_mock = mock_server
+            .mock("GET", "/api/v4/groups/1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/groups/group_ok.json"))
+            .create();
+
+        // the kept group is never deleted
+        let result = dracoon.groups().merge_groups(1, vec![2, 1]).await.unwrap();
+
+        keep_users_mock.assert();
+        merge_users_mock.assert();
+        add_users_mock.assert();
+        delete_mock.assert();
+        group_mock.assert();
+        assert_eq!(result.group.id, 1);
+        assert_eq!(result.merged_ids, vec![2]);
+        assert!(result.failed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_merge_groups_partial_failure() {
+        let (dracoon, mock_server) = get_connected_client().await;
+        let mut mock_server = mock_server;
+
+        let keep_users_mock = mock_server
+            .mock("GET", "/api/v4/groups/1/users?offset=0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(group_users_page(&[1]))
+            .create();
+
+        let merge_users_mocks = [(2, vec![2]), (3, vec![2, 3])]
+            .into_iter()
+            .map(|(group_id, user_ids)| {
+                mock_server
+                    .mock(
+                        "GET",
+                        format!("/api/v4/groups/{group_id}/users?offset=0").as_str(),
+                    )
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body(group_users_page(&user_ids))
+                    .create()
+            })
+            .collect::<Vec<_>>();
+
+        // members of all merged groups are added at once
+        let add_users_mock = mock_server
+            .mock("POST", "/api/v4/groups/1/users")
+            .match_body(mockito::Matcher::JsonString(r#"{"ids":[2,3]}"#.into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/groups/group_ok.json"))
+            .expect(1)
+            .create();
+
+        let delete_mock = mock_server
+            .mock("DELETE", "/api/v4/groups/2")
+            .with_status(204)
+            .create();
+
+        let delete_error_mock = mock_server
+            .mock("DELETE", "/api/v4/groups/3")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/error_not_found.json"))
+            .create();
+
+        let group_mock = mock_server
+            .mock("GET", "/api/v4/groups/1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/groups/group_ok.json"))
+            .create();
+
+        let result = dracoon.groups().merge_groups(1, vec![2, 3]).await.unwrap();
+
+        keep_users_mock.assert();
+        merge_users_mocks.iter().for_each(|mock| mock.assert());
+        add_users_mock.assert();
+        delete_mock.assert();
+        delete_error_mock.assert();
+        group_mock.assert();
+
+        assert_eq!(result.group.id, 1);
+        assert_eq!(result.merged_ids, vec![2]);
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(result.failed[0].0, 3);
+        assert!(result.failed[0].1.is_not_found());
+    }
+
+    #[tokio::test]
+    async fn test_merge_groups_no_delete_on_member_error() {
+        let (dracoon, mock_server) = get_connected_client().await;
+        let mut mock_server = mock_server;
+
+        let keep_users_mock = mock_server
+            .mock("GET", "/api/v4/groups/1/users?offset=0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(group_users_page(&[1]))
+            .create();
+
+        let merge_users_mock = mock_server
+            .mock("GET", "/api/v4/groups/2/users?offset=0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(group_users_page(&[2]))
+            .create();
+
+        let merge_users_error_mock = mock_server
+            .mock("GET", "/api/v4/groups/3/users?offset=0")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/error_not_found.json"))
+            .create();
+
+        let add_users_mock = mock_server
+            .mock("POST", "/api/v4/groups/1/users")
+            .expect(0)
+            .create();
+
+        let delete_mock = mock_server
+            .mock(
+                "DELETE",
+                mockito::Matcher::Regex(r"^/api/v4/groups/\d+$".into()),
+            )
+            .expect(0)
+            .create();
+
+        let err = dracoon
+            .groups()
+            .merge_groups(1, vec![2, 3])
+            .await
+            .unwrap_err();
+
+        keep_users_mock.assert();
+        merge_users_mock.assert();
+        merge_users_error_mock.assert();
+        add_users_mock.assert();
+        delete_mock.assert();
+        assert!(err.is_not_found());
+    }
 }
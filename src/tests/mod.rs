@@ -9,7 +9,7 @@ mod users;
 
 #[cfg(test)]
 pub mod dracoon {
-    use dco3_crypto::DracoonCryptoError;
+    use dco3_crypto::{DracoonCrypto, DracoonCryptoError, DracoonRSACrypto};
 
     use crate::*;
     //use dco3_crypto::DracoonCryptoError;
@@ -93,6 +93,29 @@ pub mod dracoon {
         kp_mock.assert();
     }
 
+    #[tokio::test]
+    async fn test_get_keypair_concurrent_single_fetch() {
+        let (dracoon, mock_server) = get_connected_client().await;
+        let mut mock_server = mock_server;
+
+        let kp_res = include_str!("./responses/keypair_ok.json");
+
+        let kp_mock = mock_server
+            .mock("GET", "/api/v4/user/account/keypair")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(kp_res)
+            .expect(1)
+            .create();
+
+        let calls = (0..10).map(|_| dracoon.get_keypair(Some("TopSecret1234!".to_string())));
+
+        let keypairs = futures_util::future::join_all(calls).await;
+
+        assert!(keypairs.iter().all(|kp| kp.is_ok()));
+        kp_mock.assert();
+    }
+
     #[tokio::test]
     async fn test_get_keypair_wrong_secret() {
         let (dracoon, mock_server) = get_connected_client().await;
@@ -131,6 +154,244 @@ pub mod dracoon {
         assert_eq!(err, DracoonClientError::MissingEncryptionSecret);
     }
 
+    #[tokio::test]
+    async fn test_clear_keypair() {
+        let (dracoon, mock_server) = get_connected_client().await;
+        let mut mock_server = mock_server;
+
+        let kp_res = include_str!("./responses/keypair_ok.json");
+
+        let kp_mock = mock_server
+            .mock("GET", "/api/v4/user/account/keypair")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(kp_res)
+            .expect(1)
+            .create();
+
+        dracoon
+            .get_keypair(Some("TopSecret1234!".to_string()))
+            .await
+            .unwrap();
+        assert!(dracoon.get_keypair(None).await.is_ok());
+
+        dracoon.clear_keypair().await;
+
+        let err = dracoon.get_keypair(None).await.unwrap_err();
+
+        kp_mock.assert();
+        assert_eq!(err, DracoonClientError::MissingEncryptionSecret);
+    }
+
+    #[tokio::test]
+    async fn test_set_keypair_secret() {
+        let (dracoon, mock_server) = get_connected_client().await;
+        let mut mock_server = mock_server;
+
+        let kp_res = include_str!("./responses/keypair_ok.json");
+
+        let kp_mock = mock_server
+            .mock("GET", "/api/v4/user/account/keypair")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(kp_res)
+            .expect(3)
+            .create();
+
+        dracoon
+            .get_keypair(Some("TopSecret1234!".to_string()))
+            .await
+            .unwrap();
+
+        // a wrong secret keeps the cached keypair
+        let err = dracoon
+            .set_keypair_secret("WrongSecret".to_string())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            DracoonClientError::CryptoError(DracoonCryptoError::RsaOperationFailed)
+        );
+        assert!(dracoon.get_keypair(None).await.is_ok());
+
+        // a valid secret always fetches the keypair again
+        dracoon
+            .set_keypair_secret("TopSecret1234!".to_string())
+            .await
+            .unwrap();
+
+        kp_mock.assert();
+        assert!(dracoon.get_keypair(None).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_keypair_file_roundtrip() {
+        let (dracoon, mock_server) = get_connected_client().await;
+        let mut mock_server = mock_server;
+
+        let kp_res = include_str!("./responses/keypair_ok.json");
+
+        let kp_mock = mock_server
+            .mock("GET", "/api/v4/user/account/keypair")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(kp_res)
+            .expect(1)
+            .create();
+
+        let path = std::env::temp_dir().join(format!("dco3_{}_keypair.json", std::process::id()));
+
+        dracoon
+            .user()
+            .cache_keypair_to_file(&path, "TopSecret1234!")
+            .await
+            .unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        assert!(dracoon.get_keypair(None).await.is_err());
+
+        // a wrong secret keeps the cached keypair (none)
+        let err = dracoon
+            .user()
+            .load_keypair_from_file(&path, "WrongSecret")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            DracoonClientError::CryptoError(DracoonCryptoError::RsaOperationFailed)
+        );
+        assert!(dracoon.get_keypair(None).await.is_err());
+
+        // restored without fetching the keypair again
+        dracoon
+            .user()
+            .load_keypair_from_file(&path, "TopSecret1234!")
+            .await
+            .unwrap();
+
+        kp_mock.assert();
+
+        let keypair = dracoon.get_keypair(None).await.unwrap();
+        let expected =
+            DracoonCrypto::decrypt_keypair("TopSecret1234!", serde_json::from_str(kp_res).unwrap())
+                .unwrap();
+        assert_eq!(
+            keypair.private_key_container.private_key,
+            expected.private_key_container.private_key
+        );
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cache_keypair_to_file_wrong_secret() {
+        let (dracoon, mock_server) = get_connected_client().await;
+        let mut mock_server = mock_server;
+
+        let kp_mock = mock_server
+            .mock("GET", "/api/v4/user/account/keypair")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/keypair_ok.json"))
+            .create();
+
+        let path = std::env::temp_dir().join(format!(
+            "dco3_{}_keypair_wrong_secret.json",
+            std::process::id()
+        ));
+
+        let err = dracoon
+            .user()
+            .cache_keypair_to_file(&path, "WrongSecret")
+            .await
+            .unwrap_err();
+
+        kp_mock.assert();
+
+        assert!(matches!(err, DracoonClientError::CryptoError(_)));
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_raw_request() {
+        let (dracoon, mock_server) = get_connected_client().await;
+        let mut mock_server = mock_server;
+
+        let nodes_res = include_str!("./responses/nodes/nodes_ok.json");
+
+        let nodes_mock = mock_server
+            .mock("GET", "/api/v4/nodes?offset=0")
+            .match_header("authorization", "Bearer access_token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(nodes_res)
+            .create();
+
+        let response = dracoon
+            .raw_request(reqwest::Method::GET, "/api/v4/nodes?offset=0", None)
+            .await
+            .unwrap();
+
+        nodes_mock.assert();
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+        let body: serde_json::Value = response.json().await.unwrap();
+        assert_eq!(body["items"].as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_raw_request_with_body() {
+        let (dracoon, mock_server) = get_connected_client().await;
+        let mut mock_server = mock_server;
+
+        let folder_mock = mock_server
+            .mock("POST", "/api/v4/nodes/folders")
+            .match_body(mockito::Matcher::Json(
+                serde_json::json!({"name": "test", "parentId": 1}),
+            ))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/error_not_found.json"))
+            .create();
+
+        let response = dracoon
+            .raw_request(
+                reqwest::Method::POST,
+                "/api/v4/nodes/folders",
+                Some(serde_json::json!({"name": "test", "parentId": 1})),
+            )
+            .await
+            .unwrap();
+
+        folder_mock.assert();
+        // error status codes are not mapped
+        assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_raw_request_foreign_origin() {
+        let (dracoon, _mock_server) = get_connected_client().await;
+
+        for path in [
+            "https://evil.example.com/steal",
+            "//evil.example.com/steal",
+            "http://127.0.0.1:1/steal",
+        ] {
+            let err = dracoon
+                .raw_request(reqwest::Method::GET, path, None)
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, DracoonClientError::InvalidPath(_)));
+        }
+    }
+
     #[tokio::test]
     async fn test_get_user_info() {
         let (dracoon, mock_server) = get_connected_client().await;
@@ -152,6 +413,166 @@ pub mod dracoon {
         assert_user_account(&user_info);
     }
 
+    #[tokio::test]
+    async fn test_current_user_id() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let user_info_res = include_str!("./responses/user_info_ok.json");
+
+        let user_info_mock = mock_server
+            .mock("GET", "/api/v4/user/account")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(user_info_res)
+            .expect(1)
+            .create();
+
+        let user_id = dracoon.current_user_id().await.unwrap();
+        let user_id_cached = dracoon.current_user_id().await.unwrap();
+        let user_info = dracoon.get_user_info().await.unwrap();
+
+        user_info_mock.assert();
+
+        assert_eq!(user_id, 1);
+        assert_eq!(user_id_cached, user_id);
+        assert_eq!(user_info.id, user_id);
+    }
+
+    #[tokio::test]
+    async fn test_get_user_context() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let user_info_res = include_str!("./responses/user_info_ok.json");
+
+        let user_info_mock = mock_server
+            .mock("GET", "/api/v4/user/account")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(user_info_res)
+            .expect(1)
+            .create();
+
+        // user account is cached and reused
+        let user_info = dracoon.get_user_info().await.unwrap();
+        let user_context = dracoon.get_user_context().await.unwrap();
+
+        user_info_mock.assert();
+
+        assert_eq!(user_context.account.id, user_info.id);
+        assert_eq!(user_context.roles.len(), 2);
+        assert!(user_context.has_role("USER"));
+        assert!(user_context.has_role("NONMEMBER_VIEWER"));
+        assert!(!user_context.is_config_manager);
+        assert!(!user_context.is_room_manager);
+    }
+
+    #[tokio::test]
+    async fn test_health_check() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let version_mock = mock_server
+            .mock("GET", "/api/v4/public/software/version")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/public/version_ok.json"))
+            .create();
+
+        let user_info_mock = mock_server
+            .mock("GET", "/api/v4/user/account")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/user_info_ok.json"))
+            .create();
+
+        let status = dracoon.health_check().await;
+
+        version_mock.assert();
+        user_info_mock.assert();
+
+        assert!(status.is_reachable);
+        assert!(status.is_authenticated);
+        assert!(status.api_version.is_some());
+        assert!(status.auth_latency.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_health_check_unauthenticated() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let version_mock = mock_server
+            .mock("GET", "/api/v4/public/software/version")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/public/version_ok.json"))
+            .create();
+
+        let user_info_mock = mock_server
+            .mock("GET", "/api/v4/user/account")
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"code": 401, "message": "Unauthorized", "debugInfo": "Invalid token", "errorCode": -10006}"#,
+            )
+            .expect_at_least(1)
+            .create();
+
+        let status = dracoon.health_check().await;
+
+        version_mock.assert();
+        user_info_mock.assert();
+
+        assert!(status.is_reachable);
+        assert!(!status.is_authenticated);
+        assert!(status.auth_latency.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_health_check_rate_limited() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let version_mock = mock_server
+            .mock("GET", "/api/v4/public/software/version")
+            .with_status(429)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"code": 429, "message": "Too Many Requests", "debugInfo": "Rate limit exceeded"}"#,
+            )
+            .expect_at_least(1)
+            .create();
+
+        let user_info_mock = mock_server
+            .mock("GET", "/api/v4/user/account")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/user_info_ok.json"))
+            .create();
+
+        let status = dracoon.health_check().await;
+
+        version_mock.assert();
+        user_info_mock.assert();
+
+        // a rate limited response means DRACOON was reached
+        assert!(status.is_reachable);
+        assert!(status.is_authenticated);
+        assert!(status.api_version.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_health_check_unreachable() {
+        let (dracoon, mock_server) = get_connected_client().await;
+
+        // server is gone - no response at all
+        drop(mock_server);
+
+        let status = dracoon.health_check().await;
+
+        assert!(!status.is_reachable);
+        assert!(!status.is_authenticated);
+        assert!(status.api_version.is_none());
+        assert!(status.auth_latency.is_none());
+    }
+
     #[tokio::test]
     async fn test_get_provisioning_token() {
         let client = Dracoon::builder()
@@ -1,6 +1,15 @@
 #[cfg(test)]
 pub mod tests {
+    use std::{
+        io::Write,
+        sync::{Arc, Mutex},
+    };
+
     use chrono::DateTime;
+    use dco3_crypto::{DracoonCrypto, DracoonRSACrypto, Encrypt, FileKey, UserKeyPairVersion};
+    use flate2::{write::GzEncoder, Compression};
+    use futures_util::TryStreamExt;
+    use mockito::Matcher;
 
     use crate::{
         nodes::{
@@ -98,6 +107,81 @@ pub mod tests {
         assert_node(node);
     }
 
+    #[tokio::test]
+    async fn test_get_nodes_gzip_encoded() {
+        let (dracoon, mock_server) = get_connected_client().await;
+
+        let mut mock_server = mock_server;
+
+        let nodes_res = include_str!("./responses/nodes/nodes_ok.json");
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(nodes_res.as_bytes()).unwrap();
+        let nodes_res = encoder.finish().unwrap();
+
+        let nodes_mock = mock_server
+            .mock("GET", "/api/v4/nodes?offset=0")
+            .match_header("accept-encoding", Matcher::Regex("gzip".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("content-encoding", "gzip")
+            .with_body(nodes_res)
+            .create();
+
+        let nodes = dracoon.nodes().get_nodes(None, None, None).await.unwrap();
+
+        nodes_mock.assert();
+
+        assert_eq!(nodes.items.len(), 1);
+
+        let node = nodes.items.first().unwrap();
+
+        assert_node(node);
+    }
+
+    #[tokio::test]
+    async fn test_get_nodes_compression_disabled() {
+        let mut mock_server = mockito::Server::new_async().await;
+
+        let auth_res = include_str!("../client/tests/auth_ok.json");
+
+        let auth_mock = mock_server
+            .mock("POST", "/oauth/token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(auth_res)
+            .create();
+
+        let dracoon = Dracoon::builder()
+            .with_base_url(mock_server.url())
+            .with_client_id("client_id")
+            .with_client_secret("client_secret")
+            .with_compression(false)
+            .build()
+            .unwrap()
+            .connect(OAuth2Flow::authorization_code("auth_code"))
+            .await
+            .unwrap();
+
+        auth_mock.assert();
+
+        let nodes_res = include_str!("./responses/nodes/nodes_ok.json");
+
+        let nodes_mock = mock_server
+            .mock("GET", "/api/v4/nodes?offset=0")
+            .match_header("accept-encoding", Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(nodes_res)
+            .create();
+
+        let nodes = dracoon.nodes().get_nodes(None, None, None).await.unwrap();
+
+        nodes_mock.assert();
+
+        assert_eq!(nodes.items.len(), 1);
+    }
+
     #[tokio::test]
     async fn test_get_nodes_with_parent_id() {
         let (dracoon, mock_server) = get_connected_client().await;
@@ -302,269 +386,1393 @@ pub mod tests {
     }
 
     #[tokio::test]
-    async fn test_delete_node() {
-        let (dracoon, mock_server) = get_connected_client().await;
+    async fn test_wait_for_node() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
 
-        let mut mock_server = mock_server;
+        // node is not listable on the first polls
+        let not_found_mock = mock_server
+            .mock("GET", "/api/v4/nodes/123")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/error_not_found.json"))
+            .expect(2)
+            .create();
 
         let node_mock = mock_server
-            .mock("DELETE", "/api/v4/nodes/123")
-            .with_status(204)
+            .mock("GET", "/api/v4/nodes/123")
+            .with_status(200)
             .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/nodes/node_ok.json"))
+            .expect(1)
             .create();
 
-        dracoon.nodes().delete_node(123).await.unwrap();
+        let node = dracoon
+            .nodes()
+            .wait_for_node(123, std::time::Duration::from_secs(10))
+            .await
+            .unwrap();
 
+        not_found_mock.assert();
         node_mock.assert();
+        assert_node(&node);
     }
 
     #[tokio::test]
-    async fn test_delete_nodes() {
-        let (dracoon, mock_server) = get_connected_client().await;
+    async fn test_wait_for_node_timeout() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
 
-        let mut mock_server = mock_server;
-
-        let node_ids = vec![1, 2, 3];
-
-        let node_mock = mock_server
-            .mock("DELETE", "/api/v4/nodes")
-            .with_status(204)
+        let not_found_mock = mock_server
+            .mock("GET", "/api/v4/nodes/123")
+            .with_status(404)
             .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/error_not_found.json"))
+            .expect_at_least(2)
             .create();
 
-        dracoon.nodes().delete_nodes(node_ids.into()).await.unwrap();
+        let err = dracoon
+            .nodes()
+            .wait_for_node(123, std::time::Duration::from_millis(500))
+            .await
+            .unwrap_err();
 
-        node_mock.assert();
+        not_found_mock.assert();
+        assert!(err.is_node_not_available());
     }
 
     #[tokio::test]
-    async fn test_copy_nodes() {
-        let (dracoon, mock_server) = get_connected_client().await;
-
-        let mut mock_server = mock_server;
+    async fn test_wait_for_node_other_error() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
 
-        let node_res = include_str!("./responses/nodes/node_ok.json");
-
-        let node_ids = vec![1, 2, 3];
-
-        let copy_mock = mock_server
-            .mock("POST", "/api/v4/nodes/123/copy_to")
-            .with_status(200)
+        // other errors are returned without polling again
+        let forbidden_mock = mock_server
+            .mock("GET", "/api/v4/nodes/123")
+            .with_status(403)
             .with_header("content-type", "application/json")
-            .with_body(node_res)
+            .with_body(include_str!("./responses/error_forbidden.json"))
+            .expect(1)
             .create();
 
-        let target_node = dracoon
+        let err = dracoon
             .nodes()
-            .copy_nodes(node_ids.into(), 123)
+            .wait_for_node(123, std::time::Duration::from_secs(10))
             .await
-            .unwrap();
+            .unwrap_err();
 
-        copy_mock.assert();
-
-        assert_node(&target_node);
+        forbidden_mock.assert();
+        assert!(err.is_forbidden());
     }
 
     #[tokio::test]
-    async fn test_move_nodes() {
+    async fn test_get_node_path() {
         let (dracoon, mock_server) = get_connected_client().await;
 
         let mut mock_server = mock_server;
 
-        let node_res = include_str!("./responses/nodes/node_ok.json");
-
-        let node_ids = vec![1, 2, 3];
+        let node_res = include_str!("./responses/nodes/node_ok.json")
+            .replace(r#""name": "string""#, r#""name": "file #1.txt""#)
+            .replace(
+                r#""parentPath": "string""#,
+                r#""parentPath": "/Room/Folder/""#,
+            );
 
-        let move_mock = mock_server
-            .mock("POST", "/api/v4/nodes/123/move_to")
+        let node_mock = mock_server
+            .mock("GET", "/api/v4/nodes/123")
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(node_res)
             .create();
 
-        let target_node = dracoon
-            .nodes()
-            .move_nodes(node_ids.into(), 123)
-            .await
-            .unwrap();
+        let path = dracoon.nodes().get_node_path(123).await.unwrap();
 
-        move_mock.assert();
+        node_mock.assert();
 
-        assert_node(&target_node);
+        assert_eq!(path, "/Room/Folder/file #1.txt");
     }
 
     #[tokio::test]
-    async fn test_search_nodes() {
-        let (dracoon, mock_server) = get_connected_client().await;
-
-        let mut mock_server = mock_server;
+    async fn test_get_node_stats() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
 
-        let nodes_res = include_str!("./responses/nodes/nodes_ok.json");
+        let node_res = include_str!("./responses/nodes/node_ok.json")
+            .replace(r#""cntComments": 0"#, r#""cntComments": 4"#)
+            .replace(r#""cntDownloadShares": 0"#, r#""cntDownloadShares": 2"#)
+            .replace(r#""cntUploadShares": 0"#, r#""cntUploadShares": 1"#)
+            .replace(r#""cntDeletedVersions": 0"#, r#""cntDeletedVersions": 3"#);
 
-        let nodes_mock = mock_server
-            .mock("GET", "/api/v4/nodes/search?search_string=test&offset=0")
+        let node_mock = mock_server
+            .mock("GET", "/api/v4/nodes/123")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(nodes_res)
+            .with_body(node_res)
+            .expect(1)
             .create();
 
-        let nodes = dracoon
-            .nodes()
-            .search_nodes("test", None, None, None)
-            .await
-            .unwrap();
+        let stats = dracoon.nodes().get_node_stats(123).await.unwrap();
 
-        nodes_mock.assert();
+        node_mock.assert();
 
-        assert_eq!(nodes.items.len(), 1);
+        assert_eq!(stats.node_id, 2);
+        assert_eq!(stats.cnt_comments, 4);
+        assert_eq!(stats.cnt_download_shares, 2);
+        assert_eq!(stats.cnt_upload_shares, 1);
+        assert_eq!(stats.cnt_deleted_versions, 3);
+        assert!(stats.is_favorite);
+    }
 
-        let node = nodes.items.first().unwrap();
+    fn node_with_parent(id: u64, parent_id: Option<u64>) -> String {
+        let mut node: serde_json::Value =
+            serde_json::from_str(include_str!("./responses/nodes/node_ok.json")).unwrap();
 
-        assert_node(node);
+        node["id"] = id.into();
+        node["parentId"] = parent_id.into();
+
+        node.to_string()
     }
 
     #[tokio::test]
-    async fn test_search_nodes_with_parent_id() {
-        let (dracoon, mock_server) = get_connected_client().await;
-
-        let mut mock_server = mock_server;
+    async fn test_get_node_ancestors() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let node_mocks: Vec<_> = [(3, Some(2)), (2, Some(1)), (1, None)]
+            .into_iter()
+            .map(|(id, parent_id)| {
+                mock_server
+                    .mock("GET", format!("/api/v4/nodes/{id}").as_str())
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body(node_with_parent(id, parent_id))
+                    .expect(1)
+                    .create()
+            })
+            .collect();
+
+        let ancestors = dracoon.nodes().get_node_ancestors(3).await.unwrap();
+
+        for node_mock in node_mocks {
+            node_mock.assert();
+        }
+
+        let ids: Vec<_> = ancestors.iter().map(|node| node.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
 
-        let nodes_res = include_str!("./responses/nodes/nodes_ok.json");
+    #[tokio::test]
+    async fn test_get_node_ancestors_top_level_room() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
 
-        let nodes_mock = mock_server
-            .mock(
-                "GET",
-                "/api/v4/nodes/search?search_string=test&offset=0&parent_id=123",
-            )
+        let node_mock = mock_server
+            .mock("GET", "/api/v4/nodes/1")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(nodes_res)
+            .with_body(node_with_parent(1, None))
             .create();
 
-        let nodes = dracoon
-            .nodes()
-            .search_nodes("test", Some(123), None, None)
-            .await
-            .unwrap();
-
-        nodes_mock.assert();
-
-        assert_eq!(nodes.items.len(), 1);
+        let ancestors = dracoon.nodes().get_node_ancestors(1).await.unwrap();
 
-        let node = nodes.items.first().unwrap();
+        node_mock.assert();
 
-        assert_node(node);
+        assert_eq!(ancestors.len(), 1);
+        assert_eq!(ancestors[0].id, 1);
     }
 
     #[tokio::test]
-    async fn test_search_nodes_with_limit() {
-        let (dracoon, mock_server) = get_connected_client().await;
-
-        let mut mock_server = mock_server;
+    async fn test_get_node_ancestors_parent_forbidden() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
 
-        let nodes_res = include_str!("./responses/nodes/nodes_ok.json");
-
-        let nodes_mock = mock_server
-            .mock(
-                "GET",
-                "/api/v4/nodes/search?search_string=test&limit=100&offset=0",
-            )
+        let node_mock = mock_server
+            .mock("GET", "/api/v4/nodes/3")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(nodes_res)
+            .with_body(node_with_parent(3, Some(2)))
             .create();
 
-        let params = ListAllParams::builder().with_limit(100).build();
-
-        let nodes = dracoon
-            .nodes()
-            .search_nodes("test", None, None, Some(params))
-            .await
-            .unwrap();
-
-        nodes_mock.assert();
+        let parent_mock = mock_server
+            .mock("GET", "/api/v4/nodes/2")
+            .with_status(403)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/error_forbidden.json"))
+            .create();
 
-        assert_eq!(nodes.items.len(), 1);
+        let res = dracoon.nodes().get_node_ancestors(3).await;
 
-        let node = nodes.items.first().unwrap();
+        node_mock.assert();
+        parent_mock.assert();
 
-        assert_node(node);
+        assert!(res.unwrap_err().is_forbidden());
     }
 
     #[tokio::test]
-    async fn test_search_nodes_with_offset() {
-        let (dracoon, mock_server) = get_connected_client().await;
+    async fn test_get_nodes_by_ids_skips_missing() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let node_mocks: Vec<_> = [1, 3]
+            .into_iter()
+            .map(|id| {
+                mock_server
+                    .mock("GET", format!("/api/v4/nodes/{id}").as_str())
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body(node_with_parent(id, None))
+                    .create()
+            })
+            .collect();
+
+        let missing_mock = mock_server
+            .mock("GET", "/api/v4/nodes/2")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/error_not_found.json"))
+            .create();
 
-        let mut mock_server = mock_server;
+        let nodes = dracoon.nodes().get_nodes_by_ids(&[1, 2, 3]).await.unwrap();
 
-        let nodes_res = include_str!("./responses/nodes/nodes_ok.json");
+        for node_mock in node_mocks {
+            node_mock.assert();
+        }
+        missing_mock.assert();
 
-        let nodes_mock = mock_server
-            .mock("GET", "/api/v4/nodes/search?search_string=test&offset=500")
+        let mut ids: Vec<_> = nodes.iter().map(|node| node.id).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![1, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_get_nodes_by_ids_forbidden() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let node_mock = mock_server
+            .mock("GET", "/api/v4/nodes/1")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(nodes_res)
+            .with_body(node_with_parent(1, None))
             .create();
 
-        let params = ListAllParams::builder().with_offset(500).build();
-
-        let nodes = dracoon
-            .nodes()
-            .search_nodes("test", None, None, Some(params))
-            .await
-            .unwrap();
+        let forbidden_mock = mock_server
+            .mock("GET", "/api/v4/nodes/2")
+            .with_status(403)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/error_forbidden.json"))
+            .create();
 
-        nodes_mock.assert();
+        let res = dracoon.nodes().get_nodes_by_ids(&[1, 2]).await;
 
-        assert_eq!(nodes.items.len(), 1);
+        node_mock.assert();
+        forbidden_mock.assert();
 
-        let node = nodes.items.first().unwrap();
+        assert!(res.unwrap_err().is_forbidden());
+    }
 
-        assert_node(node);
+    fn nodes_page(offset: u64, total: u64, ids: &[u64]) -> String {
+        let mut nodes: serde_json::Value =
+            serde_json::from_str(include_str!("./responses/nodes/nodes_ok.json")).unwrap();
+        let node = nodes["items"][0].clone();
+
+        nodes["range"] =
+            serde_json::json!({ "offset": offset, "limit": ids.len(), "total": total });
+        nodes["items"] = ids
+            .iter()
+            .map(|id| {
+                let mut node = node.clone();
+                node["id"] = (*id).into();
+                node
+            })
+            .collect();
+
+        nodes.to_string()
     }
 
     #[tokio::test]
-    async fn test_search_nodes_with_filter() {
-        let (dracoon, mock_server) = get_connected_client().await;
-
-        let mut mock_server = mock_server;
-
-        let nodes_res = include_str!("./responses/nodes/nodes_ok.json");
-
-        let nodes_mock = mock_server
-            .mock(
-                "GET",
-                "/api/v4/nodes/search?search_string=test&offset=0&filter=parentPath%3Acn%3Atest",
-            )
-            .with_status(200)
-            .with_header("content-type", "application/json")
-            .with_body(nodes_res)
-            .create();
+    async fn test_get_all_nodes() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let page_mocks: Vec<_> = [(0, vec![1, 2]), (2, vec![3, 4]), (4, vec![5])]
+            .into_iter()
+            .map(|(offset, ids)| {
+                mock_server
+                    .mock("GET", "/api/v4/nodes")
+                    .match_query(Matcher::AllOf(vec![
+                        Matcher::UrlEncoded("offset".into(), offset.to_string()),
+                        Matcher::UrlEncoded("parent_id".into(), "123".into()),
+                        Matcher::UrlEncoded("filter".into(), "type:eq:file".into()),
+                    ]))
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body(nodes_page(offset, 5, &ids))
+                    .expect(1)
+                    .create()
+            })
+            .collect();
 
         let params = ListAllParams::builder()
-            .with_filter(NodesSearchFilter::parent_path_contains("test"))
+            .with_filter(NodesFilter::is_file())
             .build();
 
         let nodes = dracoon
             .nodes()
-            .search_nodes("test", None, None, Some(params))
+            .get_all_nodes(Some(123), None, Some(params))
             .await
             .unwrap();
 
-        nodes_mock.assert();
-
-        assert_eq!(nodes.items.len(), 1);
-
-        let node = nodes.items.first().unwrap();
+        page_mocks.iter().for_each(|mock| mock.assert());
 
-        assert_node(node);
+        let ids: Vec<_> = nodes.items.iter().map(|node| node.id).collect();
+        assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+        assert_eq!(nodes.range.total, 5);
     }
 
     #[tokio::test]
-    async fn test_search_nodes_with_sort() {
-        let (dracoon, mock_server) = get_connected_client().await;
+    async fn test_get_all_nodes_page_error() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
 
-        let mut mock_server = mock_server;
+        let first_page_mock = mock_server
+            .mock("GET", "/api/v4/nodes?offset=0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(nodes_page(0, 4, &[1, 2]))
+            .create();
+
+        let second_page_mock = mock_server
+            .mock("GET", "/api/v4/nodes?offset=2")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/error_bad_request.json"))
+            .create();
+
+        let res = dracoon.nodes().get_all_nodes(None, None, None).await;
+
+        first_page_mock.assert();
+        second_page_mock.assert();
+
+        assert!(res.unwrap_err().is_http_error());
+    }
+
+    #[tokio::test]
+    async fn test_get_nodes_stream() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let first_page_mock = mock_server
+            .mock("GET", "/api/v4/nodes?offset=0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(nodes_page(0, 3, &[1, 2]))
+            .create();
+
+        let second_page_mock = mock_server
+            .mock("GET", "/api/v4/nodes?offset=2")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(nodes_page(2, 3, &[3]))
+            .create();
+
+        let nodes: Vec<_> = dracoon
+            .nodes()
+            .get_nodes_stream(None, None, None)
+            .try_collect()
+            .await
+            .unwrap();
+
+        first_page_mock.assert();
+        second_page_mock.assert();
+
+        let ids: Vec<_> = nodes.iter().map(|node| node.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_get_nodes_to_channel() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let first_page_mock = mock_server
+            .mock("GET", "/api/v4/nodes?offset=0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(nodes_page(0, 3, &[1, 2]))
+            .create();
+
+        let second_page_mock = mock_server
+            .mock("GET", "/api/v4/nodes?offset=2")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(nodes_page(2, 3, &[3]))
+            .create();
+
+        // capacity of 1 forces the producer to wait for the consumer
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<Node>(1);
+
+        let consumer = tokio::spawn(async move {
+            let mut ids = Vec::new();
+            while let Some(node) = rx.recv().await {
+                ids.push(node.id);
+            }
+            ids
+        });
+
+        let sent = dracoon
+            .nodes()
+            .get_nodes_to_channel(None, None, None, tx)
+            .await
+            .unwrap();
+
+        let ids = consumer.await.unwrap();
+
+        first_page_mock.assert();
+        second_page_mock.assert();
+
+        assert_eq!(sent, 3);
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_classification_breakdown() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let counts = [(1u8, 5u64), (2, 3), (3, 1), (4, 0)];
+
+        let mut mocks = Vec::new();
+
+        for (classification, count) in counts {
+            let nodes_res = include_str!("./responses/nodes/nodes_ok.json")
+                .replace(r#""total": 1"#, &format!(r#""total": {count}"#));
+
+            let mock = mock_server
+                .mock("GET", "/api/v4/nodes/search")
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("search_string".into(), "*".into()),
+                    Matcher::UrlEncoded("parent_id".into(), "123".into()),
+                    Matcher::UrlEncoded("depth_level".into(), "-1".into()),
+                    Matcher::UrlEncoded("limit".into(), "1".into()),
+                    Matcher::UrlEncoded(
+                        "filter".into(),
+                        format!("type:eq:file|classification:eq:{classification}"),
+                    ),
+                ]))
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(nodes_res)
+                .expect(1)
+                .create();
+
+            mocks.push(mock);
+        }
+
+        let breakdown = dracoon.nodes().classification_breakdown(123).await.unwrap();
+
+        mocks.iter().for_each(|mock| mock.assert());
+
+        assert_eq!(breakdown.len(), 4);
+        for (classification, count) in counts {
+            assert_eq!(breakdown.get(&classification), Some(&count));
+        }
+    }
+
+    fn file_list(files: &[(u64, u64, &str)], total: u64) -> String {
+        let mut list: serde_json::Value =
+            serde_json::from_str(include_str!("./responses/nodes/nodes_ok.json")).unwrap();
+
+        let items = files
+            .iter()
+            .map(|(id, size, parent_path)| {
+                let mut node: serde_json::Value =
+                    serde_json::from_str(include_str!("./responses/nodes/node_ok.json")).unwrap();
+                node["id"] = (*id).into();
+                node["size"] = (*size).into();
+                node["parentPath"] = (*parent_path).into();
+                node
+            })
+            .collect::<Vec<_>>();
+
+        list["range"]["offset"] = 0.into();
+        list["range"]["total"] = total.into();
+        list["items"] = items.into();
+
+        list.to_string()
+    }
+
+    #[tokio::test]
+    async fn test_get_recursive_size() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        // two pages: files in the room and in a sub folder
+        let first_page = file_list(&[(1, 100, "/room/"), (2, 200, "/room/")], 3);
+        let second_page = file_list(&[(3, 300, "/room/folder/")], 3);
+
+        let first_mock = mock_server
+            .mock("GET", "/api/v4/nodes/search")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("search_string".into(), "*".into()),
+                Matcher::UrlEncoded("parent_id".into(), "123".into()),
+                Matcher::UrlEncoded("depth_level".into(), "-1".into()),
+                Matcher::UrlEncoded("offset".into(), "0".into()),
+                Matcher::UrlEncoded("filter".into(), "type:eq:file".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(first_page)
+            .expect(1)
+            .create();
+
+        let second_mock = mock_server
+            .mock("GET", "/api/v4/nodes/search")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("depth_level".into(), "-1".into()),
+                Matcher::UrlEncoded("offset".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(second_page)
+            .expect(1)
+            .create();
+
+        let size = dracoon.nodes().get_recursive_size(123, None).await.unwrap();
+
+        first_mock.assert();
+        second_mock.assert();
+
+        assert_eq!(size, 600);
+    }
+
+    #[tokio::test]
+    async fn test_get_recursive_size_with_depth() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let search_mock = mock_server
+            .mock("GET", "/api/v4/nodes/search")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("parent_id".into(), "123".into()),
+                Matcher::UrlEncoded("depth_level".into(), "0".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(file_list(&[(1, 100, "/room/"), (2, 200, "/room/")], 2))
+            .expect(1)
+            .create();
+
+        let size = dracoon
+            .nodes()
+            .get_recursive_size(123, Some(0))
+            .await
+            .unwrap();
+
+        search_mock.assert();
+
+        assert_eq!(size, 300);
+    }
+
+    #[tokio::test]
+    async fn test_delete_node() {
+        let (dracoon, mock_server) = get_connected_client().await;
+
+        let mut mock_server = mock_server;
+
+        let node_mock = mock_server
+            .mock("DELETE", "/api/v4/nodes/123")
+            .with_status(204)
+            .with_header("content-type", "application/json")
+            .create();
+
+        dracoon.nodes().delete_node(123).await.unwrap();
+
+        node_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_delete_nodes() {
+        let (dracoon, mock_server) = get_connected_client().await;
+
+        let mut mock_server = mock_server;
+
+        let node_ids = vec![1, 2, 3];
+
+        let node_mock = mock_server
+            .mock("DELETE", "/api/v4/nodes")
+            .with_status(204)
+            .with_header("content-type", "application/json")
+            .create();
+
+        dracoon.nodes().delete_nodes(node_ids.into()).await.unwrap();
+
+        node_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_copy_nodes() {
+        let (dracoon, mock_server) = get_connected_client().await;
+
+        let mut mock_server = mock_server;
+
+        let node_res = include_str!("./responses/nodes/node_ok.json");
+
+        let node_ids = vec![1, 2, 3];
+
+        let copy_mock = mock_server
+            .mock("POST", "/api/v4/nodes/123/copy_to")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(node_res)
+            .create();
+
+        let target_node = dracoon
+            .nodes()
+            .copy_nodes(node_ids.into(), 123)
+            .await
+            .unwrap();
+
+        copy_mock.assert();
+
+        assert_node(&target_node);
+    }
+
+    #[tokio::test]
+    async fn test_copy_nodes_detailed() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let copied_mock = mock_server
+            .mock("POST", "/api/v4/nodes/123/copy_to")
+            .match_body(Matcher::PartialJson(serde_json::json!({
+                "items": [{"id": 1}],
+                "resolutionStrategy": "fail"
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/nodes/node_ok.json"))
+            .expect(1)
+            .create();
+
+        let conflict_mock = mock_server
+            .mock("POST", "/api/v4/nodes/123/copy_to")
+            .match_body(Matcher::PartialJson(serde_json::json!({
+                "items": [{"id": 2}],
+                "resolutionStrategy": "fail"
+            })))
+            .with_status(409)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/error_conflict.json"))
+            .expect(1)
+            .create();
+
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let progress_cb = progress.clone();
+        let callback = Box::new(move |done: u64, total: u64| {
+            progress_cb.lock().unwrap().push((done, total));
+        });
+
+        let req = nodes::TransferNodesRequest::new_from_ids(vec![1, 2])
+            .with_resolution_strategy(nodes::ResolutionStrategy::Fail)
+            .build();
+
+        let results = dracoon
+            .nodes()
+            .copy_nodes_detailed(req, 123, Some(callback))
+            .await;
+
+        copied_mock.assert();
+        conflict_mock.assert();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].node_id, 1);
+        assert!(results[0].is_ok());
+        assert_eq!(results[1].node_id, 2);
+        assert!(results[1].is_conflict());
+
+        let err = results[1].result.as_ref().unwrap_err();
+        assert!(err.is_node_conflict());
+        assert!(err.is_conflict());
+
+        assert_eq!(*progress.lock().unwrap(), vec![(1, 2), (2, 2)]);
+    }
+
+    #[tokio::test]
+    async fn test_copy_nodes_with_keys_encrypted_rooms() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        // keypair of the current user and of another user in the target room
+        let keypair =
+            DracoonCrypto::create_plain_user_keypair(UserKeyPairVersion::RSA4096).unwrap();
+        let other_keypair =
+            DracoonCrypto::create_plain_user_keypair(UserKeyPairVersion::RSA4096).unwrap();
+        let enc_keypair =
+            DracoonCrypto::encrypt_private_key("TopSecret1234!", keypair.clone()).unwrap();
+
+        let keypair_mock = mock_server
+            .mock("GET", "/api/v4/user/account/keypair")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::to_string(&enc_keypair).unwrap())
+            .create();
+
+        dracoon
+            .get_keypair(Some("TopSecret1234!".into()))
+            .await
+            .unwrap();
+
+        keypair_mock.assert();
+
+        // copied file key (encrypted for the current user)
+        let (_, plain_file_key) = DracoonCrypto::encrypt(b"secret").unwrap();
+        let file_key =
+            DracoonCrypto::encrypt_file_key(plain_file_key.clone(), keypair.clone()).unwrap();
+
+        let mut target_res: serde_json::Value =
+            serde_json::from_str(include_str!("./responses/nodes/node_encrypted_ok.json")).unwrap();
+        target_res["id"] = serde_json::json!(456);
+
+        // file 5 already exists in the target room, file 7 is the copy of file 3
+        let children_res = |ids: &[u64]| {
+            let items = ids
+                .iter()
+                .map(|id| {
+                    let mut node: serde_json::Value = serde_json::from_str(include_str!(
+                        "./responses/nodes/node_encrypted_ok.json"
+                    ))
+                    .unwrap();
+                    node["id"] = serde_json::json!(id);
+                    node["type"] = serde_json::json!("file");
+                    node
+                })
+                .collect::<Vec<_>>();
+
+            serde_json::json!({
+                "range": { "offset": 0, "limit": 500, "total": ids.len() },
+                "items": items
+            })
+            .to_string()
+        };
+
+        let children_before_mock = mock_server
+            .mock("GET", "/api/v4/nodes")
+            .match_query(Matcher::UrlEncoded("parent_id".into(), "456".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(children_res(&[5]))
+            .expect(1)
+            .create();
+
+        let children_after_mock = mock_server
+            .mock("GET", "/api/v4/nodes")
+            .match_query(Matcher::UrlEncoded("parent_id".into(), "456".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(children_res(&[5, 7]))
+            .expect(1)
+            .create();
+
+        let copy_mock = mock_server
+            .mock("POST", "/api/v4/nodes/456/copy_to")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(target_res.to_string())
+            .create();
+
+        let missing_keys_res = serde_json::json!({
+            "range": { "offset": 0, "limit": 100, "total": 1 },
+            "items": [{ "userId": 2, "fileId": 7 }],
+            "users": [{ "id": 2, "publicKeyContainer": other_keypair.public_key_container }],
+            "files": [{ "id": 7, "fileKeyContainer": file_key }]
+        });
+
+        let missing_keys_mock = mock_server
+            .mock("GET", "/api/v4/nodes/missingFileKeys")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("file_id".into(), "7".into()),
+                Matcher::UrlEncoded("limit".into(), "100".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(missing_keys_res.to_string())
+            .expect(1)
+            .create();
+
+        // all keys are distributed after the first batch
+        let no_missing_keys_res = serde_json::json!({
+            "range": { "offset": 0, "limit": 100, "total": 0 },
+            "items": [],
+            "users": [],
+            "files": []
+        });
+
+        let no_missing_keys_mock = mock_server
+            .mock("GET", "/api/v4/nodes/missingFileKeys")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("file_id".into(), "7".into()),
+                Matcher::UrlEncoded("limit".into(), "100".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(no_missing_keys_res.to_string())
+            .expect(1)
+            .create();
+
+        let set_keys_body = Arc::new(Mutex::new(String::new()));
+        let captured_body = set_keys_body.clone();
+
+        let set_keys_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/keys")
+            .with_status(200)
+            .with_body_from_request(move |request| {
+                *captured_body.lock().unwrap() =
+                    String::from_utf8_lossy(request.body().unwrap()).to_string();
+                vec![]
+            })
+            .create();
+
+        let result = dracoon
+            .copy_nodes_with_keys(vec![3].into(), 456)
+            .await
+            .unwrap();
+
+        children_before_mock.assert();
+        children_after_mock.assert();
+        copy_mock.assert();
+        missing_keys_mock.assert();
+        no_missing_keys_mock.assert();
+        set_keys_mock.assert();
+
+        assert_eq!(result.target.id, 456);
+        assert_eq!(result.nodes.len(), 1);
+        assert_eq!(result.nodes[0].id, 7);
+        assert_eq!(result.key_distribution.unwrap(), 1);
+
+        // the file key is re-encrypted for the other user
+        let set_keys_body: serde_json::Value =
+            serde_json::from_str(&set_keys_body.lock().unwrap()).unwrap();
+        let item = &set_keys_body["items"][0];
+        assert_eq!(item["userId"], 2);
+        assert_eq!(item["fileId"], 7);
+
+        let reencrypted_key: FileKey = serde_json::from_value(item["fileKey"].clone()).unwrap();
+        let decrypted_key =
+            DracoonCrypto::decrypt_file_key(reencrypted_key, other_keypair).unwrap();
+
+        assert_eq!(decrypted_key.key, plain_file_key.key);
+    }
+
+    #[tokio::test]
+    async fn test_copy_nodes_with_keys_distribution_error() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let keypair =
+            DracoonCrypto::create_plain_user_keypair(UserKeyPairVersion::RSA4096).unwrap();
+        let enc_keypair =
+            DracoonCrypto::encrypt_private_key("TopSecret1234!", keypair.clone()).unwrap();
+
+        let keypair_mock = mock_server
+            .mock("GET", "/api/v4/user/account/keypair")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::to_string(&enc_keypair).unwrap())
+            .create();
+
+        dracoon
+            .get_keypair(Some("TopSecret1234!".into()))
+            .await
+            .unwrap();
+
+        keypair_mock.assert();
+
+        let mut target_res: serde_json::Value =
+            serde_json::from_str(include_str!("./responses/nodes/node_encrypted_ok.json")).unwrap();
+        target_res["id"] = serde_json::json!(456);
+
+        let mut copied_file = target_res.clone();
+        copied_file["id"] = serde_json::json!(7);
+        copied_file["type"] = serde_json::json!("file");
+
+        let children_before_mock = mock_server
+            .mock("GET", "/api/v4/nodes")
+            .match_query(Matcher::UrlEncoded("parent_id".into(), "456".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!(
+                "./responses/nodes/nodes_search_no_result.json"
+            ))
+            .expect(1)
+            .create();
+
+        let children_after_mock = mock_server
+            .mock("GET", "/api/v4/nodes")
+            .match_query(Matcher::UrlEncoded("parent_id".into(), "456".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "range": { "offset": 0, "limit": 500, "total": 1 },
+                    "items": [copied_file]
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create();
+
+        let copy_mock = mock_server
+            .mock("POST", "/api/v4/nodes/456/copy_to")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(target_res.to_string())
+            .create();
+
+        let missing_keys_mock = mock_server
+            .mock("GET", "/api/v4/nodes/missingFileKeys")
+            .match_query(Matcher::UrlEncoded("file_id".into(), "7".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("./responses/error_not_found.json"))
+            .expect(1)
+            .create();
+
+        let result = dracoon
+            .copy_nodes_with_keys(vec![3].into(), 456)
+            .await
+            .unwrap();
+
+        children_before_mock.assert();
+        children_after_mock.assert();
+        copy_mock.assert();
+        missing_keys_mock.assert();
+
+        // the copied nodes are returned with the distribution error
+        assert_eq!(result.target.id, 456);
+        assert_eq!(result.nodes.len(), 1);
+        assert_eq!(result.nodes[0].id, 7);
+        assert!(result.key_distribution.unwrap_err().is_not_found());
+    }
+
+    #[tokio::test]
+    async fn test_distribute_all_missing_keys() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let keypair =
+            DracoonCrypto::create_plain_user_keypair(UserKeyPairVersion::RSA4096).unwrap();
+        let other_keypair =
+            DracoonCrypto::create_plain_user_keypair(UserKeyPairVersion::RSA4096).unwrap();
+        let enc_keypair =
+            DracoonCrypto::encrypt_private_key("TopSecret1234!", keypair.clone()).unwrap();
+
+        let keypair_mock = mock_server
+            .mock("GET", "/api/v4/user/account/keypair")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::to_string(&enc_keypair).unwrap())
+            .create();
+
+        dracoon
+            .get_keypair(Some("TopSecret1234!".into()))
+            .await
+            .unwrap();
+
+        keypair_mock.assert();
+
+        let (_, plain_file_key) = DracoonCrypto::encrypt(b"secret").unwrap();
+        let file_key = DracoonCrypto::encrypt_file_key(plain_file_key, keypair).unwrap();
+
+        // two batches with one key each, then no more missing keys
+        let batches = [(2, Some(2)), (1, Some(4)), (0, None)];
+
+        let missing_keys_mocks = batches
+            .iter()
+            .map(|(total, user_id)| {
+                let res = match user_id {
+                    Some(user_id) => serde_json::json!({
+                        "range": { "offset": 0, "limit": 100, "total": total },
+                        "items": [{ "userId": user_id, "fileId": 3 }],
+                        "users": [{ "id": user_id, "publicKeyContainer": other_keypair.public_key_container }],
+                        "files": [{ "id": 3, "fileKeyContainer": file_key }]
+                    }),
+                    None => serde_json::json!({
+                        "range": { "offset": 0, "limit": 100, "total": 0 },
+                        "items": [],
+                        "users": [],
+                        "files": []
+                    }),
+                };
+
+                mock_server
+                    .mock("GET", "/api/v4/nodes/missingFileKeys")
+                    .match_query(Matcher::UrlEncoded("room_id".into(), "123".into()))
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body(res.to_string())
+                    .expect(1)
+                    .create()
+            })
+            .collect::<Vec<_>>();
+
+        let set_keys_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/keys")
+            .with_status(200)
+            .expect(2)
+            .create();
+
+        let distributed_keys = dracoon
+            .distribute_all_missing_keys(Some(123), None, None)
+            .await
+            .unwrap();
+
+        missing_keys_mocks.iter().for_each(|mock| mock.assert());
+        set_keys_mock.assert();
+
+        assert_eq!(distributed_keys, 2);
+    }
+
+    #[tokio::test]
+    async fn test_distribute_all_missing_keys_no_progress() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let keypair =
+            DracoonCrypto::create_plain_user_keypair(UserKeyPairVersion::RSA4096).unwrap();
+        let enc_keypair = DracoonCrypto::encrypt_private_key("TopSecret1234!", keypair).unwrap();
+
+        let keypair_mock = mock_server
+            .mock("GET", "/api/v4/user/account/keypair")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::to_string(&enc_keypair).unwrap())
+            .create();
+
+        dracoon
+            .get_keypair(Some("TopSecret1234!".into()))
+            .await
+            .unwrap();
+
+        keypair_mock.assert();
+
+        // the missing keys never decrease
+        let missing_keys_res = serde_json::json!({
+            "range": { "offset": 0, "limit": 100, "total": 5 },
+            "items": [],
+            "users": [],
+            "files": []
+        });
+
+        let missing_keys_mock = mock_server
+            .mock("GET", "/api/v4/nodes/missingFileKeys")
+            .match_query(Matcher::UrlEncoded("room_id".into(), "123".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(missing_keys_res.to_string())
+            .expect(1 + crate::constants::MISSING_KEYS_MAX_STABLE_ITERATIONS as usize)
+            .create();
+
+        let set_keys_mock = mock_server
+            .mock("POST", "/api/v4/nodes/files/keys")
+            .expect(0)
+            .create();
+
+        let err = dracoon
+            .distribute_all_missing_keys(Some(123), None, None)
+            .await
+            .unwrap_err();
+
+        missing_keys_mock.assert();
+        set_keys_mock.assert();
+
+        assert!(err.is_missing_keys_not_distributable());
+        assert_eq!(
+            err,
+            DracoonClientError::MissingKeysNotDistributable {
+                remaining: 5,
+                distributed: 0
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_move_nodes() {
+        let (dracoon, mock_server) = get_connected_client().await;
+
+        let mut mock_server = mock_server;
+
+        let node_res = include_str!("./responses/nodes/node_ok.json");
+
+        let node_ids = vec![1, 2, 3];
+
+        let node_mocks = ["123", "1", "2", "3"]
+            .iter()
+            .map(|id| {
+                mock_server
+                    .mock("GET", format!("/api/v4/nodes/{id}").as_str())
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body(node_res)
+                    .create()
+            })
+            .collect::<Vec<_>>();
+
+        let move_mock = mock_server
+            .mock("POST", "/api/v4/nodes/123/move_to")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(node_res)
+            .create();
+
+        let target_node = dracoon
+            .nodes()
+            .move_nodes(node_ids.into(), 123)
+            .await
+            .unwrap();
+
+        node_mocks.iter().for_each(|mock| mock.assert());
+        move_mock.assert();
+
+        assert_node(&target_node);
+    }
+
+    #[tokio::test]
+    async fn test_move_nodes_duplicate_ids_fetched_once() {
+        let (dracoon, mock_server) = get_connected_client().await;
+
+        let mut mock_server = mock_server;
+
+        let node_res = include_str!("./responses/nodes/node_ok.json");
+
+        let node_mocks = ["123", "1", "2"]
+            .iter()
+            .map(|id| {
+                mock_server
+                    .mock("GET", format!("/api/v4/nodes/{id}").as_str())
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body(node_res)
+                    .expect(1)
+                    .create()
+            })
+            .collect::<Vec<_>>();
+
+        let move_mock = mock_server
+            .mock("POST", "/api/v4/nodes/123/move_to")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(node_res)
+            .create();
+
+        dracoon
+            .nodes()
+            .move_nodes(vec![1, 2, 1].into(), 123)
+            .await
+            .unwrap();
+
+        node_mocks.iter().for_each(|mock| mock.assert());
+        move_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_move_nodes_encryption_mismatch() {
+        let (dracoon, mock_server) = get_connected_client().await;
+
+        let mut mock_server = mock_server;
+
+        let node_res = include_str!("./responses/nodes/node_ok.json");
+        let encrypted_node_res = include_str!("./responses/nodes/node_encrypted_ok.json");
+
+        let target_mock = mock_server
+            .mock("GET", "/api/v4/nodes/123")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(encrypted_node_res)
+            .create();
+
+        let source_mock = mock_server
+            .mock("GET", "/api/v4/nodes/1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(node_res)
+            .create();
+
+        let move_mock = mock_server
+            .mock("POST", "/api/v4/nodes/123/move_to")
+            .expect(0)
+            .create();
+
+        let res = dracoon.nodes().move_nodes(vec![1].into(), 123).await;
+
+        target_mock.assert();
+        source_mock.assert();
+        move_mock.assert();
+
+        let err = res.unwrap_err();
+        assert!(err.is_encryption_boundary_crossed());
+        assert_eq!(
+            err,
+            DracoonClientError::EncryptionBoundaryCrossed {
+                node_id: 1,
+                target_parent_id: 123,
+                source_encrypted: false,
+                target_encrypted: true,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_nodes() {
+        let (dracoon, mock_server) = get_connected_client().await;
+
+        let mut mock_server = mock_server;
+
+        let nodes_res = include_str!("./responses/nodes/nodes_ok.json");
+
+        let nodes_mock = mock_server
+            .mock("GET", "/api/v4/nodes/search?search_string=test&offset=0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(nodes_res)
+            .create();
+
+        let nodes = dracoon
+            .nodes()
+            .search_nodes("test", None, None, None)
+            .await
+            .unwrap();
+
+        nodes_mock.assert();
+
+        assert_eq!(nodes.items.len(), 1);
+
+        let node = nodes.items.first().unwrap();
+
+        assert_node(node);
+    }
+
+    #[tokio::test]
+    async fn test_search_nodes_with_parent_id() {
+        let (dracoon, mock_server) = get_connected_client().await;
+
+        let mut mock_server = mock_server;
+
+        let nodes_res = include_str!("./responses/nodes/nodes_ok.json");
+
+        let nodes_mock = mock_server
+            .mock(
+                "GET",
+                "/api/v4/nodes/search?search_string=test&offset=0&parent_id=123",
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(nodes_res)
+            .create();
+
+        let nodes = dracoon
+            .nodes()
+            .search_nodes("test", Some(123), None, None)
+            .await
+            .unwrap();
+
+        nodes_mock.assert();
+
+        assert_eq!(nodes.items.len(), 1);
+
+        let node = nodes.items.first().unwrap();
+
+        assert_node(node);
+    }
+
+    #[tokio::test]
+    async fn test_search_nodes_with_limit() {
+        let (dracoon, mock_server) = get_connected_client().await;
+
+        let mut mock_server = mock_server;
+
+        let nodes_res = include_str!("./responses/nodes/nodes_ok.json");
+
+        let nodes_mock = mock_server
+            .mock(
+                "GET",
+                "/api/v4/nodes/search?search_string=test&limit=100&offset=0",
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(nodes_res)
+            .create();
+
+        let params = ListAllParams::builder().with_limit(100).build();
+
+        let nodes = dracoon
+            .nodes()
+            .search_nodes("test", None, None, Some(params))
+            .await
+            .unwrap();
+
+        nodes_mock.assert();
+
+        assert_eq!(nodes.items.len(), 1);
+
+        let node = nodes.items.first().unwrap();
+
+        assert_node(node);
+    }
+
+    #[tokio::test]
+    async fn test_search_nodes_with_offset() {
+        let (dracoon, mock_server) = get_connected_client().await;
+
+        let mut mock_server = mock_server;
+
+        let nodes_res = include_str!("./responses/nodes/nodes_ok.json");
+
+        let nodes_mock = mock_server
+            .mock("GET", "/api/v4/nodes/search?search_string=test&offset=500")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(nodes_res)
+            .create();
+
+        let params = ListAllParams::builder().with_offset(500).build();
+
+        let nodes = dracoon
+            .nodes()
+            .search_nodes("test", None, None, Some(params))
+            .await
+            .unwrap();
+
+        nodes_mock.assert();
+
+        assert_eq!(nodes.items.len(), 1);
+
+        let node = nodes.items.first().unwrap();
+
+        assert_node(node);
+    }
+
+    #[tokio::test]
+    async fn test_search_nodes_with_filter() {
+        let (dracoon, mock_server) = get_connected_client().await;
+
+        let mut mock_server = mock_server;
+
+        let nodes_res = include_str!("./responses/nodes/nodes_ok.json");
+
+        let nodes_mock = mock_server
+            .mock(
+                "GET",
+                "/api/v4/nodes/search?search_string=test&offset=0&filter=parentPath%3Acn%3Atest",
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(nodes_res)
+            .create();
+
+        let params = ListAllParams::builder()
+            .with_filter(NodesSearchFilter::parent_path_contains("test"))
+            .build();
+
+        let nodes = dracoon
+            .nodes()
+            .search_nodes("test", None, None, Some(params))
+            .await
+            .unwrap();
+
+        nodes_mock.assert();
+
+        assert_eq!(nodes.items.len(), 1);
+
+        let node = nodes.items.first().unwrap();
+
+        assert_node(node);
+    }
+
+    #[tokio::test]
+    async fn test_search_nodes_with_sort() {
+        let (dracoon, mock_server) = get_connected_client().await;
+
+        let mut mock_server = mock_server;
 
         let nodes_res = include_str!("./responses/nodes/nodes_ok.json");
 
@@ -629,4 +1837,347 @@ pub mod tests {
 
         assert_node(node);
     }
+
+    #[tokio::test]
+    async fn test_mark_favorite() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let favorite_mock = mock_server
+            .mock("POST", "/api/v4/nodes/123/favorite")
+            .with_status(200)
+            .create();
+
+        dracoon.nodes().mark_favorite(123).await.unwrap();
+
+        favorite_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_mark_favorite_forbidden() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let error_res = include_str!("./responses/error_forbidden.json");
+
+        let favorite_mock = mock_server
+            .mock("POST", "/api/v4/nodes/123/favorite")
+            .with_status(403)
+            .with_header("content-type", "application/json")
+            .with_body(error_res)
+            .create();
+
+        let err = dracoon.nodes().mark_favorite(123).await.unwrap_err();
+
+        favorite_mock.assert();
+
+        assert!(err.is_forbidden());
+    }
+
+    #[tokio::test]
+    async fn test_unmark_favorite() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let favorite_mock = mock_server
+            .mock("DELETE", "/api/v4/nodes/123/favorite")
+            .with_status(204)
+            .create();
+
+        dracoon.nodes().unmark_favorite(123).await.unwrap();
+
+        favorite_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_get_favorites() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let nodes_res = include_str!("./responses/nodes/nodes_ok.json");
+
+        let nodes_mock = mock_server
+            .mock("GET", "/api/v4/nodes/search")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("search_string".into(), "*".into()),
+                Matcher::UrlEncoded("depth_level".into(), "-1".into()),
+                Matcher::UrlEncoded("filter".into(), "type:eq:file|isFavorite:eq:true".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(nodes_res)
+            .create();
+
+        let params = ListAllParams::builder()
+            .with_filter(NodesSearchFilter::is_file())
+            .build();
+
+        let nodes = dracoon.nodes().get_favorites(Some(params)).await.unwrap();
+
+        nodes_mock.assert();
+
+        assert_eq!(nodes.items.len(), 1);
+        assert_node(nodes.items.first().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_organize_by_date() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let node_res = include_str!("./responses/nodes/node_ok.json");
+        let folder_res = include_str!("./responses/nodes/folder_ok.json");
+
+        let mut files: serde_json::Value =
+            serde_json::from_str(include_str!("./responses/nodes/nodes_ok.json")).unwrap();
+        let file = files["items"][0].clone();
+        files["range"] = serde_json::json!({ "offset": 0, "limit": 3, "total": 3 });
+        files["items"] = [
+            (11, "2023-01-15T10:00:00Z"),
+            (12, "2023-02-03T10:00:00Z"),
+            (13, "2023-01-20T10:00:00Z"),
+        ]
+        .iter()
+        .map(|(id, timestamp_creation)| {
+            let mut file = file.clone();
+            file["id"] = (*id).into();
+            file["type"] = "file".into();
+            file["timestampCreation"] = (*timestamp_creation).into();
+            file
+        })
+        .collect();
+
+        let files_mock = mock_server
+            .mock("GET", "/api/v4/nodes")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("offset".into(), "0".into()),
+                Matcher::UrlEncoded("parent_id".into(), "10".into()),
+                Matcher::UrlEncoded("filter".into(), "type:eq:file".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(files.to_string())
+            .create();
+
+        // folder for January is missing and created
+        let january_lookup_mock = mock_server
+            .mock("GET", "/api/v4/nodes")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("parent_id".into(), "20".into()),
+                Matcher::UrlEncoded("filter".into(), "name:eq:2023-01|type:eq:folder".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(nodes_page(0, 0, &[]))
+            .create();
+
+        let create_folder_mock = mock_server
+            .mock("POST", "/api/v4/nodes/folders")
+            .match_body(Matcher::PartialJsonString(
+                r#"{"name": "2023-01", "parentId": 20}"#.into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(folder_res)
+            .expect(1)
+            .create();
+
+        // folder for February already exists
+        let mut february_folder: serde_json::Value =
+            serde_json::from_str(&nodes_page(0, 1, &[5])).unwrap();
+        february_folder["items"][0]["name"] = "2023-02".into();
+        february_folder["items"][0]["type"] = "folder".into();
+
+        let february_lookup_mock = mock_server
+            .mock("GET", "/api/v4/nodes")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("parent_id".into(), "20".into()),
+                Matcher::UrlEncoded("filter".into(), "name:eq:2023-02|type:eq:folder".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(february_folder.to_string())
+            .create();
+
+        let node_mocks = ["2", "5", "11", "12", "13"]
+            .iter()
+            .map(|id| {
+                mock_server
+                    .mock("GET", format!("/api/v4/nodes/{id}").as_str())
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body(node_res)
+                    .create()
+            })
+            .collect::<Vec<_>>();
+
+        let january_move_mock = mock_server
+            .mock("POST", "/api/v4/nodes/2/move_to")
+            .match_body(Matcher::PartialJsonString(
+                r#"{"items": [{"id": 11}, {"id": 13}]}"#.into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(folder_res)
+            .expect(1)
+            .create();
+
+        let february_move_mock = mock_server
+            .mock("POST", "/api/v4/nodes/5/move_to")
+            .match_body(Matcher::PartialJsonString(
+                r#"{"items": [{"id": 12}]}"#.into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(folder_res)
+            .expect(1)
+            .create();
+
+        let folders = dracoon
+            .nodes()
+            .organize_by_date(10, 20, "%Y-%m")
+            .await
+            .unwrap();
+
+        files_mock.assert();
+        january_lookup_mock.assert();
+        create_folder_mock.assert();
+        february_lookup_mock.assert();
+        node_mocks.iter().for_each(|mock| mock.assert());
+        january_move_mock.assert();
+        february_move_mock.assert();
+
+        assert_eq!(folders.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_organize_by_date_invalid_pattern() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let files_mock = mock_server
+            .mock("GET", "/api/v4/nodes")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create();
+
+        let err = dracoon
+            .nodes()
+            .organize_by_date(10, 20, "%Y-%")
+            .await
+            .unwrap_err();
+
+        files_mock.assert();
+        assert_eq!(err, DracoonClientError::InvalidDatePattern("%Y-%".into()));
+        assert!(err.is_invalid_date_pattern());
+    }
+
+    fn named_nodes_page(nodes: &[(u64, &str, &str)]) -> String {
+        let mut page: serde_json::Value =
+            serde_json::from_str(&nodes_page(0, nodes.len() as u64, &[])).unwrap();
+        let node: serde_json::Value =
+            serde_json::from_str(include_str!("./responses/nodes/node_ok.json")).unwrap();
+
+        page["range"]["limit"] = nodes.len().into();
+        page["items"] = nodes
+            .iter()
+            .map(|(id, name, node_type)| {
+                let mut node = node.clone();
+                node["id"] = (*id).into();
+                node["name"] = (*name).into();
+                node["type"] = (*node_type).into();
+                node
+            })
+            .collect();
+
+        page.to_string()
+    }
+
+    /// mocks the tree /room (1) -> docs (2) -> b.txt (4) and /room (1) -> a.txt (3)
+    fn mock_paths_tree(
+        mock_server: &mut mockito::ServerGuard,
+        include_files: bool,
+    ) -> Vec<mockito::Mock> {
+        let mut root: serde_json::Value =
+            serde_json::from_str(include_str!("./responses/nodes/node_ok.json")).unwrap();
+        root["id"] = 1.into();
+        root["name"] = "room".into();
+        root["parentPath"] = "/".into();
+
+        let root_mock = mock_server
+            .mock("GET", "/api/v4/nodes/1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(root.to_string())
+            .create();
+
+        let (room_children, folder_children) = if include_files {
+            (
+                named_nodes_page(&[(2, "docs", "folder"), (3, "a.txt", "file")]),
+                named_nodes_page(&[(4, "b.txt", "file")]),
+            )
+        } else {
+            (
+                named_nodes_page(&[(2, "docs", "folder")]),
+                named_nodes_page(&[]),
+            )
+        };
+
+        let mut mocks = vec![root_mock];
+
+        for (parent_id, children) in [("1", room_children), ("2", folder_children)] {
+            let mut matchers = vec![Matcher::UrlEncoded("parent_id".into(), parent_id.into())];
+
+            if !include_files {
+                matchers.push(Matcher::UrlEncoded(
+                    "filter".into(),
+                    "type:eq:room:folder".into(),
+                ));
+            }
+
+            mocks.push(
+                mock_server
+                    .mock("GET", "/api/v4/nodes")
+                    .match_query(Matcher::AllOf(matchers))
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body(children)
+                    .expect(1)
+                    .create(),
+            );
+        }
+
+        mocks
+    }
+
+    #[tokio::test]
+    async fn test_list_all_paths() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let mocks = mock_paths_tree(&mut mock_server, true);
+
+        let paths = dracoon.nodes().list_all_paths(Some(1), true).await.unwrap();
+
+        mocks.iter().for_each(|mock| mock.assert());
+
+        assert_eq!(
+            paths,
+            vec![
+                (2, "/room/docs".to_string()),
+                (3, "/room/a.txt".to_string()),
+                (4, "/room/docs/b.txt".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_all_paths_without_files() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let mocks = mock_paths_tree(&mut mock_server, false);
+
+        let paths = dracoon
+            .nodes()
+            .list_all_paths(Some(1), false)
+            .await
+            .unwrap();
+
+        mocks.iter().for_each(|mock| mock.assert());
+
+        assert_eq!(paths, vec![(2, "/room/docs".to_string())]);
+    }
 }
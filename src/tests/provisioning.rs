@@ -452,4 +452,47 @@ mod tests {
 
         assert_user_item(user);
     }
+
+    #[tokio::test]
+    async fn test_get_customers_provisioning_not_available() {
+        let (dracoon, mut mock_server) = get_provisioning_client().await;
+        let error_res = include_str!("./responses/error_forbidden.json");
+
+        let customers_mock = mock_server
+            .mock("GET", "/api/v4/provisioning/customers?offset=0")
+            .with_status(403)
+            .with_header("content-type", "application/json")
+            .with_body(error_res)
+            .create();
+
+        let err = dracoon
+            .provisioning()
+            .get_customers(None)
+            .await
+            .unwrap_err();
+
+        assert!(err.is_provisioning_not_available());
+        assert!(!err.is_http_error());
+
+        customers_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_delete_customer_forbidden() {
+        let (dracoon, mut mock_server) = get_provisioning_client().await;
+        let error_res = include_str!("./responses/error_forbidden.json");
+
+        let customer_mock = mock_server
+            .mock("DELETE", "/api/v4/provisioning/customers/1")
+            .with_status(403)
+            .with_header("content-type", "application/json")
+            .with_body(error_res)
+            .create();
+
+        let err = dracoon.provisioning().delete_customer(1).await.unwrap_err();
+
+        assert!(err.is_provisioning_not_available());
+
+        customer_mock.assert();
+    }
 }
@@ -232,4 +232,54 @@ pub mod tests {
 
         assert_role_user(role_user)
     }
+
+    #[tokio::test]
+    async fn test_get_effective_rights() {
+        let (dracoon, mut mock_server) = get_connected_client().await;
+
+        let mut user_res: serde_json::Value =
+            serde_json::from_str(include_str!("./responses/user_info_ok.json")).unwrap();
+
+        user_res["userRoles"] = serde_json::json!({
+            "items": [
+                {
+                    "id": 6,
+                    "name": "NONMEMBER_VIEWER",
+                    "description": "View users and groups",
+                    "items": [
+                        { "id": 1, "name": "read_users", "description": "read users" },
+                        { "id": 2, "name": "read_groups", "description": "read groups" }
+                    ]
+                },
+                {
+                    "id": 2,
+                    "name": "USER_MANAGER",
+                    "description": "Manage users",
+                    "items": [
+                        { "id": 3, "name": "manage_users", "description": "manage users" },
+                        { "id": 1, "name": "read_users", "description": "read users" }
+                    ]
+                }
+            ]
+        });
+
+        let user_mock = mock_server
+            .mock("GET", "/api/v4/user/account")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(user_res.to_string())
+            .expect(1)
+            .create();
+
+        let rights = dracoon.roles().get_effective_rights().await.unwrap();
+
+        assert_eq!(rights, vec!["manage_users", "read_groups", "read_users"]);
+
+        // cached on the endpoint
+        let rights = dracoon.roles().get_effective_rights().await.unwrap();
+
+        user_mock.assert();
+
+        assert_eq!(rights.len(), 3);
+    }
 }
@@ -10,7 +10,7 @@ mod tests {
             UserType,
         },
         tests::{dracoon::get_connected_client, nodes::tests::assert_node},
-        ListAllParams, Rooms,
+        DracoonClientError, ListAllParams, Rooms,
     };
 
     fn assert_room_user(room_user: &RoomUser) {
@@ -173,6 +173,59 @@ mod tests {
         assert!(!room_policies.is_virus_protection_enabled);
     }
 
+    fn room_with_quota(quota: Option<u64>, size: u64) -> String {
+        let mut room: serde_json::Value =
+            serde_json::from_str(include_str!("./responses/nodes/node_ok.json")).unwrap();
+
+        room["id"] = 123.into();
+        room["type"] = "room".into();
+        room["quota"] = quota.into();
+        room["size"] = size.into();
+
+        room.to_string()
+    }
+
+    #[tokio::test]
+    async fn test_get_room_quota() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let room_mock = mock_server
+            .mock("GET", "/api/v4/nodes/123")
+            .with_status(200)
+            .with_body(room_with_quota(Some(1000), 400))
+            .with_header("content-type", "application/json")
+            .create();
+
+        let quota = client.nodes().get_room_quota(123).await.unwrap();
+
+        room_mock.assert();
+
+        assert_eq!(quota.room_id, 123);
+        assert_eq!(quota.quota, Some(1000));
+        assert_eq!(quota.size, 400);
+        assert_eq!(quota.available, Some(600));
+    }
+
+    #[tokio::test]
+    async fn test_get_room_quota_without_quota() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let room_mock = mock_server
+            .mock("GET", "/api/v4/nodes/123")
+            .with_status(200)
+            .with_body(room_with_quota(None, 400))
+            .with_header("content-type", "application/json")
+            .create();
+
+        let quota = client.nodes().get_room_quota(123).await.unwrap();
+
+        room_mock.assert();
+
+        assert_eq!(quota.quota, None);
+        assert_eq!(quota.size, 400);
+        assert_eq!(quota.available, None);
+    }
+
     #[tokio::test]
     async fn test_update_room_policies() {
         let (client, mut mock_server) = get_connected_client().await;
@@ -196,6 +249,57 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_update_rooms_policies() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let room_mocks: Vec<_> = [1, 3]
+            .iter()
+            .map(|room_id| {
+                mock_server
+                    .mock(
+                        "PUT",
+                        format!("/api/v4/nodes/rooms/{room_id}/policies").as_str(),
+                    )
+                    .with_status(204)
+                    .with_header("content-type", "application/json")
+                    .create()
+            })
+            .collect();
+
+        let error_res = include_str!("./responses/error_not_found.json");
+
+        let failing_room_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/rooms/2/policies")
+            .with_status(404)
+            .with_body(error_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let room_policies = RoomPoliciesRequest::builder()
+            .with_virus_protection_enabled(true)
+            .build();
+
+        let results = client
+            .nodes()
+            .update_rooms_policies(vec![1, 2, 3], room_policies)
+            .await;
+
+        room_mocks.iter().for_each(|mock| mock.assert());
+        failing_room_mock.assert();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert!(results[0].1.is_ok());
+        assert!(results[2].1.is_ok());
+
+        let err = results[1].1.as_ref().unwrap_err();
+        assert!(err.get_http_error().unwrap().is_not_found());
+    }
+
     #[tokio::test]
     async fn test_encrypt_room() {
         let (client, mut mock_server) = get_connected_client().await;
@@ -241,6 +345,45 @@ mod tests {
         assert_node(&room);
     }
 
+    #[tokio::test]
+    async fn test_encrypt_room_without_rescue_key_or_keypair() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let account_res = include_str!("../tests/responses/user_info_ok.json").replace(
+            "\"isEncryptionEnabled\": true",
+            "\"isEncryptionEnabled\": false",
+        );
+
+        let account_mock = mock_server
+            .mock("GET", "/api/v4/user/account")
+            .with_status(200)
+            .with_body(account_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let room_mock = mock_server
+            .mock("PUT", "/api/v4/nodes/rooms/123/encrypt")
+            .expect(0)
+            .create();
+
+        let room_enc = EncryptRoomRequest::builder(true).build();
+
+        let err = client
+            .nodes()
+            .encrypt_room(123, room_enc)
+            .await
+            .unwrap_err();
+
+        account_mock.assert();
+        room_mock.assert();
+
+        assert!(err.is_missing_room_encryption_key());
+        assert_eq!(
+            err,
+            DracoonClientError::MissingRoomEncryptionKey { room_id: 123 }
+        );
+    }
+
     #[tokio::test]
     async fn test_get_room_users() {
         let (client, mut mock_server) = get_connected_client().await;
@@ -266,6 +409,78 @@ mod tests {
         assert_room_user(room_user);
     }
 
+    #[tokio::test]
+    async fn test_get_room_user_explicit_and_inherited() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let room_users_res = include_str!("../tests/responses/nodes/room_users_ok.json");
+        let not_granted_res =
+            room_users_res.replace(r#""isGranted": true"#, r#""isGranted": false"#);
+
+        // user 3 is explicitly granted in room 123
+        let explicit_mock = mock_server
+            .mock(
+                "GET",
+                "/api/v4/nodes/rooms/123/users?offset=0&filter=userId%3Aeq%3A3%7CisGranted%3Aeq%3Aany",
+            )
+            .with_status(200)
+            .with_body(room_users_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let effective_mock = mock_server
+            .mock(
+                "GET",
+                "/api/v4/nodes/rooms/123/users?offset=0&filter=userId%3Aeq%3A3%7CisGranted%3Aeq%3Aany%7CeffectivePerm%3Aeq%3Atrue",
+            )
+            .with_status(200)
+            .with_body(room_users_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let room_user = client.nodes().get_room_user(123, 3).await.unwrap();
+
+        explicit_mock.assert();
+        effective_mock.assert();
+
+        assert_eq!(room_user.user_info.id, 3);
+        assert!(room_user.is_granted);
+        assert!(!room_user.is_inherited);
+        assert!(room_user.permissions.is_some());
+        assert!(room_user.effective_permissions.is_some());
+
+        // user 3 is only member of a group granted in room 456
+        let explicit_mock = mock_server
+            .mock(
+                "GET",
+                "/api/v4/nodes/rooms/456/users?offset=0&filter=userId%3Aeq%3A3%7CisGranted%3Aeq%3Aany",
+            )
+            .with_status(200)
+            .with_body(not_granted_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let effective_mock = mock_server
+            .mock(
+                "GET",
+                "/api/v4/nodes/rooms/456/users?offset=0&filter=userId%3Aeq%3A3%7CisGranted%3Aeq%3Aany%7CeffectivePerm%3Aeq%3Atrue",
+            )
+            .with_status(200)
+            .with_body(room_users_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let room_user = client.nodes().get_room_user(456, 3).await.unwrap();
+
+        explicit_mock.assert();
+        effective_mock.assert();
+
+        assert!(!room_user.is_granted);
+        assert!(room_user.is_inherited);
+        assert!(room_user.permissions.is_none());
+        assert!(room_user.effective_permissions.unwrap().manage);
+    }
+
     #[tokio::test]
     async fn test_get_room_users_with_limit() {
         let (client, mut mock_server) = get_connected_client().await;
@@ -520,4 +735,64 @@ mod tests {
 
         room_guests_mock.assert();
     }
+
+    #[tokio::test]
+    async fn test_room_name_available() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let mut rooms: serde_json::Value =
+            serde_json::from_str(include_str!("./responses/nodes/nodes_ok.json")).unwrap();
+        rooms["items"] = serde_json::json!([]);
+        rooms["range"] = serde_json::json!({ "offset": 0, "limit": 0, "total": 0 });
+
+        let rooms_mock = mock_server
+            .mock("GET", "/api/v4/nodes")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("parent_id".into(), "123".into()),
+                mockito::Matcher::UrlEncoded(
+                    "filter".into(),
+                    "name:eq:new room|type:eq:room".into(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(rooms.to_string())
+            .create();
+
+        let available = client
+            .nodes()
+            .room_name_available(Some(123), "new room")
+            .await
+            .unwrap();
+
+        rooms_mock.assert();
+        assert!(available);
+    }
+
+    #[tokio::test]
+    async fn test_room_name_taken() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let rooms_res = include_str!("./responses/nodes/nodes_ok.json");
+
+        let rooms_mock = mock_server
+            .mock("GET", "/api/v4/nodes")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "filter".into(),
+                "name:eq:string|type:eq:room".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(rooms_res)
+            .create();
+
+        let available = client
+            .nodes()
+            .room_name_available(None, "string")
+            .await
+            .unwrap();
+
+        rooms_mock.assert();
+        assert!(!available);
+    }
 }
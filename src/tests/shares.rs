@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod download_share_tests {
-    use chrono::DateTime;
+    use chrono::{DateTime, Utc};
+    use dco3_crypto::{DracoonCrypto, DracoonRSACrypto, Encrypt, UserKeyPairVersion};
+    use mockito::Matcher;
 
     use crate::{
         nodes::NodeType,
@@ -306,6 +308,86 @@ mod download_share_tests {
         assert_download_share(&share);
     }
 
+    #[tokio::test]
+    async fn test_create_encrypted_download_share() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let share_res = include_str!("./responses/shares/download_share_ok.json");
+
+        let (_, plain_key) = DracoonCrypto::encrypt(vec![0, 12, 33, 44]).unwrap();
+        let keypair =
+            DracoonCrypto::create_plain_user_keypair(UserKeyPairVersion::RSA2048).unwrap();
+        let enc_keypair =
+            DracoonCrypto::encrypt_private_key("TopSecret1234!", keypair.clone()).unwrap();
+        let file_key = DracoonCrypto::encrypt_file_key(plain_key, keypair).unwrap();
+
+        let share_mock = mock_server
+            .mock("POST", "/api/v4/shares/downloads")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::PartialJsonString(
+                    r#"{"nodeId":2,"password":"TopSecret1234!","maxDownloads":2,"notifyCreator":true,"sendMail":true,"mailRecipients":"foo@localhost,bar@localhost","mailSubject":"test"}"#
+                        .into(),
+                ),
+                Matcher::Regex(r#""keyPair":\{"#.into()),
+                Matcher::Regex(r#""fileKey":\{"#.into()),
+            ]))
+            .with_status(201)
+            .with_body(share_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let share = CreateDownloadShareRequest::builder(2)
+            .with_password("TopSecret1234!")
+            .with_max_downloads(2)
+            .with_keypair(enc_keypair)
+            .with_file_key(file_key)
+            .with_notify_creator(true)
+            .with_send_mail(true)
+            .with_mail_recipients(vec!["foo@localhost".into(), "bar@localhost".into()])
+            .with_mail_subject("test")
+            .build();
+
+        let share = client.shares().create_download_share(share).await.unwrap();
+
+        share_mock.assert();
+
+        assert_download_share(&share);
+        assert_eq!(
+            share.public_url(client.get_base_url()).as_str(),
+            format!("{}public/download-shares/string", client.get_base_url())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_node_download_shares() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let shares_res = include_str!("./responses/shares/download_shares_ok.json");
+        let shares_mock = mock_server
+            .mock(
+                "GET",
+                "/api/v4/shares/downloads?offset=0&filter=name%3Acn%3Atest%7CnodeId%3Aeq%3A2",
+            )
+            .with_status(200)
+            .with_body(shares_res)
+            .create();
+
+        let params = ListAllParams::builder()
+            .with_filter(DownloadSharesFilter::name_contains("test"))
+            .build();
+
+        let shares = client
+            .shares()
+            .get_node_download_shares(2, Some(params))
+            .await
+            .unwrap();
+
+        shares_mock.assert();
+
+        assert_eq!(shares.items.len(), 1);
+        assert_download_share(shares.items.first().unwrap());
+    }
+
     #[tokio::test]
     async fn test_get_download_share() {
         let (client, mut mock_server) = get_connected_client().await;
@@ -355,6 +437,48 @@ mod download_share_tests {
         assert_download_share(&share);
     }
 
+    #[tokio::test]
+    async fn test_update_download_share_expiration() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let share_res = include_str!("./responses/shares/download_share_ok.json");
+
+        let expire_at = DateTime::parse_from_rfc3339("2030-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let share_mock = mock_server
+            .mock("PUT", "/api/v4/shares/downloads/123")
+            .match_body(Matcher::Json(serde_json::json!({
+                "expiration": {
+                    "enableExpiration": true,
+                    "expireAt": "2030-01-01T00:00:00+00:00"
+                },
+                "maxDownloads": 10,
+                "notes": "extended"
+            })))
+            .with_status(200)
+            .with_body(share_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let update = UpdateDownloadShareRequest::builder()
+            .with_expiration(expire_at)
+            .with_max_downloads(10)
+            .with_notes("extended")
+            .build();
+
+        let share = client
+            .shares()
+            .update_download_share(123, update)
+            .await
+            .unwrap();
+
+        share_mock.assert();
+
+        assert_download_share(&share);
+    }
+
     #[tokio::test]
     async fn test_delete_download_share() {
         let (client, mut mock_server) = get_connected_client().await;
@@ -392,6 +516,140 @@ mod download_share_tests {
 
         assert!(res.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_send_download_share_email_invalid_recipients() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let share_mock = mock_server
+            .mock("POST", "/api/v4/shares/downloads/123/email")
+            .with_status(204)
+            .expect(0)
+            .create();
+
+        for recipients in [
+            vec![],
+            vec!["foo@localhost".into(), "".into()],
+            vec!["foo".into()],
+            vec!["@localhost".into()],
+            vec!["foo@".into()],
+            vec!["foo@bar@localhost".into()],
+            vec!["foo bar@localhost".into()],
+        ] {
+            let email = DownloadShareLinkEmail::new("test", recipients, None);
+
+            let err = client
+                .shares()
+                .send_download_share_email(123, email)
+                .await
+                .unwrap_err();
+
+            assert!(err.is_invalid_email_recipients());
+        }
+
+        share_mock.assert();
+
+        // the share is not created if the recipients are invalid
+        let create_mock = mock_server
+            .mock("POST", "/api/v4/shares/downloads")
+            .with_status(201)
+            .expect(0)
+            .create();
+
+        let share = CreateDownloadShareRequest::builder(1).build();
+        let email = DownloadShareLinkEmail::new("test", vec!["foo".into()], None);
+
+        let err = client
+            .shares()
+            .create_and_send(share, email)
+            .await
+            .unwrap_err();
+
+        create_mock.assert();
+        assert!(err.is_invalid_email_recipients());
+    }
+
+    #[tokio::test]
+    async fn test_create_and_send_download_share() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let share_res = include_str!("./responses/shares/download_share_ok.json");
+
+        let share_mock = mock_server
+            .mock("POST", "/api/v4/shares/downloads")
+            .with_status(201)
+            .with_body(share_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let email_mock = mock_server
+            .mock("POST", "/api/v4/shares/downloads/1/email")
+            .with_status(204)
+            .create();
+
+        let delete_mock = mock_server
+            .mock("DELETE", "/api/v4/shares/downloads/1")
+            .with_status(204)
+            .expect(0)
+            .create();
+
+        let share = CreateDownloadShareRequest::builder(1)
+            .with_name("test")
+            .build();
+        let email = DownloadShareLinkEmail::new("test", vec!["foo@localhost".into()], None);
+
+        let share = client.shares().create_and_send(share, email).await.unwrap();
+
+        share_mock.assert();
+        email_mock.assert();
+        delete_mock.assert();
+
+        assert_download_share(&share);
+    }
+
+    #[tokio::test]
+    async fn test_create_and_send_download_share_email_failure() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let share_res = include_str!("./responses/shares/download_share_ok.json");
+        let error_res = include_str!("./responses/error_bad_request.json");
+
+        let share_mock = mock_server
+            .mock("POST", "/api/v4/shares/downloads")
+            .with_status(201)
+            .with_body(share_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let email_mock = mock_server
+            .mock("POST", "/api/v4/shares/downloads/1/email")
+            .with_status(400)
+            .with_body(error_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let delete_mock = mock_server
+            .mock("DELETE", "/api/v4/shares/downloads/1")
+            .with_status(204)
+            .create();
+
+        let share = CreateDownloadShareRequest::builder(1)
+            .with_name("test")
+            .build();
+        let email = DownloadShareLinkEmail::new("test", vec!["foo@localhost".into()], None);
+
+        let err = client
+            .shares()
+            .create_and_send(share, email)
+            .await
+            .unwrap_err();
+
+        share_mock.assert();
+        email_mock.assert();
+        delete_mock.assert();
+
+        assert!(err.get_http_error().unwrap().is_bad_request());
+    }
 }
 
 #[cfg(test)]
@@ -769,6 +1027,50 @@ mod upload_share_tests {
         assert_upload_share(&share);
     }
 
+    #[tokio::test]
+    async fn test_set_show_uploaded_files() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let share_res = include_str!("./responses/shares/upload_share_ok.json");
+
+        let show_mock = mock_server
+            .mock("PUT", "/api/v4/shares/uploads/123")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "showUploadedFiles": true
+            })))
+            .with_status(200)
+            .with_body(share_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let hide_mock = mock_server
+            .mock("PUT", "/api/v4/shares/uploads/123")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "showUploadedFiles": false
+            })))
+            .with_status(200)
+            .with_body(share_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let share = client
+            .shares()
+            .set_show_uploaded_files(123, true)
+            .await
+            .unwrap();
+
+        show_mock.assert();
+        assert_upload_share(&share);
+
+        client
+            .shares()
+            .set_show_uploaded_files(123, false)
+            .await
+            .unwrap();
+
+        hide_mock.assert();
+    }
+
     #[tokio::test]
     async fn test_delete_upload_share() {
         let (client, mut mock_server) = get_connected_client().await;
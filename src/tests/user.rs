@@ -3,10 +3,12 @@ mod tests {
     use dco3_crypto::{DracoonCrypto, DracoonRSACrypto, UserKeyPairContainer};
 
     use crate::{
+        nodes::NodeType,
         tests::dracoon::{assert_user_account, get_connected_client},
         user::UpdateUserAccountRequest,
-        User, UserAccountKeyPairs,
+        ListAllParams, Subscriptions, User, UserAccountKeyPairs,
     };
+    use mockito::Matcher;
 
     #[tokio::test]
     async fn test_get_keypair() {
@@ -86,6 +88,55 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_export_import_encrypted_keypair() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let keypair_res = include_str!("../tests/responses/keypair_ok.json");
+
+        let export_mock = mock_server
+            .mock("GET", "/api/v4/user/account/keypair")
+            .with_status(200)
+            .with_body(keypair_res)
+            .with_header("content-type", "application/json")
+            .create();
+
+        let enc_keypair = client.user().export_encrypted_keypair().await.unwrap();
+
+        export_mock.assert();
+
+        let kp_from_json = serde_json::from_str::<UserKeyPairContainer>(keypair_res).unwrap();
+        assert_eq!(
+            enc_keypair.private_key_container.private_key,
+            kp_from_json.private_key_container.private_key
+        );
+        assert_eq!(
+            enc_keypair.public_key_container.public_key,
+            kp_from_json.public_key_container.public_key
+        );
+
+        // exported keypair is still encrypted with the secret
+        let backup = serde_json::to_string(&enc_keypair).unwrap();
+        let restored = serde_json::from_str::<UserKeyPairContainer>(&backup).unwrap();
+        assert!(DracoonCrypto::decrypt_keypair("TopSecret1234!", restored.clone()).is_ok());
+
+        let import_mock = mock_server
+            .mock("POST", "/api/v4/user/account/keypair")
+            .match_body(mockito::Matcher::Json(
+                serde_json::from_str(&backup).unwrap(),
+            ))
+            .with_status(204)
+            .create();
+
+        client
+            .user()
+            .import_encrypted_keypair(restored)
+            .await
+            .unwrap();
+
+        import_mock.assert();
+    }
+
     #[tokio::test]
     async fn test_get_user_account() {
         let (client, mut mock_server) = get_connected_client().await;
@@ -160,4 +211,259 @@ mod tests {
         assert_eq!(customer.cnt_internal_user.unwrap(), 9);
         assert_eq!(customer.customer_encryption_enabled, true);
     }
+
+    #[tokio::test]
+    async fn test_get_active_sessions() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let sessions_res = include_str!("../tests/responses/oauth_authorizations_ok.json");
+
+        let sessions_mock = mock_server
+            .mock("GET", "/api/v4/user/oauth/authorizations")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(sessions_res)
+            .create();
+
+        let sessions = client.user().get_active_sessions().await.unwrap();
+
+        sessions_mock.assert();
+
+        assert_eq!(sessions.len(), 2);
+
+        let current = sessions.first().unwrap();
+        assert_eq!(current.id, 1);
+        assert_eq!(current.client_id, "dracoon_legacy_scripting");
+        assert_eq!(current.user_agent_info, Some("dco3|0.1.0".to_string()));
+        assert!(current.created_at.is_some());
+        assert!(current.is_current());
+
+        let other = sessions.last().unwrap();
+        assert_eq!(other.id, 2);
+        assert!(!other.is_current());
+    }
+
+    #[tokio::test]
+    async fn test_revoke_session() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let revoke_mock = mock_server
+            .mock("DELETE", "/api/v4/user/oauth/authorizations/2")
+            .with_status(204)
+            .create();
+
+        let res = client.user().revoke_session(2).await;
+
+        revoke_mock.assert();
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_revoke_all_other_sessions() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let sessions_res = include_str!("../tests/responses/oauth_authorizations_ok.json");
+
+        let sessions_mock = mock_server
+            .mock("GET", "/api/v4/user/oauth/authorizations")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(sessions_res)
+            .create();
+
+        // current session must not be revoked
+        let revoke_current_mock = mock_server
+            .mock("DELETE", "/api/v4/user/oauth/authorizations/1")
+            .with_status(204)
+            .expect(0)
+            .create();
+
+        let revoke_other_mock = mock_server
+            .mock("DELETE", "/api/v4/user/oauth/authorizations/2")
+            .with_status(204)
+            .create();
+
+        let res = client.user().revoke_all_other_sessions().await;
+
+        sessions_mock.assert();
+        revoke_current_mock.assert();
+        revoke_other_mock.assert();
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_revoke_all_other_sessions_without_current() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let sessions_res = include_str!("../tests/responses/oauth_authorizations_ok.json").replace(
+            r#""isCurrentAuthorization": true"#,
+            r#""isCurrentAuthorization": false"#,
+        );
+
+        let sessions_mock = mock_server
+            .mock("GET", "/api/v4/user/oauth/authorizations")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(sessions_res)
+            .create();
+
+        let revoke_mock = mock_server
+            .mock(
+                "DELETE",
+                mockito::Matcher::Regex("/api/v4/user/oauth/authorizations/".into()),
+            )
+            .expect(0)
+            .create();
+
+        let res = client.user().revoke_all_other_sessions().await;
+
+        sessions_mock.assert();
+        revoke_mock.assert();
+
+        assert!(res.unwrap_err().is_current_session_unknown());
+    }
+
+    #[tokio::test]
+    async fn test_get_and_revoke_oauth_approvals() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let approvals_mock = mock_server
+            .mock("GET", "/api/v4/user/oauth/approvals")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/oauth_approvals_ok.json"))
+            .create();
+
+        let approvals = client.user().get_oauth_approvals().await.unwrap();
+
+        approvals_mock.assert();
+
+        assert_eq!(approvals.len(), 1);
+        assert_eq!(approvals[0].client_id, "dracoon_webapp");
+        assert_eq!(approvals[0].scope, Some("all".to_string()));
+
+        let revoke_mock = mock_server
+            .mock("DELETE", "/api/v4/user/oauth/approvals/dracoon_webapp")
+            .with_status(204)
+            .create();
+
+        client
+            .user()
+            .revoke_oauth_approval("dracoon_webapp")
+            .await
+            .unwrap();
+
+        revoke_mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_get_node_subscriptions() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let user_mock = mock_server
+            .mock("GET", "/api/v4/user/account")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/user_info_ok.json"))
+            .create();
+
+        let subscriptions_mock = mock_server
+            .mock("GET", "/api/v4/user/subscriptions/nodes")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("offset".into(), "500".into()),
+                Matcher::UrlEncoded("limit".into(), "500".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/subscriptions_ok.json"))
+            .create();
+
+        let params = ListAllParams::builder()
+            .with_offset(500)
+            .with_limit(500)
+            .build();
+
+        let subscriptions = client.get_node_subscriptions(Some(params)).await.unwrap();
+
+        user_mock.assert();
+        subscriptions_mock.assert();
+
+        assert_eq!(subscriptions.range.total, 2);
+        assert_eq!(subscriptions.items.len(), 2);
+
+        let room = subscriptions.items.first().unwrap();
+        assert_eq!(room.user_id, 1);
+        assert_eq!(room.node_id, 2);
+        assert_eq!(room.node_type, Some(NodeType::Room));
+        assert_eq!(room.auth_parent_id, Some(2));
+
+        let folder = subscriptions.items.last().unwrap();
+        assert_eq!(folder.user_id, 1);
+        assert_eq!(folder.node_id, 3);
+        assert_eq!(folder.node_type, Some(NodeType::Folder));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_node() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let user_mock = mock_server
+            .mock("GET", "/api/v4/user/account")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/user_info_ok.json"))
+            .create();
+
+        let subscribe_mock = mock_server
+            .mock("POST", "/api/v4/user/subscriptions/nodes/2")
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/subscribed_node_ok.json"))
+            .create();
+
+        let subscription = client.subscribe_node(2).await.unwrap();
+
+        user_mock.assert();
+        subscribe_mock.assert();
+
+        assert_eq!(subscription.user_id, 1);
+        assert_eq!(subscription.node_id, 2);
+        assert_eq!(subscription.node_type, Some(NodeType::Room));
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_node() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let unsubscribe_mock = mock_server
+            .mock("DELETE", "/api/v4/user/subscriptions/nodes/2")
+            .with_status(204)
+            .create();
+
+        let res = client.unsubscribe_node(2).await;
+
+        unsubscribe_mock.assert();
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_node_not_found() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let unsubscribe_mock = mock_server
+            .mock("DELETE", "/api/v4/user/subscriptions/nodes/2")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../tests/responses/error_not_found.json"))
+            .create();
+
+        let err = client.unsubscribe_node(2).await.unwrap_err();
+
+        unsubscribe_mock.assert();
+
+        assert!(err.is_not_found());
+    }
 }
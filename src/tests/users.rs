@@ -173,6 +173,38 @@ pub mod tests {
         assert_user_item(user);
     }
 
+    #[tokio::test]
+    async fn test_get_locked_users() {
+        let (client, mut mock_server) = get_connected_client().await;
+
+        let users_res = include_str!("./responses/users/users_ok.json");
+
+        let users_mock = mock_server
+            .mock(
+                "GET",
+                "/api/v4/users?offset=0&filter=isLocked%3Aeq%3Atrue%7CuserName%3Acn%3Atest",
+            )
+            .with_status(200)
+            .with_body(users_res)
+            .create();
+
+        let params = ListAllParams::builder()
+            .with_filter(UsersFilter::is_locked(true))
+            .with_filter(UsersFilter::login_contains("test"))
+            .build();
+
+        let users = client
+            .users()
+            .get_users(Some(params), None, None)
+            .await
+            .unwrap();
+
+        users_mock.assert();
+        assert_eq!(users.range.total, 1);
+        let user = users.items.first().unwrap();
+        assert_user_item(user);
+    }
+
     #[tokio::test]
     async fn test_get_users_with_roles() {
         let (client, mut mock_server) = get_connected_client().await;
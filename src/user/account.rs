@@ -1,15 +1,19 @@
 use async_trait::async_trait;
 use reqwest::header;
+use tracing::error;
 
 use crate::{
     client::{errors::DracoonClientError, Connected},
-    constants::{DRACOON_API_PREFIX, USER_ACCOUNT, USER_ACCOUNT_CUSTOMER, USER_BASE},
+    constants::{
+        DRACOON_API_PREFIX, USER_ACCOUNT, USER_ACCOUNT_CUSTOMER, USER_BASE, USER_OAUTH,
+        USER_OAUTH_APPROVALS, USER_OAUTH_AUTHORIZATIONS,
+    },
     utils::FromResponse,
 };
 
 use super::{
     models::{UpdateUserAccountRequest, UserAccount},
-    CustomerData, User, UserEndpoint,
+    CustomerData, OAuthApproval, SessionList, User, UserEndpoint, UserSession,
 };
 
 #[async_trait]
@@ -77,4 +81,103 @@ impl User for UserEndpoint<Connected> {
 
         CustomerData::from_response(response).await
     }
+
+    async fn get_active_sessions(&self) -> Result<SessionList, DracoonClientError> {
+        let url_part =
+            format!("{DRACOON_API_PREFIX}/{USER_BASE}/{USER_OAUTH}/{USER_OAUTH_AUTHORIZATIONS}");
+
+        let url = self.client().build_api_url(&url_part);
+
+        let response = self
+            .client()
+            .http
+            .get(url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .header(header::CONTENT_TYPE, "application/json")
+            .send()
+            .await?;
+
+        SessionList::from_response(response).await
+    }
+
+    async fn revoke_session(&self, session_id: u64) -> Result<(), DracoonClientError> {
+        let url_part = format!(
+            "{DRACOON_API_PREFIX}/{USER_BASE}/{USER_OAUTH}/{USER_OAUTH_AUTHORIZATIONS}/{session_id}"
+        );
+
+        self.delete_user_oauth(&url_part).await
+    }
+
+    async fn revoke_all_other_sessions(&self) -> Result<(), DracoonClientError> {
+        let sessions = self.get_active_sessions().await?;
+
+        // without a flagged current authorization, this client would sign itself out
+        if !sessions.iter().any(UserSession::is_current) {
+            error!("Current authorization not flagged - not revoking any sessions");
+            return Err(DracoonClientError::CurrentSessionUnknown);
+        }
+
+        for session in sessions.iter().filter(|s| !s.is_current()) {
+            self.revoke_session(session.id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn get_oauth_approvals(&self) -> Result<Vec<OAuthApproval>, DracoonClientError> {
+        let url_part =
+            format!("{DRACOON_API_PREFIX}/{USER_BASE}/{USER_OAUTH}/{USER_OAUTH_APPROVALS}");
+
+        let url = self.client().build_api_url(&url_part);
+
+        let response = self
+            .client()
+            .http
+            .get(url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .header(header::CONTENT_TYPE, "application/json")
+            .send()
+            .await?;
+
+        Vec::<OAuthApproval>::from_response(response).await
+    }
+
+    async fn revoke_oauth_approval(&self, client_id: &str) -> Result<(), DracoonClientError> {
+        let url_part = format!(
+            "{DRACOON_API_PREFIX}/{USER_BASE}/{USER_OAUTH}/{USER_OAUTH_APPROVALS}/{client_id}"
+        );
+
+        self.delete_user_oauth(&url_part).await
+    }
+}
+
+impl UserEndpoint<Connected> {
+    async fn delete_user_oauth(&self, url_part: &str) -> Result<(), DracoonClientError> {
+        let url = self.client().build_api_url(url_part);
+
+        let response = self
+            .client()
+            .http
+            .delete(url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .send()
+            .await?;
+
+        if response.status().is_server_error() || response.status().is_client_error() {
+            return Err(DracoonClientError::from_response(response)
+                .await
+                .expect("Could not parse error response"));
+        }
+
+        Ok(())
+    }
 }
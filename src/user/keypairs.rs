@@ -2,6 +2,7 @@ use super::{UserAccountKeyPairs, UserEndpoint};
 use crate::{
     client::{errors::DracoonClientError, Connected},
     constants::{DRACOON_API_PREFIX, USER_ACCOUNT, USER_ACCOUNT_KEYPAIR, USER_BASE},
+    models::WrappedUserKeypair,
     utils::FromResponse,
 };
 use async_trait::async_trait;
@@ -9,6 +10,10 @@ use dco3_crypto::{
     DracoonCrypto, DracoonRSACrypto, PlainUserKeyPairContainer, UserKeyPairContainer,
 };
 use reqwest::header;
+use secrecy::SecretBox;
+use std::path::Path;
+use tokio::io::AsyncWriteExt;
+use tracing::error;
 
 #[async_trait]
 impl UserAccountKeyPairs for UserEndpoint<Connected> {
@@ -65,6 +70,58 @@ impl UserAccountKeyPairs for UserEndpoint<Connected> {
         Ok(())
     }
 
+    async fn export_encrypted_keypair(&self) -> Result<UserKeyPairContainer, DracoonClientError> {
+        let url_part =
+            format!("{DRACOON_API_PREFIX}/{USER_BASE}/{USER_ACCOUNT}/{USER_ACCOUNT_KEYPAIR}");
+
+        let url = self.client().build_api_url(&url_part);
+
+        let response = self
+            .client()
+            .http
+            .get(url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .header(header::CONTENT_TYPE, "application/json")
+            .send()
+            .await?;
+
+        UserKeyPairContainer::from_response(response).await
+    }
+
+    async fn import_encrypted_keypair(
+        &self,
+        keypair: UserKeyPairContainer,
+    ) -> Result<(), DracoonClientError> {
+        let url_part =
+            format!("{DRACOON_API_PREFIX}/{USER_BASE}/{USER_ACCOUNT}/{USER_ACCOUNT_KEYPAIR}");
+
+        let url = self.client().build_api_url(&url_part);
+
+        let response = self
+            .client()
+            .http
+            .post(url)
+            .header(
+                header::AUTHORIZATION,
+                self.client().get_auth_header().await?,
+            )
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&keypair)
+            .send()
+            .await?;
+
+        if response.status().is_server_error() || response.status().is_client_error() {
+            return Err(DracoonClientError::from_response(response)
+                .await
+                .expect("Could not parse error response"));
+        }
+
+        Ok(())
+    }
+
     async fn delete_user_keypair(&self) -> Result<(), DracoonClientError> {
         let url_part =
             format!("{DRACOON_API_PREFIX}/{USER_BASE}/{USER_ACCOUNT}/{USER_ACCOUNT_KEYPAIR}");
@@ -85,4 +142,76 @@ impl UserAccountKeyPairs for UserEndpoint<Connected> {
 
         Ok(())
     }
+
+    async fn cache_keypair_to_file(
+        &self,
+        path: impl AsRef<Path> + Send,
+        secret: &str,
+    ) -> Result<(), DracoonClientError> {
+        let path = path.as_ref();
+        let enc_keypair = self.export_encrypted_keypair().await?;
+
+        // never cache a keypair that cannot be decrypted with the secret
+        DracoonCrypto::decrypt_keypair(secret, enc_keypair.clone())?;
+
+        let content = serde_json::to_vec(&enc_keypair).map_err(|err| {
+            error!("Error serializing keypair: {}", err);
+            DracoonClientError::Internal
+        })?;
+
+        write_private_file(path, &content).await.map_err(|err| {
+            error!("Error writing keypair to {}: {}", path.display(), err);
+            DracoonClientError::IoError
+        })
+    }
+
+    async fn load_keypair_from_file(
+        &self,
+        path: impl AsRef<Path> + Send,
+        secret: &str,
+    ) -> Result<(), DracoonClientError> {
+        let path = path.as_ref();
+
+        let content = tokio::fs::read(path).await.map_err(|err| {
+            error!("Error reading keypair from {}: {}", path.display(), err);
+            DracoonClientError::IoError
+        })?;
+
+        let enc_keypair: UserKeyPairContainer =
+            serde_json::from_slice(&content).map_err(|err| {
+                error!("Error parsing keypair from {}: {}", path.display(), err);
+                DracoonClientError::IoError
+            })?;
+
+        let keypair = DracoonCrypto::decrypt_keypair(secret, enc_keypair)?;
+
+        let _guard = self.keypair_fetch().lock().await;
+        self.keypair()
+            .set(SecretBox::new(Box::new(WrappedUserKeypair::new(keypair))))
+            .await;
+
+        Ok(())
+    }
+}
+
+/// helper to write a file only accessible by the owner (Unix: 0600)
+async fn write_private_file(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    options.mode(0o600);
+
+    let mut file = options.open(path).await?;
+
+    // mode is only applied to new files
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))
+            .await?;
+    }
+
+    file.write_all(content).await?;
+    file.sync_all().await
 }
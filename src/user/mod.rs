@@ -1,15 +1,19 @@
 //! This module implements a subset of the DRACOON user API.
 //! Documentation can be found here: <https://download.dracoon.com/api/swagger-ui/index.html?configUrl=/api/spec_v4/swagger-config#/user>
 
+use std::path::Path;
+
 use async_trait::async_trait;
-use dco3_crypto::PlainUserKeyPairContainer;
+use dco3_crypto::{PlainUserKeyPairContainer, UserKeyPairContainer};
 
 pub use self::models::*;
 use super::client::errors::DracoonClientError;
+use crate::models::ListAllParams;
 
 pub mod account;
 pub mod keypairs;
 pub mod models;
+pub mod subscriptions;
 
 #[async_trait]
 pub trait User {
@@ -77,6 +81,178 @@ pub trait User {
     /// let customer = dracoon.user().get_customer_info().await.unwrap();
     /// # }
     async fn get_customer_info(&self) -> Result<CustomerData, DracoonClientError>;
+
+    /// Get all active sessions (OAuth authorizations) of the user account.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, User};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let sessions = dracoon.user().get_active_sessions().await.unwrap();
+    ///
+    /// for session in sessions {
+    ///    println!("{} (current: {})", session.id, session.is_current());
+    /// }
+    /// # }
+    /// ```
+    async fn get_active_sessions(&self) -> Result<SessionList, DracoonClientError>;
+
+    /// Revoke a session (OAuth authorization) of the user account.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, User};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// dracoon.user().revoke_session(123).await.unwrap();
+    /// # }
+    /// ```
+    async fn revoke_session(&self, session_id: u64) -> Result<(), DracoonClientError>;
+
+    /// Revoke all sessions (OAuth authorizations) of the user account except the one used by this client.
+    /// Signs out all other devices. Fails with `DracoonClientError::CurrentSessionUnknown` and revokes nothing
+    /// if the current authorization is not flagged by the API.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, User};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// dracoon.user().revoke_all_other_sessions().await.unwrap();
+    /// # }
+    /// ```
+    async fn revoke_all_other_sessions(&self) -> Result<(), DracoonClientError>;
+
+    /// Get all OAuth approvals (clients granted access) of the user account.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, User};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let approvals = dracoon.user().get_oauth_approvals().await.unwrap();
+    /// # }
+    /// ```
+    async fn get_oauth_approvals(&self) -> Result<Vec<OAuthApproval>, DracoonClientError>;
+
+    /// Revoke the OAuth approval of a client (by client id).
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, User};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// dracoon.user().revoke_oauth_approval("client_id").await.unwrap();
+    /// # }
+    /// ```
+    async fn revoke_oauth_approval(&self, client_id: &str) -> Result<(), DracoonClientError>;
+}
+
+/// This trait provides methods to manage node subscriptions of the authenticated user.
+/// Subscribed users are notified about node events - unlike the eventlog, no polling is required.
+#[async_trait]
+pub trait Subscriptions {
+    /// Returns the node subscriptions of the authenticated user (paginated via `ListAllParams`).
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Subscriptions, ListAllParams};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let subscriptions = dracoon.get_node_subscriptions(None).await.unwrap();
+    ///
+    /// // or with pagination
+    /// let params = ListAllParams::builder()
+    ///    .with_offset(500)
+    ///    .build();
+    /// let subscriptions = dracoon.get_node_subscriptions(Some(params)).await.unwrap();
+    /// # }
+    /// ```
+    async fn get_node_subscriptions(
+        &self,
+        params: Option<ListAllParams>,
+    ) -> Result<SubscriptionList, DracoonClientError>;
+
+    /// Subscribes the authenticated user to a node.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Subscriptions};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let subscription = dracoon.subscribe_node(123).await.unwrap();
+    /// # }
+    /// ```
+    async fn subscribe_node(&self, node_id: u64) -> Result<Subscription, DracoonClientError>;
+
+    /// Unsubscribes the authenticated user from a node.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, Subscriptions};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// dracoon.unsubscribe_node(123).await.unwrap();
+    /// # }
+    /// ```
+    async fn unsubscribe_node(&self, node_id: u64) -> Result<(), DracoonClientError>;
 }
 
 #[async_trait]
@@ -143,4 +319,98 @@ pub trait UserAccountKeyPairs {
     /// # }
     /// ```
     async fn delete_user_keypair(&self) -> Result<(), DracoonClientError>;
+    /// Export the encrypted user keypair container (e.g. for a backup).
+    /// The private key remains encrypted with the user's encryption secret.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, UserAccountKeyPairs};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// let enc_keypair = dracoon.user().export_encrypted_keypair().await.unwrap();
+    /// let backup = serde_json::to_string(&enc_keypair).unwrap();
+    /// # }
+    /// ```
+    async fn export_encrypted_keypair(&self) -> Result<UserKeyPairContainer, DracoonClientError>;
+    /// Import an encrypted user keypair container (e.g. from a backup).
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, UserAccountKeyPairs};
+    /// # use dco3_crypto::UserKeyPairContainer;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// # let backup = String::new();
+    /// let enc_keypair: UserKeyPairContainer = serde_json::from_str(&backup).unwrap();
+    /// dracoon.user().import_encrypted_keypair(enc_keypair).await.unwrap();
+    /// // note: you need to delete the existing keypair before importing a keypair.
+    /// # }
+    /// ```
+    async fn import_encrypted_keypair(
+        &self,
+        keypair: UserKeyPairContainer,
+    ) -> Result<(), DracoonClientError>;
+    /// Stores the encrypted user keypair container in a local file (e.g. for CLI tools).
+    /// The secret is only used to verify the keypair - the private key remains encrypted.
+    /// On Unix, the file is only readable and writable by the owner (0600).
+    /// Use `load_keypair_from_file` to restore the keypair without a network call.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, UserAccountKeyPairs};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// dracoon.user().cache_keypair_to_file("keypair.json", "secret123").await.unwrap();
+    /// # }
+    /// ```
+    async fn cache_keypair_to_file(
+        &self,
+        path: impl AsRef<Path> + Send,
+        secret: &str,
+    ) -> Result<(), DracoonClientError>;
+    /// Restores the keypair from a file written with `cache_keypair_to_file` into the client's
+    /// keypair cache (see `Dracoon::get_keypair`) - no network call is made.
+    /// Fails with a crypto error if the secret is wrong - the cached keypair is kept on error.
+    /// ```no_run
+    /// # use dco3::{Dracoon, auth::OAuth2Flow, UserAccountKeyPairs};
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let dracoon = Dracoon::builder()
+    /// #  .with_base_url("https://dracoon.team")
+    /// #  .with_client_id("client_id")
+    /// #  .with_client_secret("client_secret")
+    /// #  .build()
+    /// #  .unwrap()
+    /// #  .connect(OAuth2Flow::PasswordFlow("username".into(), "password".into()))
+    /// #  .await
+    /// #  .unwrap();
+    /// dracoon.user().load_keypair_from_file("keypair.json", "secret123").await.unwrap();
+    /// # }
+    /// ```
+    async fn load_keypair_from_file(
+        &self,
+        path: impl AsRef<Path> + Send,
+        secret: &str,
+    ) -> Result<(), DracoonClientError>;
 }
@@ -5,17 +5,24 @@ use chrono::{DateTime, Utc};
 use dco3_crypto::UserKeyPairContainer;
 use dco3_derive::FromResponse;
 use reqwest::Response;
+use secrecy::SecretBox;
 use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
 
 use crate::{
     client::{errors::DracoonClientError, models::DracoonErrorResponse, DracoonClient},
-    roles::RoleList,
+    constants::{ROLE_CONFIG_MANAGER, ROLE_ROOM_MANAGER},
+    models::{Container, RangedItems, WrappedUserKeypair},
+    nodes::NodeType,
+    roles::{Role, RoleList},
     utils::{parse_body, FromResponse},
 };
 
 #[derive(Clone)]
 pub struct UserEndpoint<S> {
     client: Arc<DracoonClient<S>>,
+    keypair: Container<SecretBox<WrappedUserKeypair>>,
+    keypair_fetch: Arc<Mutex<()>>,
     state: std::marker::PhantomData<S>,
 }
 
@@ -23,6 +30,8 @@ impl<S> UserEndpoint<S> {
     pub fn new(client: Arc<DracoonClient<S>>) -> Self {
         Self {
             client,
+            keypair: Container::new(),
+            keypair_fetch: Arc::new(Mutex::new(())),
             state: std::marker::PhantomData,
         }
     }
@@ -30,6 +39,16 @@ impl<S> UserEndpoint<S> {
     pub fn client(&self) -> &Arc<DracoonClient<S>> {
         &self.client
     }
+
+    /// keypair cache (shared with the client - see `Dracoon::get_keypair`)
+    pub(crate) fn keypair(&self) -> &Container<SecretBox<WrappedUserKeypair>> {
+        &self.keypair
+    }
+
+    /// lock to serialize keypair cache updates (shared with the client)
+    pub(crate) fn keypair_fetch(&self) -> &Arc<Mutex<()>> {
+        &self.keypair_fetch
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, FromResponse)]
@@ -57,6 +76,38 @@ pub struct UserAccount {
     pub user_groups: Option<Vec<UserGroup>>,
 }
 
+/// Account of the authenticated user bundled with the effective roles
+#[derive(Debug, Clone)]
+pub struct UserContext {
+    pub account: UserAccount,
+    pub roles: Vec<Role>,
+    pub is_config_manager: bool,
+    pub is_room_manager: bool,
+}
+
+impl UserContext {
+    /// returns true if the user has a role with the given name (e.g. `USER_MANAGER`)
+    pub fn has_role(&self, name: &str) -> bool {
+        self.roles.iter().any(|role| role.name == name)
+    }
+}
+
+impl From<UserAccount> for UserContext {
+    fn from(account: UserAccount) -> Self {
+        let roles = account.user_roles.items.clone();
+        let has_role = |name: &str| roles.iter().any(|role| role.name == name);
+        let is_config_manager = has_role(ROLE_CONFIG_MANAGER);
+        let is_room_manager = has_role(ROLE_ROOM_MANAGER);
+
+        Self {
+            account,
+            roles,
+            is_config_manager,
+            is_room_manager,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct UserAuthData {
@@ -189,3 +240,95 @@ pub struct CustomerData {
     pub cnt_guest_user: Option<u64>,
     pub customer_encryption_enabled: bool,
 }
+
+/// OAuth authorization (active session / refresh token) of the authenticated user
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSession {
+    pub id: u64,
+    pub client_id: String,
+    pub client_name: String,
+    pub is_standard: Option<bool>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub user_agent_type: Option<String>,
+    pub user_agent_os: Option<String>,
+    pub user_agent_info: Option<String>,
+    pub user_agent_category: Option<String>,
+    pub is_current_authorization: Option<bool>,
+}
+
+impl UserSession {
+    /// returns true if the authorization belongs to the token used by this client
+    pub fn is_current(&self) -> bool {
+        self.is_current_authorization.unwrap_or(false)
+    }
+}
+
+pub type SessionList = Vec<UserSession>;
+
+#[async_trait]
+impl FromResponse for SessionList {
+    async fn from_response(response: Response) -> Result<Self, DracoonClientError> {
+        parse_body::<Self, DracoonErrorResponse>(response).await
+    }
+}
+
+/// OAuth approval (client granted access to the user account)
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthApproval {
+    pub client_id: String,
+    pub client_name: String,
+    pub scope: Option<String>,
+}
+
+#[async_trait]
+impl FromResponse for Vec<OAuthApproval> {
+    async fn from_response(response: Response) -> Result<Self, DracoonClientError> {
+        parse_body::<Self, DracoonErrorResponse>(response).await
+    }
+}
+
+/// Node subscribed by the authenticated user (as returned by the API)
+#[derive(Debug, Deserialize, Clone, FromResponse)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SubscribedNode {
+    pub id: u64,
+    #[serde(rename = "type")]
+    pub node_type: Option<NodeType>,
+    pub auth_parent_id: Option<u64>,
+}
+
+pub(crate) type SubscribedNodeList = RangedItems<SubscribedNode>;
+
+#[async_trait]
+impl FromResponse for SubscribedNodeList {
+    async fn from_response(response: Response) -> Result<Self, DracoonClientError> {
+        parse_body::<Self, DracoonErrorResponse>(response).await
+    }
+}
+
+/// Node subscription of the authenticated user
+/// A subscribed user is notified about events of the node (e.g. new uploads).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Subscription {
+    pub user_id: u64,
+    pub node_id: u64,
+    pub node_type: Option<NodeType>,
+    pub auth_parent_id: Option<u64>,
+}
+
+impl Subscription {
+    pub(crate) fn new(user_id: u64, node: SubscribedNode) -> Self {
+        Self {
+            user_id,
+            node_id: node.id,
+            node_type: node.node_type,
+            auth_parent_id: node.auth_parent_id,
+        }
+    }
+}
+
+pub type SubscriptionList = RangedItems<Subscription>;
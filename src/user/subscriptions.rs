@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use reqwest::header;
+
+use crate::{
+    client::{errors::DracoonClientError, Connected},
+    constants::{DRACOON_API_PREFIX, USER_BASE, USER_SUBSCRIPTIONS, USER_SUBSCRIPTIONS_NODES},
+    models::{ListAllParams, RangedItems},
+    utils::FromResponse,
+    Dracoon,
+};
+
+use super::{
+    models::{SubscribedNode, SubscribedNodeList},
+    Subscription, SubscriptionList, Subscriptions,
+};
+
+#[async_trait]
+impl Subscriptions for Dracoon<Connected> {
+    async fn get_node_subscriptions(
+        &self,
+        params: Option<ListAllParams>,
+    ) -> Result<SubscriptionList, DracoonClientError> {
+        let params = params.unwrap_or_default();
+        let url_part = format!(
+            "{DRACOON_API_PREFIX}/{USER_BASE}/{USER_SUBSCRIPTIONS}/{USER_SUBSCRIPTIONS_NODES}"
+        );
+
+        let mut api_url = self.build_api_url(&url_part);
+
+        let filters = params.filter_to_string();
+        let sorts = params.sort_to_string();
+
+        api_url
+            .query_pairs_mut()
+            .extend_pairs(params.limit.map(|v| ("limit", v.to_string())))
+            .extend_pairs(params.offset.map(|v| ("offset", v.to_string())))
+            .extend_pairs(params.sort.as_ref().map(|_| ("sort", sorts)))
+            .extend_pairs(params.filter.as_ref().map(|_| ("filter", filters)))
+            .finish();
+
+        let response = self
+            .client
+            .http
+            .get(api_url)
+            .header(header::AUTHORIZATION, self.get_auth_header().await?)
+            .send()
+            .await?;
+
+        let nodes = SubscribedNodeList::from_response(response).await?;
+
+        let user_id = self.current_user_id().await?;
+
+        Ok(RangedItems {
+            range: nodes.range,
+            items: nodes
+                .items
+                .into_iter()
+                .map(|node| Subscription::new(user_id, node))
+                .collect(),
+        })
+    }
+
+    async fn subscribe_node(&self, node_id: u64) -> Result<Subscription, DracoonClientError> {
+        let url_part = format!(
+            "{DRACOON_API_PREFIX}/{USER_BASE}/{USER_SUBSCRIPTIONS}/{USER_SUBSCRIPTIONS_NODES}/{node_id}"
+        );
+
+        let api_url = self.build_api_url(&url_part);
+
+        let response = self
+            .client
+            .http
+            .post(api_url)
+            .header(header::AUTHORIZATION, self.get_auth_header().await?)
+            .send()
+            .await?;
+
+        let node = SubscribedNode::from_response(response).await?;
+
+        let user_id = self.current_user_id().await?;
+
+        Ok(Subscription::new(user_id, node))
+    }
+
+    async fn unsubscribe_node(&self, node_id: u64) -> Result<(), DracoonClientError> {
+        let url_part = format!(
+            "{DRACOON_API_PREFIX}/{USER_BASE}/{USER_SUBSCRIPTIONS}/{USER_SUBSCRIPTIONS_NODES}/{node_id}"
+        );
+
+        let api_url = self.build_api_url(&url_part);
+
+        let response = self
+            .client
+            .http
+            .delete(api_url)
+            .header(header::AUTHORIZATION, self.get_auth_header().await?)
+            .send()
+            .await?;
+
+        if response.status().is_server_error() || response.status().is_client_error() {
+            return Err(DracoonClientError::from_response(response)
+                .await
+                .expect("Could not parse error response"));
+        }
+
+        Ok(())
+    }
+}
@@ -704,6 +704,11 @@ impl UsersFilter {
         Self::UserName(FilterOperator::Cn, value.into())
     }
 
+    /// Filter by login (user name in DRACOON)
+    pub fn login_contains(value: impl Into<String>) -> Self {
+        Self::UserName(FilterOperator::Cn, value.into())
+    }
+
     pub fn first_name_contains(value: impl Into<String>) -> Self {
         Self::FirstName(FilterOperator::Cn, value.into())
     }
@@ -828,3 +833,35 @@ impl From<UsersFilter> for Box<dyn FilterQuery> {
         Box::new(f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_users_filter_login_contains() {
+        let filter = UsersFilter::login_contains("test");
+        assert_eq!(filter.to_filter_string(), "userName:cn:test");
+    }
+
+    #[test]
+    fn test_users_filter_email_contains() {
+        let filter = UsersFilter::email_contains("@dracoon.com");
+        assert_eq!(filter.to_filter_string(), "email:cn:@dracoon.com");
+    }
+
+    #[test]
+    fn test_users_filter_is_locked() {
+        let filter = UsersFilter::is_locked(true);
+        let neg_filter = UsersFilter::is_locked(false);
+        assert_eq!(filter.to_filter_string(), "isLocked:eq:true");
+        assert_eq!(neg_filter.to_filter_string(), "isLocked:eq:false");
+    }
+
+    #[test]
+    fn test_users_filter_effective_roles() {
+        let filter = UsersFilter::effective_roles(true);
+        assert_eq!(filter.to_filter_string(), "effectiveRoles:eq:true");
+    }
+}
@@ -1,11 +1,23 @@
+use std::{
+    fmt::Write,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
 use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset};
+use openssl::hash::{Hasher, MessageDigest};
 use reqwest::Response;
 use serde::de::DeserializeOwned;
 use serde_xml_rs::from_str;
+use tokio::io::AsyncWrite;
 use tracing::error;
 
 use super::{
-    client::{errors::DracoonClientError, models::StatusCodeState},
+    client::{
+        errors::DracoonClientError,
+        models::{parse_retry_after, MaxResponseBodySize, StatusCodeState},
+    },
     nodes::models::S3ErrorResponse,
 };
 
@@ -16,17 +28,57 @@ where
     E: DeserializeOwned + Into<DracoonClientError>,
 {
     match Into::<StatusCodeState>::into(res.status()) {
-        StatusCodeState::Ok(_) => Ok(res.json::<T>().await.map_err(|err| {
+        StatusCodeState::Ok(_) => Ok(parse_json::<T>(res).await.map_err(|err| {
             error!("{}", err);
             err
         })?),
-        StatusCodeState::Error(_) => Err(build_error_body::<E>(res.json::<E>().await.map_err(
-            |err| {
+        StatusCodeState::Error(status) => {
+            let retry_after = parse_retry_after(res.headers());
+            let error = build_error_body::<E>(parse_json::<E>(res).await.map_err(|err| {
                 error!("Failed to parse error body: {}", err);
                 err
-            },
-        )?)),
+            })?);
+
+            Err(error.into_rate_limited(status, retry_after))
+        }
+    }
+}
+
+/// Parses a JSON body - enforces the max. response body size (if configured)
+pub(crate) async fn parse_json<T: DeserializeOwned>(
+    mut res: Response,
+) -> Result<T, DracoonClientError> {
+    let Some(MaxResponseBodySize(max_size)) =
+        res.extensions().get::<MaxResponseBodySize>().copied()
+    else {
+        return Ok(res.json::<T>().await?);
+    };
+
+    let too_large = || {
+        error!("Response body exceeds max. size ({} bytes)", max_size);
+        DracoonClientError::ResponseTooLarge { max_size }
+    };
+
+    if res
+        .content_length()
+        .is_some_and(|length| length > max_size as u64)
+    {
+        return Err(too_large());
+    }
+
+    // the content length is not known for chunked or compressed responses
+    let mut body = Vec::new();
+    while let Some(chunk) = res.chunk().await? {
+        if body.len() + chunk.len() > max_size {
+            return Err(too_large());
+        }
+        body.extend_from_slice(&chunk);
     }
+
+    serde_json::from_slice(&body).map_err(|err| {
+        error!("Failed to parse response body: {}", err);
+        DracoonClientError::ConnectionFailed("Unknown".into())
+    })
 }
 
 /// Builds the error body from the response
@@ -53,6 +105,32 @@ pub async fn build_s3_error(response: Response) -> DracoonClientError {
     DracoonClientError::S3Error(Box::new(err_response))
 }
 
+/// Requests with timestamps (RFC 3339) that are sent in the timezone configured for the client
+pub(crate) trait WithTimestampTimezone: Sized {
+    /// Returns the timestamps of the request (creation and modification)
+    fn timestamps_mut(&mut self) -> [&mut Option<String>; 2];
+
+    /// Converts all timestamps to the given timezone - unchanged if no timezone is configured
+    /// (timestamps which are no valid RFC 3339 timestamps are kept as they are)
+    fn with_timestamp_timezone(mut self, timezone: Option<FixedOffset>) -> Self {
+        let Some(timezone) = timezone else {
+            return self;
+        };
+
+        for timestamp in self.timestamps_mut() {
+            if let Some(converted) = timestamp
+                .as_deref()
+                .and_then(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok())
+                .map(|timestamp| timestamp.with_timezone(&timezone).to_rfc3339())
+            {
+                *timestamp = Some(converted);
+            }
+        }
+
+        self
+    }
+}
+
 #[async_trait]
 pub trait FromResponse {
     /// Trait that allows to convert a response into a specific type (async)
@@ -60,3 +138,64 @@ pub trait FromResponse {
     where
         Self: Sized;
 }
+
+/// Writer that hashes all content written to the inner writer
+pub(crate) struct HashingWriter<'w> {
+    inner: &'w mut (dyn AsyncWrite + Send + Unpin),
+    hasher: Hasher,
+}
+
+impl<'w> HashingWriter<'w> {
+    pub(crate) fn new(
+        inner: &'w mut (dyn AsyncWrite + Send + Unpin),
+        digest: MessageDigest,
+    ) -> Result<Self, DracoonClientError> {
+        let hasher = Hasher::new(digest).map_err(|err| {
+            error!("Error creating hasher: {}", err);
+            DracoonClientError::Internal
+        })?;
+
+        Ok(Self { inner, hasher })
+    }
+
+    /// Returns the hex encoded hash of all written content
+    pub(crate) fn finish(mut self) -> Result<String, DracoonClientError> {
+        let digest = self.hasher.finish().map_err(|err| {
+            error!("Error finishing hash: {}", err);
+            DracoonClientError::Internal
+        })?;
+
+        Ok(digest.iter().fold(String::new(), |mut hash, byte| {
+            let _ = write!(hash, "{byte:02x}");
+            hash
+        }))
+    }
+}
+
+impl AsyncWrite for HashingWriter<'_> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = &mut *self;
+        match Pin::new(&mut *this.inner).poll_write(cx, buf) {
+            Poll::Ready(Ok(written)) => {
+                // only hash what was actually written
+                this.hasher
+                    .update(&buf[..written])
+                    .map_err(std::io::Error::other)?;
+                Poll::Ready(Ok(written))
+            }
+            poll => poll,
+        }
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut *self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut *self.inner).poll_shutdown(cx)
+    }
+}